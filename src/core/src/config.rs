@@ -0,0 +1,283 @@
+use std::{fmt::Display, fs, net::SocketAddr, path::PathBuf, str::FromStr, thread};
+
+use serde::Deserialize;
+use time::{Duration, OffsetDateTime, format_description::well_known::Rfc3339};
+
+const CONFIG_FILE: &str = "config.toml";
+#[cfg(not(feature = "demo"))]
+const DATABASE_SCHEMES: &[&str] = &["postgres://", "postgresql://", "sqlite:"];
+
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+        pub server: ServerConfig,
+        pub database: DatabaseConfig,
+        pub token: TokenConfig
+}
+
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+        pub address: SocketAddr,
+        pub workers: usize
+}
+
+impl Default for ServerConfig {
+        fn default() -> Self {
+                Self {
+                        address: SocketAddr::from(([0, 0, 0, 0], 8080)),
+                        workers: thread::available_parallelism().map_or(1, Into::into)
+                }
+        }
+}
+
+#[derive(Debug, Clone)]
+pub struct DatabaseConfig {
+        pub url: String,
+        pub min_connections: u32,
+        pub max_connections: u32,
+        pub acquire_timeout: std::time::Duration,
+        pub idle_timeout: Option<std::time::Duration>,
+        pub max_lifetime: Option<std::time::Duration>
+}
+
+impl Default for DatabaseConfig {
+        fn default() -> Self {
+                Self {
+                        url: String::new(),
+                        min_connections: 1,
+                        max_connections: 10,
+                        acquire_timeout: std::time::Duration::from_secs(30),
+                        idle_timeout: Some(std::time::Duration::from_secs(600)),
+                        max_lifetime: Some(std::time::Duration::from_secs(1800))
+                }
+        }
+}
+
+#[derive(Debug, Clone)]
+pub struct TokenConfig {
+        pub access_expires_after: Duration,
+        pub refresh_expires_after: Duration,
+        pub calendar_expires_after: Duration,
+        pub cursor_expires_after: Duration,
+        pub secret_key: PathBuf,
+        pub public_key: PathBuf,
+        pub hash_key: PathBuf,
+        pub cursor_key: PathBuf,
+        pub key_id: Option<String>,
+        pub previous_keys: Vec<PreviousKeyConfig>
+}
+
+#[derive(Debug, Clone)]
+pub struct PreviousKeyConfig {
+        pub kid: Option<String>,
+        pub public_key: PathBuf,
+        pub retire_at: OffsetDateTime
+}
+
+impl Default for TokenConfig {
+        fn default() -> Self {
+                Self {
+                        access_expires_after: Duration::minutes(15),
+                        refresh_expires_after: Duration::days(30),
+                        calendar_expires_after: Duration::days(365),
+                        cursor_expires_after: Duration::days(1),
+                        secret_key: PathBuf::from("pkey.pem"),
+                        public_key: PathBuf::from("pkey.pub.pem"),
+                        hash_key: PathBuf::from("hash.key"),
+                        cursor_key: PathBuf::from("cursor.key"),
+                        key_id: None,
+                        previous_keys: Vec::new()
+                }
+        }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid configuration:{}", .0.iter().map(|issue| format!("\n  - {issue}")).collect::<String>())]
+pub struct ConfigError(pub Vec<String>);
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FileConfig {
+        server: FileServerConfig,
+        database: FileDatabaseConfig,
+        token: FileTokenConfig
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FileServerConfig {
+        address: Option<SocketAddr>,
+        workers: Option<usize>
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FileDatabaseConfig {
+        url: Option<String>,
+        min_connections: Option<u32>,
+        max_connections: Option<u32>,
+        acquire_timeout: Option<u64>,
+        idle_timeout: Option<u64>,
+        max_lifetime: Option<u64>
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FileTokenConfig {
+        access_expires_after: Option<i64>,
+        refresh_expires_after: Option<i64>,
+        calendar_expires_after: Option<i64>,
+        cursor_expires_after: Option<i64>,
+        secret_key: Option<PathBuf>,
+        public_key: Option<PathBuf>,
+        hash_key: Option<PathBuf>,
+        cursor_key: Option<PathBuf>,
+        key_id: Option<String>,
+        previous_keys: Vec<FilePreviousKeyConfig>
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FilePreviousKeyConfig {
+        kid: Option<String>,
+        public_key: PathBuf,
+        retire_at: String
+}
+
+struct Source<F: Fn(&str) -> Option<String>> {
+        env: F,
+        issues: Vec<String>
+}
+
+impl<F: Fn(&str) -> Option<String>> Source<F> {
+        fn optional<T: FromStr>(&mut self, var: &str, file: Option<T>) -> Option<T>
+        where T::Err: Display
+        {
+                match (self.env)(var).filter(|value| !value.trim().is_empty()) {
+                        Some(value) => match value.trim().parse() {
+                                Ok(value) => Some(value),
+                                Err(err) => {
+                                        self.issues.push(format!("{var}: {err} (got {value:?})"));
+                                        None
+                                }
+                        },
+                        None => file
+                }
+        }
+
+        fn value<T: FromStr>(&mut self, var: &str, file: Option<T>, default: T) -> T
+        where T::Err: Display
+        {
+                self.optional(var, file).unwrap_or(default)
+        }
+
+        fn seconds(&mut self, var: &str, file: Option<i64>, default: Duration) -> Duration {
+                match self.optional(var, file) {
+                        Some(seconds) if seconds <= 0 => {
+                                self.issues.push(format!("{var}: should be a positive number of seconds (got {seconds})"));
+                                default
+                        },
+                        Some(seconds) => Duration::seconds(seconds),
+                        None => default
+                }
+        }
+
+        fn std_seconds(&mut self, var: &str, file: Option<u64>) -> Option<std::time::Duration> {
+                self.optional(var, file).map(std::time::Duration::from_secs)
+        }
+}
+
+impl Config {
+        pub fn load() -> Result<Self, ConfigError> {
+                let file = match dotenvy::var("CONFIG_FILE") {
+                        Ok(path) => Some(
+                                fs::read_to_string(&path)
+                                        .map_err(|err| ConfigError(vec![format!("CONFIG_FILE: couldn't read {path}: {err}")]))?
+                        ),
+                        Err(_) => fs::read_to_string(CONFIG_FILE).ok()
+                };
+
+                Self::from_sources(|var| dotenvy::var(var).ok(), file.as_deref())
+        }
+
+        pub fn from_sources(env: impl Fn(&str) -> Option<String>, file: Option<&str>) -> Result<Self, ConfigError> {
+                let mut issues = Vec::new();
+
+                let file = match file.map(toml::from_str::<FileConfig>).transpose() {
+                        Ok(file) => file.unwrap_or_default(),
+                        Err(err) => {
+                                issues.push(format!("config file: {}", err.message().trim()));
+                                FileConfig::default()
+                        }
+                };
+
+                if env("JWT_EXPIRES_AFTER").is_some() {
+                        issues.push("JWT_EXPIRES_AFTER: no longer read, set ACCESS_EXPIRES_AFTER instead".to_string());
+                }
+
+                let mut source = Source { env, issues };
+                let defaults = Self::default();
+
+                let server = ServerConfig {
+                        address: source.value("SERVER_ADDRESS", file.server.address, defaults.server.address),
+                        workers: source.value("SERVER_WORKERS", file.server.workers, defaults.server.workers)
+                };
+
+                let database = DatabaseConfig {
+                        url: source.optional("DATABASE_URL", file.database.url).unwrap_or_default(),
+                        min_connections: source.value("MIN_CONNECTIONS", file.database.min_connections, defaults.database.min_connections),
+                        max_connections: source.value("MAX_CONNECTIONS", file.database.max_connections, defaults.database.max_connections),
+                        acquire_timeout: source.std_seconds("ACQUIRE_TIMEOUT", file.database.acquire_timeout).unwrap_or(defaults.database.acquire_timeout),
+                        idle_timeout: source.std_seconds("IDLE_TIMEOUT", file.database.idle_timeout).or(defaults.database.idle_timeout),
+                        max_lifetime: source.std_seconds("MAX_LIFETIME", file.database.max_lifetime).or(defaults.database.max_lifetime)
+                };
+
+                let mut token = TokenConfig {
+                        access_expires_after: source.seconds("ACCESS_EXPIRES_AFTER", file.token.access_expires_after, defaults.token.access_expires_after),
+                        refresh_expires_after: source.seconds("REFRESH_EXPIRES_AFTER", file.token.refresh_expires_after, defaults.token.refresh_expires_after),
+                        calendar_expires_after: source.seconds("CALENDAR_EXPIRES_AFTER", file.token.calendar_expires_after, defaults.token.calendar_expires_after),
+                        cursor_expires_after: source.seconds("CURSOR_EXPIRES_AFTER", file.token.cursor_expires_after, defaults.token.cursor_expires_after),
+                        secret_key: source.value("SECRET_KEY_FILE", file.token.secret_key, defaults.token.secret_key),
+                        public_key: source.value("PUBLIC_KEY_FILE", file.token.public_key, defaults.token.public_key),
+                        hash_key: source.value("HASH_KEY_FILE", file.token.hash_key, defaults.token.hash_key),
+                        cursor_key: source.value("CURSOR_KEY_FILE", file.token.cursor_key, defaults.token.cursor_key),
+                        key_id: source.optional("KEY_ID", file.token.key_id),
+                        previous_keys: Vec::new()
+                };
+
+                let mut issues = source.issues;
+
+                for (index, key) in file.token.previous_keys.into_iter().enumerate() {
+                        match OffsetDateTime::parse(&key.retire_at, &Rfc3339) {
+                                Ok(retire_at) => token.previous_keys.push(PreviousKeyConfig { kid: key.kid, public_key: key.public_key, retire_at }),
+                                Err(err) => issues.push(format!("token.previous_keys[{index}].retire_at: {err} (got {:?})", key.retire_at))
+                        }
+                }
+
+                if server.workers == 0 {
+                        issues.push("SERVER_WORKERS: should be greater than 0".to_string());
+                }
+                #[cfg(not(feature = "demo"))]
+                if database.url.is_empty() {
+                        issues.push("DATABASE_URL: should be set".to_string());
+                } else if !DATABASE_SCHEMES.iter().any(|scheme| database.url.starts_with(scheme)) {
+                        issues.push(format!("DATABASE_URL: should start with one of {}", DATABASE_SCHEMES.join(", ")));
+                }
+                if database.max_connections == 0 {
+                        issues.push("MAX_CONNECTIONS: should be greater than 0".to_string());
+                }
+                if database.min_connections > database.max_connections {
+                        issues.push(format!(
+                                "MIN_CONNECTIONS: should not exceed MAX_CONNECTIONS ({} > {})",
+                                database.min_connections, database.max_connections
+                        ));
+                }
+                if token.access_expires_after >= token.refresh_expires_after {
+                        issues.push("ACCESS_EXPIRES_AFTER: should be shorter than REFRESH_EXPIRES_AFTER".to_string());
+                }
+
+                match issues.is_empty() {
+                        true => Ok(Self { server, database, token }),
+                        false => Err(ConfigError(issues))
+                }
+        }
+}
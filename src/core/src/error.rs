@@ -11,6 +11,20 @@ pub enum DomainError {
         FilterOp(String),
         #[error("Wrong filter style {0}")]
         Filter(String),
+        #[error("Coordinates {0} are out of range")]
+        Coordinates(String),
+        #[error("Invalid recurrence rule {0}")]
+        Recurrence(String),
+        #[error("Invalid occurrence window {0}")]
+        Window(String),
+        #[error("Invalid cursor: {0}")]
+        Cursor(String),
+        #[error("Signing keys are already initialized")]
+        KeysInitialized,
+        #[error("Token config is already initialized")]
+        ConfigInitialized,
+        #[error("Invalid key: {0}")]
+        Key(String),
         #[error("{0}")]
         Infallible(#[from] std::convert::Infallible),
         #[error("{0}")]
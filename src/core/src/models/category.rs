@@ -0,0 +1,10 @@
+use time::PrimitiveDateTime;
+
+pub type CategoryName = String;
+
+#[derive(Debug, Clone)]
+pub struct CategoryModel {
+        pub name: CategoryName,
+        pub created_at: PrimitiveDateTime,
+        pub updated_at: PrimitiveDateTime
+}
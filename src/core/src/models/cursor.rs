@@ -0,0 +1,123 @@
+use std::fmt::Display;
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chacha20poly1305::{KeyInit, XChaCha20Poly1305, XNonce, aead::Aead};
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, TimestampMicroSeconds, TimestampSeconds};
+use sha2::{Digest, Sha256};
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+use crate::error::DomainError;
+
+use super::token::{config, cursor_key};
+
+const NONCE_LEN: usize = 24;
+
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CursorValue {
+        Int(i64),
+        Float(f64),
+        Text(String),
+        Timestamp(#[serde_as(as = "TimestampMicroSeconds<i64>")] PrimitiveDateTime),
+        TimestampTz(#[serde_as(as = "TimestampMicroSeconds<i64>")] OffsetDateTime),
+        Null
+}
+
+pub trait CursorKey<M>: Display {
+        fn cursor_value(&self, model: &M) -> CursorValue;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cursor {
+        pub order: String,
+        pub values: Vec<CursorValue>
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CursorClaims {
+        #[serde_as(as = "TimestampSeconds")]
+        exp: OffsetDateTime,
+        #[serde(flatten)]
+        cursor: Cursor
+}
+
+impl Cursor {
+        pub fn new<M, K: CursorKey<M>>(keys: &[K], model: &M) -> Self {
+                Self {
+                        order: fingerprint(keys),
+                        values: keys.iter().map(|key| key.cursor_value(model)).collect()
+                }
+        }
+
+        pub fn values<M, K: CursorKey<M>>(&self, keys: &[K]) -> Result<&[CursorValue], DomainError> {
+                if self.order != fingerprint(keys) || self.values.len() != keys.len() {
+                        return Err(DomainError::Cursor(format!("cursor was issued for order {}", self.order)));
+                }
+
+                Ok(&self.values)
+        }
+
+        pub fn encode(self) -> String {
+                let claims = CursorClaims {
+                        exp: OffsetDateTime::now_utc() + config().cursor_expires_after,
+                        cursor: self
+                };
+                let payload = serde_json::to_vec(&claims).expect("cursor should be serializable");
+
+                let mut nonce = XNonce::default();
+                OsRng.fill_bytes(&mut nonce);
+                let ciphertext = cipher().encrypt(&nonce, payload.as_slice()).expect("cursor should be encryptable");
+
+                URL_SAFE_NO_PAD.encode([nonce.as_slice(), &ciphertext].concat())
+        }
+
+        pub fn decode_from(token: &str) -> Result<Self, DomainError> {
+                let token = URL_SAFE_NO_PAD.decode(token)
+                        .map_err(|err| DomainError::Cursor(err.to_string()))?;
+                let Some((nonce, ciphertext)) = token.split_at_checked(NONCE_LEN) else {
+                        return Err(DomainError::Cursor("cursor is too short".to_string()));
+                };
+
+                let payload = cipher().decrypt(XNonce::from_slice(nonce), ciphertext)
+                        .map_err(|_| DomainError::Cursor("cursor was not issued by this server".to_string()))?;
+                let claims: CursorClaims = serde_json::from_slice(&payload)
+                        .map_err(|err| DomainError::Cursor(err.to_string()))?;
+
+                if claims.exp < OffsetDateTime::now_utc() {
+                        return Err(DomainError::Cursor("cursor has expired".to_string()));
+                }
+
+                Ok(claims.cursor)
+        }
+}
+
+fn cipher() -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(&Sha256::digest(cursor_key()))
+}
+
+fn fingerprint<K: Display>(keys: &[K]) -> String {
+        keys.iter().map(ToString::to_string).collect::<Vec<_>>().join(",")
+}
+
+#[derive(Debug, Clone)]
+pub struct CursorPage<T> {
+        pub items: Vec<T>,
+        pub limit: u32,
+        pub next: Option<Cursor>
+}
+
+impl<T> CursorPage<T> {
+        pub fn new<K: CursorKey<T>>(mut items: Vec<T>, limit: u32, keys: &[K]) -> Self {
+                let next = if items.len() > limit as usize {
+                        items.truncate(limit as usize);
+                        items.last().map(|last| Cursor::new(keys, last))
+                } else {
+                        None
+                };
+
+                Self { items, limit, next }
+        }
+}
@@ -2,7 +2,7 @@ use std::{fmt::Display, str::FromStr};
 
 use time::{OffsetDateTime, PrimitiveDateTime};
 
-use super::{utils::{FilterOp, OrderOp}, user::UserId};
+use super::{category::CategoryName, cursor::{CursorKey, CursorValue}, geo::GeoPoint, recurrence::RecurrenceRule, utils::{FilterOp, OrderOp, Timestamp, order_fields}, user::UserId};
 use crate::error::DomainError;
 
 pub type EventId = u64;
@@ -14,15 +14,38 @@ pub struct NewEvent {
         pub description: String,
         pub date: OffsetDateTime,
         pub cost: u32,
-        pub address: String
+        pub address: String,
+        pub capacity: Option<u32>,
+        pub category: Option<CategoryName>,
+        pub tags: Vec<String>,
+        pub language: SearchLanguage,
+        pub location: Option<GeoPoint>,
+        pub recurrence: Option<RecurrenceRule>
 }
 
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub enum EventStatus {
+        Draft,
+        #[default]
+        OnReview,
         Approved,
         Rejected,
-        #[default]
-        OnReview
+        Cancelled,
+        Postponed,
+        Completed
+}
+
+impl EventStatus {
+        pub fn can_transition_to(&self, status: &Self) -> bool {
+                matches!(
+                        (self, status),
+                        (Self::Draft, Self::OnReview | Self::Cancelled) |
+                        (Self::OnReview, Self::Draft | Self::Approved | Self::Rejected | Self::Cancelled) |
+                        (Self::Approved, Self::OnReview | Self::Postponed | Self::Cancelled | Self::Completed) |
+                        (Self::Rejected, Self::Draft | Self::OnReview) |
+                        (Self::Postponed, Self::OnReview | Self::Approved | Self::Cancelled)
+                )
+        }
 }
 
 impl FromStr for EventStatus {
@@ -30,9 +53,13 @@ impl FromStr for EventStatus {
 
         fn from_str(s: &str) -> Result<Self, Self::Err> {
                 match s.trim() {
+                        "Draft" => Ok(Self::Draft),
+                        "OnReview" => Ok(Self::OnReview),
                         "Approved" => Ok(Self::Approved),
                         "Rejected" => Ok(Self::Rejected),
-                        "OnReview" => Ok(Self::OnReview),
+                        "Cancelled" => Ok(Self::Cancelled),
+                        "Postponed" => Ok(Self::Postponed),
+                        "Completed" => Ok(Self::Completed),
                         _ => Err(DomainError::Parse(s.to_string()))
                 }
         }
@@ -41,9 +68,13 @@ impl FromStr for EventStatus {
 impl Display for EventStatus {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 let string = match self {
+                        Self::Draft => "Draft",
+                        Self::OnReview => "OnReview",
                         Self::Approved => "Approved",
                         Self::Rejected => "Rejected",
-                        Self::OnReview => "OnReview"
+                        Self::Cancelled => "Cancelled",
+                        Self::Postponed => "Postponed",
+                        Self::Completed => "Completed"
                 };
 
                 f.write_str(string)
@@ -59,18 +90,98 @@ pub struct EventModel {
         pub date: OffsetDateTime,
         pub cost: u32,
         pub address: String,
+        pub capacity: Option<u32>,
+        pub category: Option<CategoryName>,
+        pub tags: Vec<String>,
+        pub language: SearchLanguage,
+        pub location: Option<GeoPoint>,
+        pub distance: Option<f64>,
+        pub rank: Option<f32>,
+        pub recurrence: Option<RecurrenceRule>,
         pub status: EventStatus,
         pub created_at: PrimitiveDateTime,
         pub updated_at: PrimitiveDateTime
 }
 
+#[derive(Debug, Clone)]
+pub struct EventStatusChangeModel {
+        pub id: u64,
+        pub event_id: EventId,
+        pub from: EventStatus,
+        pub to: EventStatus,
+        pub changed_by: Option<UserId>,
+        pub created_at: PrimitiveDateTime
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct EventUpdate {
+        pub title: Option<String>,
+        pub description: Option<String>,
+        pub date: Option<OffsetDateTime>,
+        pub cost: Option<u32>,
+        pub address: Option<String>,
+        pub capacity: Option<Option<u32>>,
+        pub location: Option<Option<GeoPoint>>
+}
+
+impl EventUpdate {
+        pub fn is_empty(&self) -> bool {
+                self.title.is_none()
+                        && self.description.is_none()
+                        && self.date.is_none()
+                        && self.cost.is_none()
+                        && self.address.is_none()
+                        && self.capacity.is_none()
+                        && self.location.is_none()
+        }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum SearchLanguage {
+        #[default]
+        Simple,
+        English,
+        Russian,
+        German,
+        French,
+        Spanish
+}
+
+impl FromStr for SearchLanguage {
+        type Err = DomainError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.trim() {
+                        "simple" => Ok(Self::Simple),
+                        "english" => Ok(Self::English),
+                        "russian" => Ok(Self::Russian),
+                        "german" => Ok(Self::German),
+                        "french" => Ok(Self::French),
+                        "spanish" => Ok(Self::Spanish),
+                        _ => Err(DomainError::Parse(s.to_string()))
+                }
+        }
+}
+
+impl Display for SearchLanguage {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let string = match self {
+                        Self::Simple => "simple",
+                        Self::English => "english",
+                        Self::Russian => "russian",
+                        Self::German => "german",
+                        Self::French => "french",
+                        Self::Spanish => "spanish"
+                };
+
+                f.write_str(string)
+        }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum EventUpdate {
-        Status(EventStatus),
-        Title(String),
-        Description(String),
-        Cost(u32),
-        Address(String)
+pub struct EventSearch {
+        pub query: String,
+        pub language: Option<SearchLanguage>
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -78,7 +189,12 @@ pub enum EventFilter {
         OrganizerId(FilterOp<UserId>),
         Status(FilterOp<EventStatus>),
         Title(FilterOp<String>),
-        Cost(FilterOp<u32>)
+        Cost(FilterOp<u32>),
+        Category(FilterOp<CategoryName>),
+        Tag(FilterOp<String>),
+        Date(FilterOp<Timestamp>),
+        CreatedAt(FilterOp<Timestamp>),
+        UpdatedAt(FilterOp<Timestamp>)
 }
 
 impl FromStr for EventFilter {
@@ -92,6 +208,11 @@ impl FromStr for EventFilter {
                                 "status" => Ok(Self::Status(op.parse()?)),
                                 "title" => Ok(Self::Title(op.parse()?)),
                                 "cost" => Ok(Self::Cost(op.parse()?)),
+                                "category" => Ok(Self::Category(op.to_lowercase().parse()?)),
+                                "tag" => Ok(Self::Tag(op.to_lowercase().parse()?)),
+                                "date" => Ok(Self::Date(op.parse()?)),
+                                "created_at" => Ok(Self::CreatedAt(op.parse()?)),
+                                "updated_at" => Ok(Self::UpdatedAt(op.parse()?)),
                                 _ => Err(DomainError::Filter(s.to_string()))
                         }
                 } else {
@@ -100,46 +221,85 @@ impl FromStr for EventFilter {
         }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub enum EventOrder {
-        Id(OrderOp),
-        OrganizerId(OrderOp),
-        Cost(OrderOp),
-        Status(OrderOp),
-        CreatedAt(OrderOp),
-        UpdatedAt(OrderOp)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EventView {
+        Upcoming,
+        Past
 }
 
-impl FromStr for EventOrder {
+impl EventView {
+        pub fn filter(&self, now: OffsetDateTime) -> EventFilter {
+                match self {
+                        Self::Upcoming => EventFilter::Date(FilterOp::Gte(Timestamp(now))),
+                        Self::Past => EventFilter::Date(FilterOp::Lt(Timestamp(now)))
+                }
+        }
+}
+
+impl FromStr for EventView {
         type Err = DomainError;
 
         fn from_str(s: &str) -> Result<Self, Self::Err> {
-                if let Some((field, op)) = s.split_once(' ') {
-                        match field.trim() {
-                                "id" => Ok(Self::Id(op.parse()?)),
-                                "organizer_id" => Ok(Self::OrganizerId(op.parse()?)),
-                                "cost" => Ok(Self::Cost(op.parse()?)),
-                                "status" => Ok(Self::Status(op.parse()?)),
-                                "created_at" => Ok(Self::CreatedAt(op.parse()?)),
-                                "updated_at" => Ok(Self::UpdatedAt(op.parse()?)),
-                                _ => Err(DomainError::Order(s.to_string()))
-                        }
-                } else {
-                        match s.trim() {
-                                "id" => Ok(Self::Id(OrderOp::default())),
-                                "organizer_id" => Ok(Self::OrganizerId(OrderOp::default())),
-                                "cost" => Ok(Self::Cost(OrderOp::default())),
-                                "status" => Ok(Self::Status(OrderOp::default())),
-                                "created_at" => Ok(Self::CreatedAt(OrderOp::default())),
-                                "updated_at" => Ok(Self::UpdatedAt(OrderOp::default())),
-                                _ => Err(DomainError::Order(s.to_string()))
-                        }
+                match s.trim() {
+                        "upcoming" => Ok(Self::Upcoming),
+                        "past" => Ok(Self::Past),
+                        _ => Err(DomainError::Parse(s.to_string()))
                 }
         }
 }
 
+order_fields! {
+        EventOrder {
+                Id => "id",
+                OrganizerId => "organizer_id",
+                Cost => "cost",
+                Status => "status",
+                Date => "date",
+                CreatedAt => "created_at",
+                UpdatedAt => "updated_at",
+                Distance => "distance",
+                Rank => "rank"
+        }
+}
+
 impl Default for EventOrder {
         fn default() -> Self {
                 Self::Id(OrderOp::default())
         }
+}
+
+impl EventOrder {
+        pub fn sort_keys(order_by: &[Self], search: bool, near: bool) -> Vec<Self> {
+                let mut keys = order_by.to_vec();
+
+                if search {
+                        keys.push(Self::Rank(OrderOp::Desc));
+                }
+
+                if near {
+                        keys.push(Self::Distance(OrderOp::Asc));
+                }
+
+                if !keys.iter().any(|key| matches!(key, Self::Id(_))) {
+                        keys.push(Self::Id(OrderOp::Asc));
+                }
+
+                keys
+        }
+}
+
+impl CursorKey<EventModel> for EventOrder {
+        fn cursor_value(&self, model: &EventModel) -> CursorValue {
+                match self {
+                        Self::Id(_) => CursorValue::Int(model.id as i64),
+                        Self::OrganizerId(_) => CursorValue::Int(model.organizer_id as i64),
+                        Self::Cost(_) => CursorValue::Int(model.cost as i64),
+                        Self::Status(_) => CursorValue::Text(model.status.to_string()),
+                        Self::Date(_) => CursorValue::TimestampTz(model.date),
+                        Self::CreatedAt(_) => CursorValue::Timestamp(model.created_at),
+                        Self::UpdatedAt(_) => CursorValue::Timestamp(model.updated_at),
+                        Self::Distance(_) => model.distance.map_or(CursorValue::Null, CursorValue::Float),
+                        Self::Rank(_) => model.rank.map_or(CursorValue::Null, |rank| CursorValue::Float(rank as f64))
+                }
+        }
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use std::{fmt::Display, str::FromStr};
 use time::PrimitiveDateTime;
 
 use crate::error::DomainError;
-use super::{event::{EventId, EventModel}, user::UserId, utils::{FilterOp, OrderOp}};
+use super::{cursor::{CursorKey, CursorValue}, event::{EventId, EventModel}, user::UserId, utils::{FilterOp, OrderOp, order_fields}};
 
 #[derive(Debug, Clone)]
 pub struct FavoriteId {
@@ -53,37 +53,38 @@ impl FromStr for FavoriteFilter {
         }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub enum FavoriteOrder {
-        EventId(OrderOp),
-        CreatedAt(OrderOp),
-        UpdatedAt(OrderOp)
+order_fields! {
+        FavoriteOrder {
+                EventId => "event_id",
+                CreatedAt => "created_at",
+                UpdatedAt => "updated_at"
+        }
 }
 
-impl FromStr for FavoriteOrder {
-        type Err = DomainError;
+impl Default for FavoriteOrder {
+        fn default() -> Self {
+                Self::EventId(OrderOp::default())
+        }
+}
 
-        fn from_str(s: &str) -> Result<Self, Self::Err> {
-                if let Some((field, op)) = s.split_once(' ') {
-                        match field.trim() {
-                                "event_id" => Ok(Self::EventId(op.parse()?)),
-                                "created_at" => Ok(Self::CreatedAt(op.parse()?)),
-                                "updated_at" => Ok(Self::UpdatedAt(op.parse()?)),
-                                _ => Err(DomainError::Order(s.to_string()))
-                        }
-                } else {
-                        match s.trim() {
-                                "event_id" => Ok(Self::EventId(OrderOp::default())),
-                                "created_at" => Ok(Self::CreatedAt(OrderOp::default())),
-                                "updated_at" => Ok(Self::UpdatedAt(OrderOp::default())),
-                                _ => Err(DomainError::Order(s.to_string()))
-                        }
+impl FavoriteOrder {
+        pub fn sort_keys(order_by: &[Self]) -> Vec<Self> {
+                let mut keys = order_by.to_vec();
+
+                if !keys.iter().any(|key| matches!(key, Self::EventId(_))) {
+                        keys.push(Self::EventId(OrderOp::Asc));
                 }
+
+                keys
         }
 }
 
-impl Default for FavoriteOrder {
-        fn default() -> Self {
-                Self::EventId(OrderOp::default())
+impl CursorKey<FavoriteEventModel> for FavoriteOrder {
+        fn cursor_value(&self, model: &FavoriteEventModel) -> CursorValue {
+                match self {
+                        Self::EventId(_) => CursorValue::Int(model.event.id as i64),
+                        Self::CreatedAt(_) => CursorValue::Timestamp(model.created_at),
+                        Self::UpdatedAt(_) => CursorValue::Timestamp(model.updated_at)
+                }
         }
 }
@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use crate::error::DomainError;
+
+pub const EARTH_RADIUS_KM: f64 = 6371.0;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GeoPoint {
+        pub latitude: f64,
+        pub longitude: f64
+}
+
+impl GeoPoint {
+        pub fn new(latitude: f64, longitude: f64) -> Result<Self, DomainError> {
+                if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+                        return Err(DomainError::Coordinates(format!("{latitude},{longitude}")));
+                }
+
+                Ok(Self { latitude, longitude })
+        }
+
+        pub fn distance_km(&self, other: &GeoPoint) -> f64 {
+                let half_chord = ((other.latitude - self.latitude).to_radians() / 2.0).sin().powi(2)
+                        + self.latitude.to_radians().cos() * other.latitude.to_radians().cos() * ((other.longitude - self.longitude).to_radians() / 2.0).sin().powi(2);
+
+                EARTH_RADIUS_KM * 2.0 * half_chord.sqrt().min(1.0).asin()
+        }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GeoRadius {
+        pub center: GeoPoint,
+        pub radius_km: f64
+}
+
+impl FromStr for GeoRadius {
+        type Err = DomainError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let parts = s.split(',')
+                        .map(|part| part.trim().parse::<f64>())
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|_| DomainError::Parse(s.to_string()))?;
+
+                match parts[..] {
+                        [latitude, longitude, radius_km] if radius_km.is_finite() && radius_km >= 0.0 => Ok(Self {
+                                center: GeoPoint::new(latitude, longitude)?,
+                                radius_km
+                        }),
+                        _ => Err(DomainError::Parse(s.to_string()))
+                }
+        }
+}
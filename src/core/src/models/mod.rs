@@ -1,5 +1,13 @@
+pub mod category;
+pub mod cursor;
 pub mod event;
 pub mod favorite;
+pub mod geo;
+pub mod occurrence;
+pub mod recurrence;
+pub mod registration;
+pub mod session;
 pub mod user;
 pub mod utils;
+pub mod waitlist;
 pub mod token;
\ No newline at end of file
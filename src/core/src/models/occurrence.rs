@@ -0,0 +1,188 @@
+use std::fmt::Display;
+
+use time::{Duration, OffsetDateTime, PrimitiveDateTime};
+
+use crate::error::DomainError;
+use super::event::{EventId, EventModel};
+
+const MAX_WINDOW_DAYS: i64 = 366;
+
+#[derive(Debug, Clone)]
+pub struct OccurrenceId {
+        pub event_id: EventId,
+        pub original_date: OffsetDateTime
+}
+
+impl Display for OccurrenceId {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "event {} and date {}", self.event_id, self.original_date.unix_timestamp())
+        }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct OccurrenceOverride {
+        pub date: Option<OffsetDateTime>,
+        pub address: Option<String>,
+        pub cancelled: bool
+}
+
+#[derive(Debug, Clone)]
+pub struct OccurrenceOverrideModel {
+        pub event_id: EventId,
+        pub original_date: OffsetDateTime,
+        pub date: Option<OffsetDateTime>,
+        pub address: Option<String>,
+        pub cancelled: bool,
+        pub created_at: PrimitiveDateTime,
+        pub updated_at: PrimitiveDateTime
+}
+
+#[derive(Debug, Clone)]
+pub struct OccurrenceModel {
+        pub event: EventModel,
+        pub original_date: OffsetDateTime,
+        pub date: OffsetDateTime,
+        pub address: String,
+        pub cancelled: bool
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct OccurrenceWindow {
+        pub from: OffsetDateTime,
+        pub to: OffsetDateTime
+}
+
+impl OccurrenceWindow {
+        pub fn new(from: OffsetDateTime, to: OffsetDateTime) -> Result<Self, DomainError> {
+                if to < from || to - from > Duration::days(MAX_WINDOW_DAYS) {
+                        return Err(DomainError::Window(format!("{} - {}", from.unix_timestamp(), to.unix_timestamp())));
+                }
+
+                Ok(Self { from, to })
+        }
+
+        pub fn contains(&self, date: OffsetDateTime) -> bool {
+                self.from <= date && date <= self.to
+        }
+
+        pub fn expand(&self, event: &EventModel, overrides: &[OccurrenceOverrideModel]) -> Vec<OccurrenceModel> {
+                let overrides: Vec<&OccurrenceOverrideModel> = overrides.iter()
+                        .filter(|occurrence| occurrence.event_id == event.id)
+                        .collect();
+
+                let mut original_dates = match &event.recurrence {
+                        Some(rule) => rule.occurrences(event.date, self.from, self.to),
+                        None if self.contains(event.date) => vec![event.date],
+                        None => Vec::new()
+                };
+
+                original_dates.extend(
+                        overrides.iter()
+                                .filter(|occurrence| !self.contains(occurrence.original_date))
+                                .filter(|occurrence| occurrence.date.is_some_and(|date| self.contains(date)))
+                                .map(|occurrence| occurrence.original_date)
+                );
+
+                original_dates.into_iter()
+                        .map(|original_date| {
+                                let occurrence = overrides.iter().find(|occurrence| occurrence.original_date == original_date);
+
+                                OccurrenceModel {
+                                        event: event.clone(),
+                                        original_date,
+                                        date: occurrence.and_then(|occurrence| occurrence.date).unwrap_or(original_date),
+                                        address: occurrence.and_then(|occurrence| occurrence.address.clone()).unwrap_or_else(|| event.address.clone()),
+                                        cancelled: occurrence.is_some_and(|occurrence| occurrence.cancelled)
+                                }
+                        })
+                        .filter(|occurrence| self.contains(occurrence.date))
+                        .collect()
+        }
+}
+
+#[cfg(test)]
+mod tests {
+        use time::{Duration, OffsetDateTime, PrimitiveDateTime};
+
+        use super::{OccurrenceOverrideModel, OccurrenceWindow};
+        use crate::models::event::{EventModel, EventStatus, SearchLanguage};
+
+        const START: i64 = 1_900_000_000;
+
+        fn at(days: i64) -> OffsetDateTime {
+                OffsetDateTime::from_unix_timestamp(START).unwrap() + Duration::days(days)
+        }
+
+        fn event(recurrence: Option<&str>) -> EventModel {
+                let now = PrimitiveDateTime::new(at(0).date(), at(0).time());
+
+                EventModel {
+                        id: 1,
+                        organizer_id: 1,
+                        title: "Standup".to_string(),
+                        description: String::new(),
+                        date: at(0),
+                        cost: 0,
+                        address: "Main street 1".to_string(),
+                        capacity: None,
+                        category: None,
+                        tags: Vec::new(),
+                        language: SearchLanguage::Simple,
+                        location: None,
+                        distance: None,
+                        rank: None,
+                        recurrence: recurrence.map(|recurrence| recurrence.parse().unwrap()),
+                        status: EventStatus::Approved,
+                        created_at: now,
+                        updated_at: now
+                }
+        }
+
+        fn occurrence_override(event_id: u64, original_date: OffsetDateTime, date: Option<OffsetDateTime>, cancelled: bool) -> OccurrenceOverrideModel {
+                let now = PrimitiveDateTime::new(at(0).date(), at(0).time());
+
+                OccurrenceOverrideModel { event_id, original_date, date, address: None, cancelled, created_at: now, updated_at: now }
+        }
+
+        #[test]
+        fn window_rejects_inverted_and_oversized_ranges() {
+                assert!(OccurrenceWindow::new(at(0), at(0)).is_ok());
+                assert!(OccurrenceWindow::new(at(1), at(0)).is_err());
+                assert!(OccurrenceWindow::new(at(0), at(366)).is_ok());
+                assert!(OccurrenceWindow::new(at(0), at(367)).is_err());
+        }
+
+        #[test]
+        fn single_event_expands_only_inside_window() {
+                let event = event(None);
+
+                assert_eq!(OccurrenceWindow::new(at(0), at(1)).unwrap().expand(&event, &[]).len(), 1);
+                assert!(OccurrenceWindow::new(at(1), at(2)).unwrap().expand(&event, &[]).is_empty());
+        }
+
+        #[test]
+        fn overrides_cancel_and_move_occurrences() {
+                let event = event(Some("FREQ=DAILY;COUNT=5"));
+                let mut overrides = vec![
+                        occurrence_override(1, at(1), None, true),
+                        occurrence_override(1, at(2), Some(at(10)), false),
+                        occurrence_override(1, at(4), Some(at(1) + Duration::hours(1)), false),
+                        occurrence_override(2, at(3), None, true)
+                ];
+                overrides[0].address = Some("Side street 2".to_string());
+
+                let occurrences = OccurrenceWindow::new(at(0), at(3)).unwrap().expand(&event, &overrides);
+                let dates: Vec<_> = occurrences.iter()
+                        .map(|occurrence| (occurrence.original_date, occurrence.date, occurrence.cancelled))
+                        .collect();
+
+                assert_eq!(dates, [
+                        (at(0), at(0), false),
+                        (at(1), at(1), true),
+                        (at(3), at(3), false),
+                        (at(4), at(1) + Duration::hours(1), false)
+                ]);
+                assert_eq!(occurrences[1].address, "Side street 2");
+                assert_eq!(occurrences[0].address, "Main street 1");
+        }
+}
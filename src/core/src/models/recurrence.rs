@@ -0,0 +1,357 @@
+use std::{fmt::Display, str::FromStr};
+
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+use crate::error::DomainError;
+
+const MAX_ITERATIONS: u32 = 100_000;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Frequency {
+        Daily,
+        Weekly,
+        Monthly,
+        Yearly
+}
+
+impl FromStr for Frequency {
+        type Err = DomainError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.trim() {
+                        "DAILY" => Ok(Self::Daily),
+                        "WEEKLY" => Ok(Self::Weekly),
+                        "MONTHLY" => Ok(Self::Monthly),
+                        "YEARLY" => Ok(Self::Yearly),
+                        _ => Err(DomainError::Recurrence(s.to_string()))
+                }
+        }
+}
+
+impl Display for Frequency {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let string = match self {
+                        Self::Daily => "DAILY",
+                        Self::Weekly => "WEEKLY",
+                        Self::Monthly => "MONTHLY",
+                        Self::Yearly => "YEARLY"
+                };
+
+                f.write_str(string)
+        }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RecurrenceRule {
+        pub frequency: Frequency,
+        pub interval: u32,
+        pub count: Option<u32>,
+        pub until: Option<OffsetDateTime>,
+        pub by_day: Vec<Weekday>
+}
+
+impl RecurrenceRule {
+        pub fn occurrences(&self, start: OffsetDateTime, from: OffsetDateTime, to: OffsetDateTime) -> Vec<OffsetDateTime> {
+                let mut occurrences = Vec::new();
+                let mut produced = 0;
+
+                for step in 0..MAX_ITERATIONS {
+                        let Some(candidates) = self.candidates(start, step as i64 * self.interval as i64) else {
+                                break;
+                        };
+
+                        for candidate in candidates.into_iter().filter(|candidate| *candidate >= start) {
+                                if self.until.is_some_and(|until| candidate > until)
+                                        || self.count.is_some_and(|count| produced >= count)
+                                        || candidate > to {
+                                        return occurrences;
+                                }
+
+                                produced += 1;
+                                if candidate >= from {
+                                        occurrences.push(candidate);
+                                }
+                        }
+                }
+
+                occurrences
+        }
+
+        pub fn end(&self, start: OffsetDateTime) -> Option<OffsetDateTime> {
+                match (self.count, self.until) {
+                        (Some(_), _) => {
+                                let last = PrimitiveDateTime::new(Date::MAX, Time::MIDNIGHT).assume_utc();
+                                Some(self.occurrences(start, start, last).pop().unwrap_or(start))
+                        },
+                        (None, until) => until
+                }
+        }
+
+        pub fn is_occurrence(&self, start: OffsetDateTime, date: OffsetDateTime) -> bool {
+                self.occurrences(start, date, date).contains(&date)
+        }
+
+        fn candidates(&self, start: OffsetDateTime, offset: i64) -> Option<Vec<OffsetDateTime>> {
+                let at = |date: Date| PrimitiveDateTime::new(date, start.time()).assume_offset(start.offset());
+
+                match self.frequency {
+                        Frequency::Daily => start.checked_add(Duration::days(offset)).map(|date| vec![date]),
+                        Frequency::Weekly if self.by_day.is_empty() => start.checked_add(Duration::weeks(offset)).map(|date| vec![date]),
+                        Frequency::Weekly => {
+                                let monday = start.date()
+                                        .checked_sub(Duration::days(start.weekday().number_days_from_monday() as i64))?
+                                        .checked_add(Duration::weeks(offset))?;
+                                let mut days: Vec<Date> = self.by_day.iter()
+                                        .filter_map(|weekday| monday.checked_add(Duration::days(weekday.number_days_from_monday() as i64)))
+                                        .collect();
+                                days.sort();
+                                Some(days.into_iter().map(at).collect())
+                        },
+                        Frequency::Monthly => {
+                                let months = start.year() as i64 * 12 + start.month() as i64 - 1 + offset;
+                                let year = i32::try_from(months / 12).ok()?;
+                                let month = Month::try_from((months % 12 + 1) as u8).ok()?;
+                                calendar_date(year, month, start.day()).map(|date| date.map(at).into_iter().collect())
+                        },
+                        Frequency::Yearly => {
+                                let year = i32::try_from(start.year() as i64 + offset).ok()?;
+                                calendar_date(year, start.month(), start.day()).map(|date| date.map(at).into_iter().collect())
+                        }
+                }
+        }
+}
+
+fn calendar_date(year: i32, month: Month, day: u8) -> Option<Option<Date>> {
+        Date::from_calendar_date(year, month, 1).ok()?;
+        Some(Date::from_calendar_date(year, month, day).ok())
+}
+
+impl FromStr for RecurrenceRule {
+        type Err = DomainError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let rule = s.trim();
+                let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+
+                let mut frequency = None;
+                let mut interval = 1;
+                let mut count = None;
+                let mut until = None;
+                let mut by_day = Vec::new();
+
+                for part in rule.split(';').filter(|part| !part.is_empty()) {
+                        let Some((key, value)) = part.split_once('=') else {
+                                return Err(DomainError::Recurrence(s.to_string()));
+                        };
+
+                        match key.trim() {
+                                "FREQ" => frequency = Some(value.parse().map_err(|_| DomainError::Recurrence(s.to_string()))?),
+                                "INTERVAL" => interval = value.trim().parse().map_err(|_| DomainError::Recurrence(s.to_string()))?,
+                                "COUNT" => count = Some(value.trim().parse().map_err(|_| DomainError::Recurrence(s.to_string()))?),
+                                "UNTIL" => until = Some(parse_until(value.trim()).ok_or(DomainError::Recurrence(s.to_string()))?),
+                                "BYDAY" => by_day = value.split(',')
+                                        .map(parse_weekday)
+                                        .collect::<Option<_>>()
+                                        .ok_or(DomainError::Recurrence(s.to_string()))?,
+                                _ => return Err(DomainError::Recurrence(s.to_string()))
+                        }
+                }
+
+                let Some(frequency) = frequency else {
+                        return Err(DomainError::Recurrence(s.to_string()));
+                };
+
+                if interval == 0
+                        || count == Some(0)
+                        || (count.is_some() && until.is_some())
+                        || (!by_day.is_empty() && frequency != Frequency::Weekly) {
+                        return Err(DomainError::Recurrence(s.to_string()));
+                }
+
+                Ok(Self { frequency, interval, count, until, by_day })
+        }
+}
+
+impl Display for RecurrenceRule {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "FREQ={}", self.frequency)?;
+
+                if self.interval != 1 {
+                        write!(f, ";INTERVAL={}", self.interval)?;
+                }
+
+                if let Some(count) = self.count {
+                        write!(f, ";COUNT={count}")?;
+                }
+
+                if let Some(until) = self.until {
+                        write!(f, ";UNTIL={}", format_utc(until))?;
+                }
+
+                if !self.by_day.is_empty() {
+                        let days: Vec<&str> = self.by_day.iter().map(|weekday| format_weekday(*weekday)).collect();
+                        write!(f, ";BYDAY={}", days.join(","))?;
+                }
+
+                Ok(())
+        }
+}
+
+pub fn format_utc(date: OffsetDateTime) -> String {
+        let date = date.to_offset(UtcOffset::UTC);
+        format!(
+                "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+                date.year(), date.month() as u8, date.day(),
+                date.hour(), date.minute(), date.second()
+        )
+}
+
+fn parse_until(s: &str) -> Option<OffsetDateTime> {
+        let digits = |range: std::ops::Range<usize>| s.get(range)?.parse::<u32>().ok();
+
+        let month = Month::try_from(digits(4..6)? as u8).ok()?;
+        let date = Date::from_calendar_date(digits(0..4)? as i32, month, digits(6..8)? as u8).ok()?;
+
+        let time = match s.len() {
+                8 => Time::MIDNIGHT,
+                16 if s.get(8..9) == Some("T") && s.ends_with('Z') => Time::from_hms(digits(9..11)? as u8, digits(11..13)? as u8, digits(13..15)? as u8).ok()?,
+                _ => return None
+        };
+
+        Some(PrimitiveDateTime::new(date, time).assume_utc())
+}
+
+fn parse_weekday(s: &str) -> Option<Weekday> {
+        match s.trim() {
+                "MO" => Some(Weekday::Monday),
+                "TU" => Some(Weekday::Tuesday),
+                "WE" => Some(Weekday::Wednesday),
+                "TH" => Some(Weekday::Thursday),
+                "FR" => Some(Weekday::Friday),
+                "SA" => Some(Weekday::Saturday),
+                "SU" => Some(Weekday::Sunday),
+                _ => None
+        }
+}
+
+fn format_weekday(weekday: Weekday) -> &'static str {
+        match weekday {
+                Weekday::Monday => "MO",
+                Weekday::Tuesday => "TU",
+                Weekday::Wednesday => "WE",
+                Weekday::Thursday => "TH",
+                Weekday::Friday => "FR",
+                Weekday::Saturday => "SA",
+                Weekday::Sunday => "SU"
+        }
+}
+
+#[cfg(test)]
+mod tests {
+        use time::{OffsetDateTime, Weekday};
+
+        use super::{Frequency, RecurrenceRule, parse_until};
+
+        fn utc(s: &str) -> OffsetDateTime {
+                parse_until(s).unwrap()
+        }
+
+        fn rule(s: &str) -> RecurrenceRule {
+                s.parse().unwrap()
+        }
+
+        #[test]
+        fn weekly_by_day_starts_from_first_date() {
+                let dates = rule("FREQ=WEEKLY;BYDAY=FR,MO,WE;COUNT=5")
+                        .occurrences(utc("20300109T100000Z"), utc("20300101T000000Z"), utc("20301231T000000Z"));
+
+                assert_eq!(dates, [
+                        utc("20300109T100000Z"),
+                        utc("20300111T100000Z"),
+                        utc("20300114T100000Z"),
+                        utc("20300116T100000Z"),
+                        utc("20300118T100000Z")
+                ]);
+        }
+
+        #[test]
+        fn weekly_interval_skips_weeks() {
+                let dates = rule("FREQ=WEEKLY;INTERVAL=2;BYDAY=TU")
+                        .occurrences(utc("20300101T100000Z"), utc("20300101T000000Z"), utc("20300201T000000Z"));
+
+                assert_eq!(dates, [utc("20300101T100000Z"), utc("20300115T100000Z"), utc("20300129T100000Z")]);
+        }
+
+        #[test]
+        fn until_is_inclusive() {
+                let dates = rule("FREQ=DAILY;UNTIL=20300110T100000Z")
+                        .occurrences(utc("20300107T100000Z"), utc("20300101T000000Z"), utc("20301231T000000Z"));
+
+                assert_eq!(dates.len(), 4);
+                assert_eq!(dates.last(), Some(&utc("20300110T100000Z")));
+        }
+
+        #[test]
+        fn count_includes_occurrences_before_window() {
+                let dates = rule("FREQ=DAILY;COUNT=5")
+                        .occurrences(utc("20300107T100000Z"), utc("20300109T000000Z"), utc("20301231T000000Z"));
+
+                assert_eq!(dates, [utc("20300109T100000Z"), utc("20300110T100000Z"), utc("20300111T100000Z")]);
+        }
+
+        #[test]
+        fn window_boundaries_are_inclusive() {
+                let daily = rule("FREQ=DAILY");
+                let start = utc("20300107T100000Z");
+
+                let dates = daily.occurrences(start, utc("20300108T100000Z"), utc("20300110T100000Z"));
+                assert_eq!(dates, [utc("20300108T100000Z"), utc("20300109T100000Z"), utc("20300110T100000Z")]);
+
+                let dates = daily.occurrences(start, utc("20300108T100001Z"), utc("20300110T095959Z"));
+                assert_eq!(dates, [utc("20300109T100000Z")]);
+
+                assert!(daily.occurrences(start, utc("20300101T000000Z"), utc("20300107T095959Z")).is_empty());
+        }
+
+        #[test]
+        fn monthly_skips_missing_days() {
+                let dates = rule("FREQ=MONTHLY;COUNT=3")
+                        .occurrences(utc("20300131T100000Z"), utc("20300101T000000Z"), utc("20311231T000000Z"));
+
+                assert_eq!(dates, [utc("20300131T100000Z"), utc("20300331T100000Z"), utc("20300531T100000Z")]);
+        }
+
+        #[test]
+        fn end_is_last_occurrence() {
+                let start = utc("20300107T100000Z");
+
+                assert_eq!(rule("FREQ=WEEKLY;BYDAY=MO,WE;COUNT=3").end(start), Some(utc("20300114T100000Z")));
+                assert_eq!(rule("FREQ=DAILY;UNTIL=20300110").end(start), Some(utc("20300110T000000Z")));
+                assert_eq!(rule("FREQ=DAILY").end(start), None);
+        }
+
+        #[test]
+        fn is_occurrence_matches_exact_dates() {
+                let weekly = rule("FREQ=WEEKLY;BYDAY=MO;UNTIL=20300121T235959Z");
+                let start = utc("20300107T100000Z");
+
+                assert!(weekly.is_occurrence(start, utc("20300114T100000Z")));
+                assert!(!weekly.is_occurrence(start, utc("20300114T100001Z")));
+                assert!(!weekly.is_occurrence(start, utc("20300115T100000Z")));
+                assert!(!weekly.is_occurrence(start, utc("20300128T100000Z")));
+        }
+
+        #[test]
+        fn parses_and_formats() {
+                let weekly = rule("RRULE:FREQ=WEEKLY;INTERVAL=2;UNTIL=20300110;BYDAY=MO,FR");
+
+                assert_eq!(weekly.frequency, Frequency::Weekly);
+                assert_eq!(weekly.by_day, [Weekday::Monday, Weekday::Friday]);
+                assert_eq!(weekly.to_string(), "FREQ=WEEKLY;INTERVAL=2;UNTIL=20300110T000000Z;BYDAY=MO,FR");
+                assert_eq!(rule(&weekly.to_string()), weekly);
+
+                for invalid in ["", "FREQ=HOURLY", "FREQ=DAILY;INTERVAL=0", "FREQ=DAILY;COUNT=0", "FREQ=DAILY;COUNT=2;UNTIL=20300110", "FREQ=DAILY;BYDAY=MO", "FREQ=WEEKLY;BYDAY=XX", "FREQ=DAILY;UNTIL=2030"] {
+                        assert!(invalid.parse::<RecurrenceRule>().is_err(), "{invalid}");
+                }
+        }
+}
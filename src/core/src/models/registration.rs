@@ -0,0 +1,61 @@
+use std::{fmt::Display, str::FromStr};
+
+use time::PrimitiveDateTime;
+
+use crate::error::DomainError;
+use super::{event::EventId, user::UserId, utils::{FilterOp, OrderOp, order_fields}};
+
+#[derive(Debug, Clone)]
+pub struct RegistrationId {
+        pub user_id: UserId,
+        pub event_id: EventId
+}
+
+impl Display for RegistrationId {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "user {} and event {}", self.user_id, self.event_id)
+        }
+}
+
+#[derive(Debug, Clone)]
+pub struct RegistrationModel {
+        pub user_id: UserId,
+        pub event_id: EventId,
+        pub created_at: PrimitiveDateTime,
+        pub updated_at: PrimitiveDateTime
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RegistrationFilter {
+        UserId(FilterOp<UserId>)
+}
+
+impl FromStr for RegistrationFilter {
+        type Err = DomainError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let s = s.trim();
+                if let Some((field, op)) = s.split_once(' ') {
+                        match field.trim() {
+                                "user_id" => Ok(Self::UserId(op.parse()?)),
+                                _ => Err(DomainError::Filter(s.to_string()))
+                        }
+                } else {
+                        Err(DomainError::Filter(s.to_string()))
+                }
+        }
+}
+
+order_fields! {
+        RegistrationOrder {
+                UserId => "user_id",
+                CreatedAt => "created_at",
+                UpdatedAt => "updated_at"
+        }
+}
+
+impl Default for RegistrationOrder {
+        fn default() -> Self {
+                Self::CreatedAt(OrderOp::default())
+        }
+}
@@ -0,0 +1,63 @@
+use std::{fmt::Display, str::FromStr};
+
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use super::user::UserId;
+use crate::error::DomainError;
+
+pub type SessionId = Uuid;
+
+#[derive(Debug, Clone, Default)]
+pub struct SessionMetadata {
+        pub device: Option<String>,
+        pub user_agent: Option<String>,
+        pub ip: Option<String>
+}
+
+#[derive(Debug, Clone)]
+pub struct SessionModel {
+        pub id: SessionId,
+        pub user_id: UserId,
+        pub metadata: SessionMetadata,
+        pub created_at: PrimitiveDateTime,
+        pub last_used_at: PrimitiveDateTime,
+        pub revoked_at: Option<PrimitiveDateTime>
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecurityEventKind {
+        RefreshTokenReuse
+}
+
+impl FromStr for SecurityEventKind {
+        type Err = DomainError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.trim() {
+                        "RefreshTokenReuse" => Ok(Self::RefreshTokenReuse),
+                        _ => Err(DomainError::Parse(s.to_string()))
+                }
+        }
+}
+
+impl Display for SecurityEventKind {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let string = match self {
+                        Self::RefreshTokenReuse => "RefreshTokenReuse"
+                };
+
+                f.write_str(string)
+        }
+}
+
+#[derive(Debug, Clone)]
+pub struct SecurityEventModel {
+        pub id: u64,
+        pub user_id: UserId,
+        pub session_id: Option<SessionId>,
+        pub kind: SecurityEventKind,
+        pub user_agent: Option<String>,
+        pub ip: Option<String>,
+        pub created_at: PrimitiveDateTime
+}
@@ -1,70 +1,246 @@
-use std::{fmt::Display, fs::File, io::Read, str::FromStr, sync::LazyLock};
+use std::{fmt::Display, fs, path::Path, str::FromStr, sync::{LazyLock, OnceLock}};
 
-use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
-use serde::{Deserialize, Serialize};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use ed25519_dalek::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use hmac::{Hmac, Mac};
+use jsonwebtoken::{
+        Algorithm, DecodingKey, EncodingKey, Header, Validation,
+        errors::{Error, ErrorKind},
+        jwk::{AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm, OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse}
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use serde_with::{serde_as, TimestampSeconds, DisplayFromStr};
-use time::{Duration, OffsetDateTime};
+use sha2::{Digest, Sha256};
+use time::OffsetDateTime;
+use uuid::Uuid;
 
-use crate::error::DomainError;
+use crate::{config::TokenConfig, error::DomainError};
 
-use super::user::{UserRole, UserId};
+use super::{session::SessionId, user::{UserRole, UserId}};
 
-static PUBLIC_KEY: LazyLock<DecodingKey> = LazyLock::new(|| {
-        let mut buffer = Vec::new();
-        File::open("pkey.pub.pem")
-                .expect("file pkey.pub.pem should exist")
-                .read_to_end(&mut buffer)
-                .expect("file pkey.pub.pem should contain pub key");
+static KEYS: OnceLock<KeySet> = OnceLock::new();
+static HASH_KEY: OnceLock<Vec<u8>> = OnceLock::new();
+static CURSOR_KEY: OnceLock<Vec<u8>> = OnceLock::new();
+static CONFIG: OnceLock<TokenConfig> = OnceLock::new();
 
-        DecodingKey::from_ed_pem(&buffer)
-                .expect("file pkey.pub.pem should be valid EdDSA pub key")
-});
+const MIN_SYMMETRIC_KEY_LEN: usize = 32;
 
-static SECRET_KEY: LazyLock<EncodingKey> = LazyLock::new(|| {
-        let mut buffer = Vec::new();
-        File::open("pkey.pem")
-                .expect("file pkey.pem should exist")
-                .read_to_end(&mut buffer)
-                .expect("file pkey.pem should contain sec key");
+struct VerifyingKey {
+        key: DecodingKey,
+        jwk: Jwk,
+        retire_at: Option<OffsetDateTime>
+}
 
-        EncodingKey::from_ed_pem(&buffer)
-                .expect("file pkey.pem should be valid EdDSA sec key")
-});
+impl VerifyingKey {
+        fn new(public_key: &ed25519_dalek::VerifyingKey, kid: Option<String>, retire_at: Option<OffsetDateTime>) -> Self {
+                let x = URL_SAFE_NO_PAD.encode(public_key.as_bytes());
+                let kid = kid.unwrap_or_else(|| {
+                        let thumbprint = Sha256::digest(format!(r#"{{"crv":"Ed25519","kty":"OKP","x":"{x}"}}"#));
+                        URL_SAFE_NO_PAD.encode(thumbprint)
+                });
 
-static ACCESS_EXPIRES_AFTER: LazyLock<Duration> = LazyLock::new(|| {
-        dotenvy::var("ACCESS_EXPIRES_AFTER")
-                .expect("ACCESS_EXPIRES_AFTER var should be set")
-                .parse()
-                .map(time::Duration::seconds)
-                .expect("ACCESS_EXPIRES_AFTER should be valid u64")
-});
+                Self {
+                        key: DecodingKey::from_ed_der(public_key.as_bytes()),
+                        jwk: Jwk {
+                                common: CommonParameters {
+                                        public_key_use: Some(PublicKeyUse::Signature),
+                                        key_algorithm: Some(KeyAlgorithm::EdDSA),
+                                        key_id: Some(kid),
+                                        ..Default::default()
+                                },
+                                algorithm: AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                                        key_type: OctetKeyPairType::OctetKeyPair,
+                                        curve: EllipticCurve::Ed25519,
+                                        x
+                                })
+                        },
+                        retire_at
+                }
+        }
 
-static REFRESH_EXPIRES_AFTER: LazyLock<Duration> = LazyLock::new(|| {
-        dotenvy::var("REFRESH_EXPIRES_AFTER")
-                .expect("REFRESH_EXPIRES_AFTER var should be set")
-                .parse()
-                .map(time::Duration::seconds)
-                .expect("REFRESH_EXPIRES_AFTER should be valid u64")
-});
+        fn kid(&self) -> &str {
+                self.jwk.common.key_id.as_deref().unwrap_or_default()
+        }
+
+        fn is_active(&self, now: OffsetDateTime) -> bool {
+                self.retire_at.is_none_or(|retire_at| now < retire_at)
+        }
+}
+
+pub struct KeySet {
+        header: Header,
+        secret_key: EncodingKey,
+        keys: Vec<VerifyingKey>
+}
+
+impl KeySet {
+        pub fn from_ed_pem(secret_key: &str, public_key: &str, kid: Option<String>) -> Result<Self, DomainError> {
+                let signing_key = ed25519_dalek::SigningKey::from_pkcs8_pem(secret_key)
+                        .map_err(|err| DomainError::Key(format!("not a valid EdDSA secret key: {err}")))?;
+                let public_key = ed25519_dalek::VerifyingKey::from_public_key_pem(public_key)
+                        .map_err(|err| DomainError::Key(format!("not a valid EdDSA public key: {err}")))?;
+
+                if signing_key.verifying_key() != public_key {
+                        return Err(DomainError::Key("public key does not match the secret key".to_string()));
+                }
+
+                let key = VerifyingKey::new(&public_key, kid, None);
+                let mut header = Header::new(Algorithm::EdDSA);
+                header.kid = Some(key.kid().to_string());
+
+                Ok(Self {
+                        header,
+                        secret_key: EncodingKey::from_ed_pem(secret_key.as_bytes())
+                                .map_err(|err| DomainError::Key(format!("not a valid EdDSA secret key: {err}")))?,
+                        keys: vec![key]
+                })
+        }
+
+        pub fn with_previous_key(mut self, public_key: &str, kid: Option<String>, retire_at: OffsetDateTime) -> Result<Self, DomainError> {
+                let public_key = ed25519_dalek::VerifyingKey::from_public_key_pem(public_key)
+                        .map_err(|err| DomainError::Key(format!("not a valid EdDSA public key: {err}")))?;
+                let key = VerifyingKey::new(&public_key, kid, Some(retire_at));
+
+                if self.keys.iter().any(|existing| existing.kid() == key.kid()) {
+                        return Err(DomainError::Key(format!("kid {} is used by more than one key", key.kid())));
+                }
+
+                self.keys.push(key);
+                Ok(self)
+        }
+
+        pub fn kid(&self) -> &str {
+                self.keys[0].kid()
+        }
+
+        fn decoding_key(&self, kid: Option<&str>, now: OffsetDateTime) -> Option<&DecodingKey> {
+                match kid {
+                        Some(kid) => self.keys.iter()
+                                .find(|key| key.kid() == kid && key.is_active(now))
+                                .map(|key| &key.key),
+                        None => Some(&self.keys[0].key)
+                }
+        }
+
+        fn jwks(&self, now: OffsetDateTime) -> JwkSet {
+                JwkSet {
+                        keys: self.keys.iter()
+                                .filter(|key| key.is_active(now))
+                                .map(|key| key.jwk.clone())
+                                .collect()
+                }
+        }
+}
+
+impl std::fmt::Debug for KeySet {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct("KeySet")
+                        .field("kids", &self.keys.iter().map(VerifyingKey::kid).collect::<Vec<_>>())
+                        .finish_non_exhaustive()
+        }
+}
+
+pub fn set_config(config: TokenConfig) -> Result<(), DomainError> {
+        CONFIG.set(config)
+                .map_err(|_| DomainError::ConfigInitialized)
+}
+
+pub fn set_keys(keys: KeySet) -> Result<(), DomainError> {
+        KEYS.set(keys)
+                .map_err(|_| DomainError::KeysInitialized)
+}
+
+pub fn set_hash_key(key: Vec<u8>) -> Result<(), DomainError> {
+        HASH_KEY.set(key)
+                .map_err(|_| DomainError::KeysInitialized)
+}
+
+pub fn set_cursor_key(key: Vec<u8>) -> Result<(), DomainError> {
+        CURSOR_KEY.set(key)
+                .map_err(|_| DomainError::KeysInitialized)
+}
+
+pub fn load_keys(config: &TokenConfig) -> Result<(), DomainError> {
+        let read_key = |path: &Path| fs::read(path)
+                .map_err(|err| DomainError::Key(format!("couldn't read {}: {err}", path.display())));
+        let read_pem = |path: &Path| fs::read_to_string(path)
+                .map_err(|err| DomainError::Key(format!("couldn't read {}: {err}", path.display())));
+        let in_file = |path: &Path, err| match err {
+                DomainError::Key(detail) => DomainError::Key(format!("{}: {detail}", path.display())),
+                err => err
+        };
+
+        let mut keys = KeySet::from_ed_pem(&read_pem(&config.secret_key)?, &read_pem(&config.public_key)?, config.key_id.clone())
+                .map_err(|err| in_file(&config.secret_key, err))?;
+        for key in &config.previous_keys {
+                keys = keys.with_previous_key(&read_pem(&key.public_key)?, key.kid.clone(), key.retire_at)
+                        .map_err(|err| in_file(&key.public_key, err))?;
+        }
+
+        let read_symmetric_key = |path: &Path| match read_key(path)? {
+                key if key.len() < MIN_SYMMETRIC_KEY_LEN => Err(DomainError::Key(format!("{} should hold at least {MIN_SYMMETRIC_KEY_LEN} bytes", path.display()))),
+                key => Ok(key)
+        };
+
+        let hash_key = read_symmetric_key(&config.hash_key)?;
+        let cursor_key = read_symmetric_key(&config.cursor_key)?;
+
+        set_keys(keys)?;
+        set_hash_key(hash_key)?;
+        set_cursor_key(cursor_key)
+}
+
+pub(crate) fn config() -> &'static TokenConfig {
+        CONFIG.get_or_init(TokenConfig::default)
+}
+
+fn keys() -> &'static KeySet {
+        KEYS.get().expect("signing keys should be loaded at startup")
+}
+
+pub(crate) fn cursor_key() -> &'static [u8] {
+        CURSOR_KEY.get().expect("cursor key should be loaded at startup")
+}
+
+pub fn hash_token(token: &str) -> String {
+        let key = HASH_KEY.get().expect("hash key should be loaded at startup");
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC should accept keys of any length");
+        mac.update(token.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+}
+
+pub fn jwks() -> JwkSet {
+        keys().jwks(OffsetDateTime::now_utc())
+}
+
+pub(crate) fn encode<T: Serialize>(claims: &T) -> Result<String, Error> {
+        let keys = keys();
+        jsonwebtoken::encode(&keys.header, claims, &keys.secret_key)
+}
+
+pub(crate) fn decode<T: DeserializeOwned + Clone>(token: &str, validation: &Validation) -> Result<T, Error> {
+        let header = jsonwebtoken::decode_header(token)?;
+        let key = keys().decoding_key(header.kid.as_deref(), OffsetDateTime::now_utc())
+                .ok_or_else(|| Error::from(ErrorKind::InvalidSignature))?;
+
+        Ok(jsonwebtoken::decode(token, key, validation)?.claims)
+}
 
 static VALIDATION: LazyLock<Validation> = LazyLock::new(|| {
         let mut validation = Validation::new(Algorithm::EdDSA);
         validation.set_issuer(&["event_microservice"]);
-        validation.set_audience(&["access", "refresh"]);
+        validation.set_audience(&["access", "refresh", "calendar"]);
         validation
 });
 
-static HEADER: LazyLock<Header> = LazyLock::new(|| {
-        Header::new(Algorithm::EdDSA)
-});
-
 pub type AccessToken = String;
 pub type RefreshToken = String;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Token {
         Access,
-        Refresh
+        Refresh,
+        Calendar
 }
 
 impl FromStr for Token {
@@ -74,6 +250,7 @@ impl FromStr for Token {
                 match s {
                         "access" => Ok(Self::Access),
                         "refresh" => Ok(Self::Refresh),
+                        "calendar" => Ok(Self::Calendar),
                         _ => Err(DomainError::Parse(s.to_string()))
                 }
         }
@@ -83,7 +260,8 @@ impl Display for Token {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 match self {
                         Self::Access => f.write_str("access"),
-                        Self::Refresh => f.write_str("refresh")
+                        Self::Refresh => f.write_str("refresh"),
+                        Self::Calendar => f.write_str("calendar")
                 }
         }
 }
@@ -107,41 +285,62 @@ pub struct Claims {
         pub nbf: OffsetDateTime,
         #[serde_as(as = "TimestampSeconds")]
         pub iat: OffsetDateTime,
+        pub jti: Uuid,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub sid: Option<SessionId>,
         #[serde_as(as = "DisplayFromStr")]
         pub role: UserRole
 }
 
 impl Claims {
-        pub fn new_access(user_id: UserId, current_timestamp: OffsetDateTime, role: UserRole) -> Self {
+        pub fn new_access(user_id: UserId, session_id: SessionId, current_timestamp: OffsetDateTime, role: UserRole) -> Self {
                 Self {
                         iss: String::from("event_microservice"),
                         sub: user_id,
                         aud: Token::Access,
-                        exp: current_timestamp + *ACCESS_EXPIRES_AFTER,
+                        exp: current_timestamp + config().access_expires_after,
                         nbf: current_timestamp,
                         iat: current_timestamp,
+                        jti: Uuid::new_v4(),
+                        sid: Some(session_id),
                         role
                 }
         }
 
-        pub fn new_refresh(user_id: UserId, current_timestamp: OffsetDateTime, role: UserRole) -> Self {
+        pub fn new_refresh(user_id: UserId, session_id: SessionId, current_timestamp: OffsetDateTime, role: UserRole) -> Self {
                 Self {
                         iss: String::from("event_microservice"),
                         sub: user_id,
                         aud: Token::Refresh,
-                        exp: current_timestamp + *REFRESH_EXPIRES_AFTER,
+                        exp: current_timestamp + config().refresh_expires_after,
+                        nbf: current_timestamp,
+                        iat: current_timestamp,
+                        jti: Uuid::new_v4(),
+                        sid: Some(session_id),
+                        role
+                }
+        }
+
+        pub fn new_calendar(user_id: UserId, session_id: SessionId, current_timestamp: OffsetDateTime, role: UserRole) -> Self {
+                Self {
+                        iss: String::from("event_microservice"),
+                        sub: user_id,
+                        aud: Token::Calendar,
+                        exp: current_timestamp + config().calendar_expires_after,
                         nbf: current_timestamp,
                         iat: current_timestamp,
+                        jti: Uuid::new_v4(),
+                        sid: Some(session_id),
                         role
                 }
         }
 
-        pub fn encode(self) -> Result<String, jsonwebtoken::errors::Error> {
-                jsonwebtoken::encode(&HEADER, &self, &SECRET_KEY)
+        pub fn encode(self) -> Result<String, Error> {
+                encode(&self)
         }
 
-        pub fn decode_from(token: &str) -> Result<Self, jsonwebtoken::errors::Error> {
-                Ok(jsonwebtoken::decode(&token, &PUBLIC_KEY, &VALIDATION)?.claims)
+        pub fn decode_from(token: &str) -> Result<Self, Error> {
+                decode(token, &VALIDATION)
         }
 
         pub fn is_access(&self) -> bool {
@@ -151,4 +350,8 @@ impl Claims {
         pub fn is_refresh(&self) -> bool {
                 self.aud == Token::Refresh
         }
+
+        pub fn is_calendar(&self) -> bool {
+                self.aud == Token::Calendar
+        }
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use std::{fmt::Display, str::FromStr};
 use time::PrimitiveDateTime;
 
 use crate::error::DomainError;
-use super::utils::{FilterOp, OrderOp};
+use super::{cursor::{CursorKey, CursorValue}, utils::{FilterOp, OrderOp, order_fields}};
 
 pub type UserId = u64;
 
@@ -85,40 +85,40 @@ impl FromStr for UserFilter {
         }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub enum UserOrder {
-        Id(OrderOp),
-        Role(OrderOp),
-        CreatedAt(OrderOp),
-        UpdatedAt(OrderOp)
+order_fields! {
+        UserOrder {
+                Id => "id",
+                Role => "role",
+                CreatedAt => "created_at",
+                UpdatedAt => "updated_at"
+        }
 }
 
-impl FromStr for UserOrder {
-        type Err = DomainError;
+impl Default for UserOrder {
+        fn default() -> Self {
+                Self::Id(OrderOp::default())
+        }
+}
 
-        fn from_str(s: &str) -> Result<Self, Self::Err> {
-                if let Some((field, op)) = s.split_once(' ') {
-                        match field.trim() {
-                                "id" => Ok(Self::Id(op.parse()?)),
-                                "role" => Ok(Self::Role(op.parse()?)),
-                                "created_at" => Ok(Self::CreatedAt(op.parse()?)),
-                                "updated_at" => Ok(Self::UpdatedAt(op.parse()?)),
-                                _ => Err(DomainError::Order(s.to_string()))
-                        }
-                } else {
-                        match s.trim() {
-                                "id" => Ok(Self::Id(OrderOp::default())),
-                                "role" => Ok(Self::Role(OrderOp::default())),
-                                "created_at" => Ok(Self::CreatedAt(OrderOp::default())),
-                                "updated_at" => Ok(Self::UpdatedAt(OrderOp::default())),
-                                _ => Err(DomainError::Order(s.to_string()))
-                        }
+impl UserOrder {
+        pub fn sort_keys(order_by: &[Self]) -> Vec<Self> {
+                let mut keys = order_by.to_vec();
+
+                if !keys.iter().any(|key| matches!(key, Self::Id(_))) {
+                        keys.push(Self::Id(OrderOp::Asc));
                 }
+
+                keys
         }
 }
 
-impl Default for UserOrder {
-        fn default() -> Self {
-                Self::Id(OrderOp::default())
+impl CursorKey<UserModel> for UserOrder {
+        fn cursor_value(&self, model: &UserModel) -> CursorValue {
+                match self {
+                        Self::Id(_) => CursorValue::Int(model.id as i64),
+                        Self::Role(_) => CursorValue::Text(model.role.to_string()),
+                        Self::CreatedAt(_) => CursorValue::Timestamp(model.created_at),
+                        Self::UpdatedAt(_) => CursorValue::Timestamp(model.updated_at)
+                }
         }
 }
@@ -1,5 +1,7 @@
 use std::{fmt::Display, str::FromStr};
 
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset, format_description::well_known::Rfc3339};
+
 use crate::error::DomainError;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -8,6 +10,50 @@ pub struct Offset {
         pub limit: u32
 }
 
+impl Offset {
+        pub fn skip(&self) -> i64 {
+                i64::from(self.limit).saturating_mul(i64::from(self.page.saturating_sub(1)))
+        }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Page<T> {
+        pub items: Vec<T>,
+        pub offset: Offset,
+        pub total: u64
+}
+
+impl<T> Page<T> {
+        pub fn new(items: Vec<T>, offset: Offset, total: u64) -> Self {
+                Self { items, offset, total }
+        }
+
+        pub fn paginate(items: Vec<T>, offset: Offset) -> Self {
+                let total = items.len() as u64;
+                let items = items.into_iter()
+                        .skip(usize::try_from(offset.skip()).unwrap_or(usize::MAX))
+                        .take(offset.limit as usize)
+                        .collect();
+
+                Self { items, offset, total }
+        }
+
+        pub fn total_pages(&self) -> u64 {
+                match self.offset.limit {
+                        0 => 0,
+                        limit => self.total.div_ceil(limit as u64)
+                }
+        }
+
+        pub fn has_next(&self) -> bool {
+                (self.offset.page as u64) < self.total_pages()
+        }
+
+        pub fn has_prev(&self) -> bool {
+                self.offset.page > 1
+        }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum FilterOp<T>
 {
@@ -16,7 +62,13 @@ pub enum FilterOp<T>
         Gt(T),
         Lt(T),
         Gte(T),
-        Lte(T)
+        Lte(T),
+        In(Vec<T>),
+        Between(T, T),
+        Contains(String),
+        StartsWith(String),
+        IsNull,
+        NotNull
 }
 
 impl<T: FromStr> FromStr for FilterOp<T>
@@ -27,12 +79,36 @@ where DomainError: std::convert::From<<T as FromStr>::Err>
         fn from_str(s: &str) -> Result<Self, Self::Err> {
                 if let Some((op, value)) = s.split_once(' ') {
                         match op.trim() {
-                                "eq" => Ok(Self::Eq(value.parse()?)),
-                                "ne" => Ok(Self::Ne(value.parse()?)),
-                                "gt" => Ok(Self::Gt(value.parse()?)),
-                                "lt" => Ok(Self::Lt(value.parse()?)),
-                                "gte" => Ok(Self::Gte(value.parse()?)),
-                                "lte" => Ok(Self::Lte(value.parse()?)),
+                                "eq" => Ok(Self::Eq(value.trim().parse()?)),
+                                "ne" => Ok(Self::Ne(value.trim().parse()?)),
+                                "gt" => Ok(Self::Gt(value.trim().parse()?)),
+                                "lt" => Ok(Self::Lt(value.trim().parse()?)),
+                                "gte" => Ok(Self::Gte(value.trim().parse()?)),
+                                "lte" => Ok(Self::Lte(value.trim().parse()?)),
+                                "in" => {
+                                        let values = value.trim()
+                                                .strip_prefix('(')
+                                                .and_then(|values| values.strip_suffix(')'))
+                                                .filter(|values| !values.trim().is_empty())
+                                                .ok_or_else(|| DomainError::Filter(s.to_string()))?;
+
+                                        let values = values.split('|')
+                                                .map(|value| value.trim().parse())
+                                                .collect::<Result<_, _>>()?;
+                                        Ok(Self::In(values))
+                                },
+                                "between" => {
+                                        let (from, to) = value.split_once(" and ")
+                                                .ok_or_else(|| DomainError::Filter(s.to_string()))?;
+                                        Ok(Self::Between(from.trim().parse()?, to.trim().parse()?))
+                                },
+                                "contains" => Ok(Self::Contains(value.to_string())),
+                                "starts_with" => Ok(Self::StartsWith(value.to_string())),
+                                "is" => match value.trim() {
+                                        "null" => Ok(Self::IsNull),
+                                        "not null" => Ok(Self::NotNull),
+                                        _ => Err(DomainError::FilterOp(s.to_string()))
+                                },
                                 _ => Err(DomainError::FilterOp(s.to_string()))
                         }
                 } else {
@@ -41,26 +117,26 @@ where DomainError: std::convert::From<<T as FromStr>::Err>
         }
 }
 
-impl<T> FilterOp<T> {
-        pub fn operation(&self) -> String {
-                match self {
-                        Self::Eq(_) => String::from("="),
-                        Self::Ne(_) => String::from("!="),
-                        Self::Gt(_) => String::from(">"),
-                        Self::Lt(_) => String::from("<"),
-                        Self::Gte(_) => String::from(">="),
-                        Self::Lte(_) => String::from("<=")
-                }
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FilterGroup<F>(pub Vec<F>);
+
+impl<F> From<F> for FilterGroup<F> {
+        fn from(value: F) -> Self {
+                Self(vec![value])
         }
+}
 
-        pub fn value(&self) -> &T {
-                match self {
-                        Self::Eq(value) |
-                        Self::Ne(value) |
-                        Self::Gt(value) |
-                        Self::Lt(value) |
-                        Self::Gte(value) |
-                        Self::Lte(value) => value
+impl<F: FromStr<Err = DomainError>> FromStr for FilterGroup<F> {
+        type Err = DomainError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let s = s.trim();
+                match s.strip_prefix('(').and_then(|group| group.strip_suffix(')')) {
+                        Some(group) => group.split(" or ")
+                                .map(str::parse)
+                                .collect::<Result<_, _>>()
+                                .map(Self),
+                        None => s.parse().map(|filter| Self(vec![filter]))
                 }
         }
 }
@@ -93,3 +169,99 @@ impl Display for OrderOp {
                 }
         }
 }
+
+pub trait OrderField {
+        fn field(&self) -> (&'static str, &OrderOp);
+}
+
+macro_rules! order_fields {
+        ($name:ident { $($variant:ident => $field:literal),* $(,)? }) => {
+                #[derive(Debug, PartialEq, Eq, Clone)]
+                pub enum $name {
+                        $($variant($crate::models::utils::OrderOp)),*
+                }
+
+                impl $crate::models::utils::OrderField for $name {
+                        fn field(&self) -> (&'static str, &$crate::models::utils::OrderOp) {
+                                match self {
+                                        $(Self::$variant(op) => ($field, op)),*
+                                }
+                        }
+                }
+
+                impl std::str::FromStr for $name {
+                        type Err = $crate::error::DomainError;
+
+                        fn from_str(s: &str) -> Result<Self, Self::Err> {
+                                let (field, op) = match s.split_once(' ') {
+                                        Some((field, op)) => (field, Some(op)),
+                                        None => (s, None)
+                                };
+                                let variant: fn($crate::models::utils::OrderOp) -> Self = match field.trim() {
+                                        $($field => Self::$variant,)*
+                                        _ => return Err($crate::error::DomainError::Order(s.to_string()))
+                                };
+                                Ok(variant(op.map(str::parse).transpose()?.unwrap_or_default()))
+                        }
+                }
+
+                impl std::fmt::Display for $name {
+                        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                                let (field, op) = $crate::models::utils::OrderField::field(self);
+                                write!(f, "{field} {op}")
+                        }
+                }
+        };
+}
+
+pub(crate) use order_fields;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Timestamp(pub OffsetDateTime);
+
+impl Timestamp {
+        pub fn utc(&self) -> PrimitiveDateTime {
+                let utc = self.0.to_offset(UtcOffset::UTC);
+                PrimitiveDateTime::new(utc.date(), utc.time())
+        }
+}
+
+impl FromStr for Timestamp {
+        type Err = DomainError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let s = s.trim();
+                if let Ok(seconds) = s.parse::<i64>() {
+                        OffsetDateTime::from_unix_timestamp(seconds)
+                                .map(Self)
+                                .map_err(|_| DomainError::Parse(s.to_string()))
+                } else {
+                        OffsetDateTime::parse(s, &Rfc3339)
+                                .map(Self)
+                                .map_err(|_| DomainError::Parse(s.to_string()))
+                }
+        }
+}
+
+#[cfg(test)]
+mod tests {
+        use super::{Offset, Page};
+
+        #[test]
+        fn paginate_slices_items() {
+                let page = Page::paginate((1..=5).collect(), Offset { page: 2, limit: 2 });
+
+                assert_eq!(page.items, [3, 4]);
+                assert_eq!(page.total, 5);
+                assert!(page.has_next() && page.has_prev());
+        }
+
+        #[test]
+        fn paginate_survives_huge_pages() {
+                let page = Page::paginate((1..=5).collect::<Vec<i32>>(), Offset { page: u32::MAX, limit: u32::MAX });
+
+                assert!(page.items.is_empty());
+                assert_eq!(page.total, 5);
+                assert_eq!(Offset { page: u32::MAX, limit: u32::MAX }.skip(), i64::MAX);
+        }
+}
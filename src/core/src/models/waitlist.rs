@@ -0,0 +1,26 @@
+use std::fmt::Display;
+
+use time::PrimitiveDateTime;
+
+use super::{event::EventId, user::UserId};
+
+#[derive(Debug, Clone)]
+pub struct WaitlistId {
+        pub user_id: UserId,
+        pub event_id: EventId
+}
+
+impl Display for WaitlistId {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "user {} and event {}", self.user_id, self.event_id)
+        }
+}
+
+#[derive(Debug, Clone)]
+pub struct WaitlistModel {
+        pub user_id: UserId,
+        pub event_id: EventId,
+        pub position: u64,
+        pub created_at: PrimitiveDateTime,
+        pub updated_at: PrimitiveDateTime
+}
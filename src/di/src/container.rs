@@ -1,30 +1,77 @@
-use infrastructure::db::{refresh::postgresql::PgRefreshRepository, event::postgresql::PgEventRepository, favorite::postgresql::PgFavoriteRepository, provider::PgProvider, user::postgresql::PgUserRepository};
-use use_case::services::{refresh::RefreshService, event::EventService, favorite::FavoriteService, user::UserService};
+#[cfg(not(feature = "demo"))]
+use std::error::Error;
+
+#[cfg(feature = "demo")]
+use infrastructure::db::provider::MemoryProvider;
+#[cfg(all(feature = "sqlite", not(feature = "demo")))]
+use infrastructure::db::provider::SqliteProvider;
+#[cfg(not(feature = "demo"))]
+use infrastructure::db::{error::DbError, provider::{Backend, PgProvider}};
+use domain::config::{ConfigError, DatabaseConfig};
+use infrastructure::db::{category::repository::CategoryRepository, session::repository::SessionRepository, event::repository::EventRepository, favorite::repository::FavoriteRepository, occurrence::repository::OccurrenceRepository, provider::Provider, registration::repository::RegistrationRepository, user::repository::UserRepository, waitlist::repository::WaitlistRepository};
+use use_case::services::{category::CategoryService, session::SessionService, event::EventService, favorite::FavoriteService, occurrence::OccurrenceService, registration::RegistrationService, user::UserService, waitlist::WaitlistService};
 
 pub struct DiContainer {
-        db_provider: PgProvider
+        db_provider: Box<dyn Provider>
 }
 
 impl DiContainer {
-        pub async fn new() -> Self {
+        pub fn from_provider(provider: impl Provider + 'static) -> Self {
                 Self {
-                        db_provider: PgProvider::new().await.expect("db should construct")
+                        db_provider: Box::new(provider)
+                }
+        }
+
+        #[cfg(not(feature = "demo"))]
+        pub async fn new(config: &DatabaseConfig) -> Result<Self, ConfigError> {
+                let unavailable = |err: DbError| {
+                        let cause = err.source().map_or_else(|| err.to_string(), ToString::to_string);
+                        ConfigError(vec![format!("DATABASE_URL: couldn't set up the database: {cause}")])
+                };
+
+                match Backend::from_config(config) {
+                        Backend::Postgres => Ok(Self::from_provider(PgProvider::new(config).await.map_err(unavailable)?)),
+                        #[cfg(feature = "sqlite")]
+                        Backend::Sqlite => Ok(Self::from_provider(SqliteProvider::new(config).await.map_err(unavailable)?)),
+                        #[cfg(not(feature = "sqlite"))]
+                        Backend::Sqlite => Err(ConfigError(vec!["DATABASE_URL: points to sqlite, but the sqlite feature is disabled".to_string()]))
                 }
         }
 
-        pub fn create_user_service(&self) -> UserService<PgUserRepository> {
+        #[cfg(feature = "demo")]
+        pub async fn new(_: &DatabaseConfig) -> Result<Self, ConfigError> {
+                Ok(Self::from_provider(MemoryProvider::new()))
+        }
+
+        pub fn create_user_service(&self) -> UserService<Box<dyn UserRepository>> {
                 UserService::new(self.db_provider.provide_user_repository())
         }
 
-        pub fn create_event_service(&self) -> EventService<PgEventRepository> {
+        pub fn create_event_service(&self) -> EventService<Box<dyn EventRepository>> {
                 EventService::new(self.db_provider.provide_event_repository())
         }
 
-        pub fn create_favorite_service(&self) -> FavoriteService<PgFavoriteRepository> {
+        pub fn create_category_service(&self) -> CategoryService<Box<dyn CategoryRepository>> {
+                CategoryService::new(self.db_provider.provide_category_repository())
+        }
+
+        pub fn create_favorite_service(&self) -> FavoriteService<Box<dyn FavoriteRepository>> {
                 FavoriteService::new(self.db_provider.provide_favorite_repository())
         }
 
-        pub fn create_refresh_service(&self) -> RefreshService<PgRefreshRepository> {
-                RefreshService::new(self.db_provider.provide_refresh_repository())
+        pub fn create_occurrence_service(&self) -> OccurrenceService<Box<dyn EventRepository>, Box<dyn OccurrenceRepository>> {
+                OccurrenceService::new(self.db_provider.provide_event_repository(), self.db_provider.provide_occurrence_repository())
+        }
+
+        pub fn create_registration_service(&self) -> RegistrationService<Box<dyn EventRepository>, Box<dyn RegistrationRepository>> {
+                RegistrationService::new(self.db_provider.provide_event_repository(), self.db_provider.provide_registration_repository())
+        }
+
+        pub fn create_waitlist_service(&self) -> WaitlistService<Box<dyn EventRepository>, Box<dyn WaitlistRepository>> {
+                WaitlistService::new(self.db_provider.provide_event_repository(), self.db_provider.provide_waitlist_repository())
+        }
+
+        pub fn create_session_service(&self) -> SessionService<Box<dyn SessionRepository>> {
+                SessionService::new(self.db_provider.provide_session_repository())
         }
 }
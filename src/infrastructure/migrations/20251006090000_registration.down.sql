@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS registration_trigger_set_updated_at ON "registration";
+DROP TABLE IF EXISTS "registration";
+ALTER TABLE "event" DROP COLUMN IF EXISTS capacity;
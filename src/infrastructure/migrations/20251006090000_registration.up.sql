@@ -0,0 +1,18 @@
+-- Add up migration script here
+ALTER TABLE "event" ADD COLUMN IF NOT EXISTS capacity INT CHECK (capacity >= 0);
+
+DROP TABLE IF EXISTS "registration";
+CREATE TABLE "registration" (
+        user_id    BIGINT    NOT NULL REFERENCES "user"(id) ON DELETE CASCADE,
+        event_id   BIGINT    NOT NULL REFERENCES "event"(id) ON DELETE CASCADE,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY(user_id, event_id)
+);
+
+CREATE INDEX IF NOT EXISTS registration_event_id_idx ON "registration"(event_id);
+
+CREATE OR REPLACE TRIGGER registration_trigger_set_updated_at
+BEFORE UPDATE ON "registration"
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
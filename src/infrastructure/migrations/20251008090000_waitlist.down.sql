@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS waitlist_trigger_set_updated_at ON "waitlist";
+DROP TABLE IF EXISTS "waitlist";
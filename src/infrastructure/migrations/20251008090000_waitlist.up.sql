@@ -0,0 +1,17 @@
+-- Add up migration script here
+DROP TABLE IF EXISTS "waitlist";
+CREATE TABLE "waitlist" (
+        id         BIGSERIAL NOT NULL UNIQUE,
+        user_id    BIGINT    NOT NULL REFERENCES "user"(id) ON DELETE CASCADE,
+        event_id   BIGINT    NOT NULL REFERENCES "event"(id) ON DELETE CASCADE,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY(user_id, event_id)
+);
+
+CREATE INDEX IF NOT EXISTS waitlist_event_id_idx ON "waitlist"(event_id, id);
+
+CREATE OR REPLACE TRIGGER waitlist_trigger_set_updated_at
+BEFORE UPDATE ON "waitlist"
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
@@ -0,0 +1,12 @@
+-- Add up migration script here
+DROP TABLE IF EXISTS "event_status_history";
+CREATE TABLE "event_status_history" (
+        id          BIGSERIAL NOT NULL PRIMARY KEY,
+        event_id    BIGINT    NOT NULL REFERENCES "event"(id) ON DELETE CASCADE,
+        from_status TEXT      NOT NULL,
+        to_status   TEXT      NOT NULL,
+        changed_by  BIGINT    REFERENCES "user"(id) ON DELETE SET NULL,
+        created_at  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS event_status_history_event_id_idx ON "event_status_history"(event_id, id);
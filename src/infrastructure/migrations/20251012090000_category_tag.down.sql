@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS "event_tag";
+ALTER TABLE "event" DROP COLUMN IF EXISTS category;
+DROP TRIGGER IF EXISTS category_trigger_set_updated_at ON "category";
+DROP TABLE IF EXISTS "category";
@@ -0,0 +1,28 @@
+-- Add up migration script here
+DROP TABLE IF EXISTS "category";
+CREATE TABLE "category" (
+        name       TEXT      NOT NULL PRIMARY KEY,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE OR REPLACE TRIGGER category_trigger_set_updated_at
+BEFORE UPDATE ON "category"
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+INSERT INTO "category" (name)
+VALUES ('concert'), ('conference'), ('exhibition'), ('festival'), ('meetup'), ('sport'), ('theatre'), ('workshop');
+
+ALTER TABLE "event" ADD COLUMN IF NOT EXISTS category TEXT REFERENCES "category"(name) ON DELETE SET NULL ON UPDATE CASCADE;
+
+CREATE INDEX IF NOT EXISTS event_category_idx ON "event"(category);
+
+DROP TABLE IF EXISTS "event_tag";
+CREATE TABLE "event_tag" (
+        event_id BIGINT NOT NULL REFERENCES "event"(id) ON DELETE CASCADE,
+        tag      TEXT   NOT NULL,
+        PRIMARY KEY(event_id, tag)
+);
+
+CREATE INDEX IF NOT EXISTS event_tag_tag_idx ON "event_tag"(tag);
@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS event_search_simple_idx;
+DROP INDEX IF EXISTS event_search_idx;
+ALTER TABLE "event" DROP COLUMN IF EXISTS search_simple;
+ALTER TABLE "event" DROP COLUMN IF EXISTS search;
+ALTER TABLE "event" DROP COLUMN IF EXISTS language;
@@ -0,0 +1,17 @@
+-- Add up migration script here
+ALTER TABLE "event" ADD COLUMN IF NOT EXISTS language REGCONFIG NOT NULL DEFAULT 'simple';
+
+ALTER TABLE "event" ADD COLUMN IF NOT EXISTS search TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector(language, title), 'A') ||
+        setweight(to_tsvector(language, description), 'B') ||
+        setweight(to_tsvector(language, address), 'C')
+) STORED;
+
+ALTER TABLE "event" ADD COLUMN IF NOT EXISTS search_simple TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', title), 'A') ||
+        setweight(to_tsvector('simple', description), 'B') ||
+        setweight(to_tsvector('simple', address), 'C')
+) STORED;
+
+CREATE INDEX IF NOT EXISTS event_search_idx ON "event" USING GIN(search);
+CREATE INDEX IF NOT EXISTS event_search_simple_idx ON "event" USING GIN(search_simple);
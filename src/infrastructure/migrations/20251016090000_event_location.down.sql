@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS event_location_idx;
+ALTER TABLE "event" DROP CONSTRAINT IF EXISTS event_location_check;
+ALTER TABLE "event" DROP COLUMN IF EXISTS longitude;
+ALTER TABLE "event" DROP COLUMN IF EXISTS latitude;
@@ -0,0 +1,6 @@
+-- Add up migration script here
+ALTER TABLE "event" ADD COLUMN IF NOT EXISTS latitude DOUBLE PRECISION CHECK (latitude BETWEEN -90 AND 90);
+ALTER TABLE "event" ADD COLUMN IF NOT EXISTS longitude DOUBLE PRECISION CHECK (longitude BETWEEN -180 AND 180);
+ALTER TABLE "event" ADD CONSTRAINT event_location_check CHECK ((latitude IS NULL) = (longitude IS NULL));
+
+CREATE INDEX IF NOT EXISTS event_location_idx ON "event"(latitude, longitude);
@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS event_occurrence_trigger_set_updated_at ON "event_occurrence";
+DROP TABLE IF EXISTS "event_occurrence";
+DROP INDEX IF EXISTS event_date_idx;
+ALTER TABLE "event" DROP COLUMN IF EXISTS recurrence;
+ALTER TABLE "event" ADD CONSTRAINT event_title_key UNIQUE (title);
@@ -0,0 +1,24 @@
+-- Add up migration script here
+ALTER TABLE "event" DROP CONSTRAINT IF EXISTS event_title_key;
+ALTER TABLE "event" ADD COLUMN IF NOT EXISTS recurrence TEXT;
+
+CREATE INDEX IF NOT EXISTS event_date_idx ON "event"(date);
+
+DROP TABLE IF EXISTS "event_occurrence";
+CREATE TABLE "event_occurrence" (
+        event_id      BIGINT      NOT NULL REFERENCES "event"(id) ON DELETE CASCADE,
+        original_date TIMESTAMPTZ NOT NULL,
+        date          TIMESTAMPTZ,
+        address       TEXT,
+        cancelled     BOOLEAN     NOT NULL DEFAULT FALSE,
+        created_at    TIMESTAMP   NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at    TIMESTAMP   NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY(event_id, original_date)
+);
+
+CREATE INDEX IF NOT EXISTS event_occurrence_date_idx ON "event_occurrence"(event_id, date);
+
+CREATE OR REPLACE TRIGGER event_occurrence_trigger_set_updated_at
+BEFORE UPDATE ON "event_occurrence"
+FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
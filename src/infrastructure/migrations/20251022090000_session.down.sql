@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS "session";
+CREATE TABLE "refresh" (
+        user_id BIGINT NOT NULL PRIMARY KEY REFERENCES "user"(id) ON DELETE CASCADE,
+        token   TEXT   NOT NULL
+);
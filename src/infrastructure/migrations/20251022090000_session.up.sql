@@ -0,0 +1,16 @@
+-- Add up migration script here
+DROP TABLE IF EXISTS "refresh";
+DROP TABLE IF EXISTS "session";
+CREATE TABLE "session" (
+        id           UUID      NOT NULL PRIMARY KEY,
+        user_id      BIGINT    NOT NULL REFERENCES "user"(id) ON DELETE CASCADE,
+        token        TEXT      NOT NULL,
+        device       TEXT,
+        user_agent   TEXT,
+        ip           TEXT,
+        created_at   TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        last_used_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        revoked_at   TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS session_user_id_idx ON "session"(user_id, last_used_at);
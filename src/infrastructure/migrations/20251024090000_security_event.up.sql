@@ -0,0 +1,13 @@
+-- Add up migration script here
+DROP TABLE IF EXISTS "security_event";
+CREATE TABLE "security_event" (
+        id         BIGSERIAL NOT NULL PRIMARY KEY,
+        user_id    BIGINT    NOT NULL REFERENCES "user"(id) ON DELETE CASCADE,
+        session_id UUID      REFERENCES "session"(id) ON DELETE SET NULL,
+        kind       TEXT      NOT NULL,
+        user_agent TEXT,
+        ip         TEXT,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS security_event_user_id_idx ON "security_event"(user_id, id);
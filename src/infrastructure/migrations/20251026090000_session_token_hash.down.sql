@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE "session" ADD COLUMN IF NOT EXISTS token TEXT NOT NULL DEFAULT '';
+UPDATE "session" SET revoked_at = CURRENT_TIMESTAMP WHERE revoked_at IS NULL;
+ALTER TABLE "session" DROP COLUMN IF EXISTS token_hash;
@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE "session" ADD COLUMN IF NOT EXISTS token_hash TEXT;
+UPDATE "session" SET revoked_at = CURRENT_TIMESTAMP WHERE revoked_at IS NULL;
+ALTER TABLE "session" DROP COLUMN IF EXISTS token;
@@ -0,0 +1,4 @@
+-- Add up migration script here
+INSERT INTO "category" (name)
+VALUES ('music')
+ON CONFLICT (name) DO NOTHING;
@@ -0,0 +1,2 @@
+-- Add up migration script here
+ALTER TABLE "event" ADD COLUMN IF NOT EXISTS recurrence_end TIMESTAMPTZ;
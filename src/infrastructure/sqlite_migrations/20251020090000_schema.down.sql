@@ -0,0 +1,15 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS "refresh";
+DROP TABLE IF EXISTS "waitlist";
+DROP TABLE IF EXISTS "registration";
+DROP TABLE IF EXISTS "favorite";
+DROP TABLE IF EXISTS "event_occurrence";
+DROP TABLE IF EXISTS "event_status_history";
+DROP TABLE IF EXISTS "event_tag";
+DROP TRIGGER IF EXISTS event_search_update;
+DROP TRIGGER IF EXISTS event_search_delete;
+DROP TRIGGER IF EXISTS event_search_insert;
+DROP TABLE IF EXISTS "event_search";
+DROP TABLE IF EXISTS "event";
+DROP TABLE IF EXISTS "category";
+DROP TABLE IF EXISTS "user";
@@ -0,0 +1,123 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS "user" (
+        id            INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+        login         TEXT    NOT NULL UNIQUE,
+        password_hash TEXT    NOT NULL,
+        role          TEXT    NOT NULL DEFAULT 'User',
+        created_at    TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        updated_at    TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now'))
+);
+
+CREATE TABLE IF NOT EXISTS "category" (
+        name       TEXT NOT NULL PRIMARY KEY,
+        created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now'))
+);
+
+INSERT OR IGNORE INTO "category" (name)
+VALUES ('concert'), ('conference'), ('exhibition'), ('festival'), ('meetup'), ('sport'), ('theatre'), ('workshop');
+
+CREATE TABLE IF NOT EXISTS "event" (
+        id           INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+        organizer_id INTEGER NOT NULL REFERENCES "user"(id) ON DELETE CASCADE,
+        title        TEXT    NOT NULL,
+        description  TEXT    NOT NULL,
+        date         TEXT    NOT NULL,
+        cost         INTEGER NOT NULL,
+        address      TEXT    NOT NULL,
+        capacity     INTEGER CHECK (capacity >= 0),
+        category     TEXT    REFERENCES "category"(name) ON DELETE SET NULL ON UPDATE CASCADE,
+        language     TEXT    NOT NULL DEFAULT 'simple',
+        latitude     REAL    CHECK (latitude BETWEEN -90 AND 90),
+        longitude    REAL    CHECK (longitude BETWEEN -180 AND 180),
+        recurrence   TEXT,
+        status       TEXT    NOT NULL DEFAULT 'OnReview',
+        created_at   TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        updated_at   TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        CHECK ((latitude IS NULL) = (longitude IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS event_date_idx ON "event"(date);
+CREATE INDEX IF NOT EXISTS event_category_idx ON "event"(category);
+CREATE INDEX IF NOT EXISTS event_location_idx ON "event"(latitude, longitude);
+
+CREATE VIRTUAL TABLE IF NOT EXISTS "event_search" USING fts5(title, description, address, content = 'event', content_rowid = 'id');
+
+CREATE TRIGGER IF NOT EXISTS event_search_insert AFTER INSERT ON "event" BEGIN
+        INSERT INTO "event_search" (rowid, title, description, address) VALUES (NEW.id, NEW.title, NEW.description, NEW.address);
+END;
+
+CREATE TRIGGER IF NOT EXISTS event_search_delete AFTER DELETE ON "event" BEGIN
+        INSERT INTO "event_search" ("event_search", rowid, title, description, address) VALUES ('delete', OLD.id, OLD.title, OLD.description, OLD.address);
+END;
+
+CREATE TRIGGER IF NOT EXISTS event_search_update AFTER UPDATE OF title, description, address ON "event" BEGIN
+        INSERT INTO "event_search" ("event_search", rowid, title, description, address) VALUES ('delete', OLD.id, OLD.title, OLD.description, OLD.address);
+        INSERT INTO "event_search" (rowid, title, description, address) VALUES (NEW.id, NEW.title, NEW.description, NEW.address);
+END;
+
+CREATE TABLE IF NOT EXISTS "event_tag" (
+        event_id INTEGER NOT NULL REFERENCES "event"(id) ON DELETE CASCADE,
+        tag      TEXT    NOT NULL,
+        PRIMARY KEY(event_id, tag)
+);
+
+CREATE INDEX IF NOT EXISTS event_tag_tag_idx ON "event_tag"(tag);
+
+CREATE TABLE IF NOT EXISTS "event_status_history" (
+        id          INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+        event_id    INTEGER NOT NULL REFERENCES "event"(id) ON DELETE CASCADE,
+        from_status TEXT    NOT NULL,
+        to_status   TEXT    NOT NULL,
+        changed_by  INTEGER REFERENCES "user"(id) ON DELETE SET NULL,
+        created_at  TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS event_status_history_event_id_idx ON "event_status_history"(event_id, id);
+
+CREATE TABLE IF NOT EXISTS "event_occurrence" (
+        event_id      INTEGER NOT NULL REFERENCES "event"(id) ON DELETE CASCADE,
+        original_date TEXT    NOT NULL,
+        date          TEXT,
+        address       TEXT,
+        cancelled     INTEGER NOT NULL DEFAULT FALSE,
+        created_at    TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        updated_at    TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        PRIMARY KEY(event_id, original_date)
+);
+
+CREATE INDEX IF NOT EXISTS event_occurrence_date_idx ON "event_occurrence"(event_id, date);
+
+CREATE TABLE IF NOT EXISTS "favorite" (
+        user_id    INTEGER NOT NULL REFERENCES "user"(id) ON DELETE CASCADE,
+        event_id   INTEGER NOT NULL REFERENCES "event"(id) ON DELETE CASCADE,
+        created_at TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        updated_at TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        PRIMARY KEY(user_id, event_id)
+);
+
+CREATE TABLE IF NOT EXISTS "registration" (
+        user_id    INTEGER NOT NULL REFERENCES "user"(id) ON DELETE CASCADE,
+        event_id   INTEGER NOT NULL REFERENCES "event"(id) ON DELETE CASCADE,
+        created_at TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        updated_at TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        PRIMARY KEY(user_id, event_id)
+);
+
+CREATE INDEX IF NOT EXISTS registration_event_id_idx ON "registration"(event_id);
+
+CREATE TABLE IF NOT EXISTS "waitlist" (
+        id         INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+        user_id    INTEGER NOT NULL REFERENCES "user"(id) ON DELETE CASCADE,
+        event_id   INTEGER NOT NULL REFERENCES "event"(id) ON DELETE CASCADE,
+        created_at TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        updated_at TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        UNIQUE(user_id, event_id)
+);
+
+CREATE INDEX IF NOT EXISTS waitlist_event_id_idx ON "waitlist"(event_id, id);
+
+CREATE TABLE IF NOT EXISTS "refresh" (
+        user_id INTEGER NOT NULL PRIMARY KEY REFERENCES "user"(id) ON DELETE CASCADE,
+        token   TEXT    NOT NULL
+);
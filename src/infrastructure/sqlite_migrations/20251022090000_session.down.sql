@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS "session";
+CREATE TABLE IF NOT EXISTS "refresh" (
+        user_id INTEGER NOT NULL PRIMARY KEY REFERENCES "user"(id) ON DELETE CASCADE,
+        token   TEXT    NOT NULL
+);
@@ -0,0 +1,15 @@
+-- Add up migration script here
+DROP TABLE IF EXISTS "refresh";
+CREATE TABLE IF NOT EXISTS "session" (
+        id           BLOB    NOT NULL PRIMARY KEY,
+        user_id      INTEGER NOT NULL REFERENCES "user"(id) ON DELETE CASCADE,
+        token        TEXT    NOT NULL,
+        device       TEXT,
+        user_agent   TEXT,
+        ip           TEXT,
+        created_at   TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        last_used_at TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+        revoked_at   TEXT
+);
+
+CREATE INDEX IF NOT EXISTS session_user_id_idx ON "session"(user_id, last_used_at);
@@ -0,0 +1,12 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS "security_event" (
+        id         INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+        user_id    INTEGER NOT NULL REFERENCES "user"(id) ON DELETE CASCADE,
+        session_id BLOB    REFERENCES "session"(id) ON DELETE SET NULL,
+        kind       TEXT    NOT NULL,
+        user_agent TEXT,
+        ip         TEXT,
+        created_at TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS security_event_user_id_idx ON "security_event"(user_id, id);
@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE "session" ADD COLUMN token_hash TEXT;
+UPDATE "session" SET revoked_at = strftime('%Y-%m-%d %H:%M:%f', 'now') WHERE revoked_at IS NULL;
+ALTER TABLE "session" DROP COLUMN token;
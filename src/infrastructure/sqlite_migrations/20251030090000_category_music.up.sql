@@ -0,0 +1,3 @@
+-- Add up migration script here
+INSERT OR IGNORE INTO "category" (name)
+VALUES ('music');
@@ -0,0 +1,21 @@
+use domain::models::category::CategoryModel;
+use sqlx::FromRow;
+
+use time::PrimitiveDateTime;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct CategoryEntity {
+        pub name: String,
+        pub created_at: PrimitiveDateTime,
+        pub updated_at: PrimitiveDateTime
+}
+
+impl From<CategoryEntity> for CategoryModel {
+        fn from(value: CategoryEntity) -> Self {
+                CategoryModel {
+                        name: value.name,
+                        created_at: value.created_at,
+                        updated_at: value.updated_at
+                }
+        }
+}
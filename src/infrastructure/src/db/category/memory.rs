@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+use super::entity::CategoryEntity;
+use super::repository::CategoryRepository;
+use crate::db::memory::{MemoryStore, now, unique_violation};
+use crate::Result;
+
+pub struct MemoryCategoryRepository {
+        store: MemoryStore
+}
+
+impl MemoryCategoryRepository {
+        pub fn new(store: MemoryStore) -> Self {
+                Self { store }
+        }
+}
+
+#[async_trait]
+impl CategoryRepository for MemoryCategoryRepository {
+        async fn list(&self) -> Result<Vec<CategoryEntity>> {
+                Ok(self.store.read().categories.values().cloned().collect())
+        }
+
+        async fn create(&self, name: &str) -> Result<CategoryEntity> {
+                let mut tables = self.store.write();
+
+                if tables.categories.contains_key(name) {
+                        return Err(unique_violation("category", "category_pkey"));
+                }
+
+                let created_at = now();
+                let category = CategoryEntity { name: name.to_string(), created_at, updated_at: created_at };
+                tables.categories.insert(category.name.clone(), category.clone());
+
+                Ok(category)
+        }
+
+        async fn delete(&self, name: &str) -> Result<Option<CategoryEntity>> {
+                Ok(self.store.write().delete_category(name))
+        }
+}
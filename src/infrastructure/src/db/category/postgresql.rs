@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+
+use super::entity::CategoryEntity;
+use super::repository::CategoryRepository;
+use crate::Result;
+
+pub struct PgCategoryRepository {
+        pool: Pool<Postgres>
+}
+
+impl PgCategoryRepository {
+        pub fn new(pool: Pool<Postgres>) -> Self {
+                Self { pool }
+        }
+}
+
+#[async_trait]
+impl CategoryRepository for PgCategoryRepository {
+        async fn list(&self) -> Result<Vec<CategoryEntity>> {
+                sqlx::query_as(
+                        r#"
+                        SELECT name, created_at, updated_at
+                        FROM "category"
+                        ORDER BY name
+                        "#
+                )
+                .fetch_all(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn create(&self, name: &str) -> Result<CategoryEntity> {
+                sqlx::query_as(
+                        r#"
+                        INSERT INTO "category" (name)
+                        VALUES ($1)
+                        RETURNING name, created_at, updated_at
+                        "#
+                )
+                .bind(name)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn delete(&self, name: &str) -> Result<Option<CategoryEntity>> {
+                sqlx::query_as(
+                        r#"
+                        DELETE FROM "category"
+                        WHERE name = $1
+                        RETURNING name, created_at, updated_at
+                        "#
+                )
+                .bind(name)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+}
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+use super::entity::CategoryEntity;
+use crate::Result;
+
+#[async_trait]
+pub trait CategoryRepository: Send + Sync {
+        async fn list(&self) -> Result<Vec<CategoryEntity>>;
+        async fn create(&self, name: &str) -> Result<CategoryEntity>;
+        async fn delete(&self, name: &str) -> Result<Option<CategoryEntity>>;
+}
+
+#[async_trait]
+impl<T: CategoryRepository + ?Sized> CategoryRepository for Box<T> {
+        async fn list(&self) -> Result<Vec<CategoryEntity>> {
+                (**self).list().await
+        }
+
+        async fn create(&self, name: &str) -> Result<CategoryEntity> {
+                (**self).create(name).await
+        }
+
+        async fn delete(&self, name: &str) -> Result<Option<CategoryEntity>> {
+                (**self).delete(name).await
+        }
+}
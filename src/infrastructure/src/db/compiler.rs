@@ -0,0 +1,139 @@
+use domain::models::{cursor::CursorValue, utils::{FilterGroup, FilterOp, Offset, OrderField}};
+use sqlx::{Database, Encode, Postgres, QueryBuilder, Type, query_builder::Separated};
+#[cfg(feature = "sqlite")]
+use sqlx::Sqlite;
+
+pub trait Dialect: Database {
+        const LIKE: &'static str;
+        const ESCAPE: &'static str;
+
+        fn push_value<'args>(separated: &mut Separated<'_, 'args, Self, &'static str>, value: &'args CursorValue);
+}
+
+impl Dialect for Postgres {
+        const LIKE: &'static str = "::TEXT ILIKE ";
+        const ESCAPE: &'static str = "";
+
+        fn push_value<'args>(separated: &mut Separated<'_, 'args, Self, &'static str>, value: &'args CursorValue) {
+                match value {
+                        CursorValue::Null => separated.push_unseparated("NULL"),
+                        CursorValue::Int(value) => separated.push_bind_unseparated(*value),
+                        CursorValue::Float(value) => separated.push_bind_unseparated(*value),
+                        CursorValue::Text(value) => separated.push_bind_unseparated(value.as_str()),
+                        CursorValue::Timestamp(value) => separated.push_bind_unseparated(*value),
+                        CursorValue::TimestampTz(value) => separated.push_bind_unseparated(*value)
+                };
+        }
+}
+
+#[cfg(feature = "sqlite")]
+impl Dialect for Sqlite {
+        const LIKE: &'static str = " LIKE ";
+        const ESCAPE: &'static str = r" ESCAPE '\'";
+
+        fn push_value<'args>(separated: &mut Separated<'_, 'args, Self, &'static str>, value: &'args CursorValue) {
+                use super::sqlite::{timestamp, timestamptz};
+
+                match value {
+                        CursorValue::Null => separated.push_unseparated("NULL"),
+                        CursorValue::Int(value) => separated.push_bind_unseparated(*value),
+                        CursorValue::Float(value) => separated.push_bind_unseparated(*value),
+                        CursorValue::Text(value) => separated.push_bind_unseparated(value.as_str()),
+                        CursorValue::Timestamp(value) => separated.push_bind_unseparated(timestamp(value)),
+                        CursorValue::TimestampTz(value) => separated.push_bind_unseparated(timestamptz(value))
+                };
+        }
+}
+
+pub trait FilterColumn<DB: Database> {
+        fn push_filter<'args>(&'args self, separated: &mut Separated<'_, 'args, DB, &'static str>);
+}
+
+pub trait OrderColumn: OrderField {
+        const QUALIFIER: &'static str = "";
+}
+
+pub fn push_filters<'args, DB: Database, F: FilterColumn<DB>>(separated: &mut Separated<'_, 'args, DB, &'static str>, filters: &'args [FilterGroup<F>]) {
+        for FilterGroup(group) in filters {
+                separated.push("(FALSE");
+
+                for filter in group {
+                        separated.push_unseparated(" OR ");
+                        filter.push_filter(separated);
+                }
+
+                separated.push_unseparated(")");
+        }
+}
+
+pub fn push_order<DB: Database, O: OrderColumn>(query_builder: &mut QueryBuilder<'_, DB>, order_by: &[O]) {
+        if !order_by.is_empty() {
+                let mut separated = query_builder.separated(", ");
+                separated.push_unseparated(" ORDER BY ");
+
+                for (column, op) in order_by.iter().map(OrderField::field) {
+                        separated.push(O::QUALIFIER).push_unseparated(column).push_unseparated(" ").push_unseparated(op.to_string());
+                }
+        }
+}
+
+pub fn push_offset<'args, DB: Database>(query_builder: &mut QueryBuilder<'args, DB>, offset: &Offset)
+where i64: Encode<'args, DB> + Type<DB>
+{
+        query_builder.push(" LIMIT ").push_bind(i64::from(offset.limit));
+        query_builder.push(" OFFSET ").push_bind(offset.skip());
+}
+
+pub fn push_limit<'args, DB: Database>(query_builder: &mut QueryBuilder<'args, DB>, limit: u32)
+where i64: Encode<'args, DB> + Type<DB>
+{
+        query_builder.push(" LIMIT ").push_bind(i64::from(limit));
+}
+
+pub fn push_condition<'args, DB: Dialect, T, V>(
+        separated: &mut Separated<'_, 'args, DB, &'static str>,
+        column: &'static str,
+        op: &'args FilterOp<T>,
+        bind: impl Fn(&'args T) -> V
+)
+where
+        V: 'args + Encode<'args, DB> + Type<DB>,
+        String: Encode<'args, DB> + Type<DB>
+{
+        separated.push_unseparated(column);
+
+        match op {
+                FilterOp::Eq(value) => separated.push_unseparated(" = ").push_bind_unseparated(bind(value)),
+                FilterOp::Ne(value) => separated.push_unseparated(" != ").push_bind_unseparated(bind(value)),
+                FilterOp::Gt(value) => separated.push_unseparated(" > ").push_bind_unseparated(bind(value)),
+                FilterOp::Lt(value) => separated.push_unseparated(" < ").push_bind_unseparated(bind(value)),
+                FilterOp::Gte(value) => separated.push_unseparated(" >= ").push_bind_unseparated(bind(value)),
+                FilterOp::Lte(value) => separated.push_unseparated(" <= ").push_bind_unseparated(bind(value)),
+                FilterOp::In(values) => {
+                        separated.push_unseparated(" IN (");
+                        for (position, value) in values.iter().enumerate() {
+                                if position > 0 {
+                                        separated.push_unseparated(", ");
+                                }
+                                separated.push_bind_unseparated(bind(value));
+                        }
+                        separated.push_unseparated(")")
+                },
+                FilterOp::Between(from, to) => separated
+                        .push_unseparated(" BETWEEN ")
+                        .push_bind_unseparated(bind(from))
+                        .push_unseparated(" AND ")
+                        .push_bind_unseparated(bind(to)),
+                FilterOp::Contains(value) => separated.push_unseparated(DB::LIKE).push_bind_unseparated(format!("%{}%", escape_like(value))).push_unseparated(DB::ESCAPE),
+                FilterOp::StartsWith(value) => separated.push_unseparated(DB::LIKE).push_bind_unseparated(format!("{}%", escape_like(value))).push_unseparated(DB::ESCAPE),
+                FilterOp::IsNull => separated.push_unseparated(" IS NULL"),
+                FilterOp::NotNull => separated.push_unseparated(" IS NOT NULL")
+        };
+}
+
+fn escape_like(value: &str) -> String {
+        value
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+}
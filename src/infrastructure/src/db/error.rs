@@ -18,23 +18,33 @@ pub enum DbError {
 
 impl From<sqlx::error::Error> for DbError {
         fn from(value: sqlx::error::Error) -> Self {
-                use sqlx::error::Error;
+                use sqlx::error::{Error, ErrorKind};
                 match value {
                         Error::Database(err) => {
                                 use sqlx::postgres::PgDatabaseError;
-                                let pg_err = err.downcast::<PgDatabaseError>();
-                                match pg_err.code() {
-                                        "23503" => Self::ForeignKeyViolation {
-                                                table: pg_err.table().unwrap().to_string(),
-                                                column: pg_err.column().unwrap().to_string(),
-                                                source: pg_err
+                                let column = err.try_downcast_ref::<PgDatabaseError>()
+                                        .and_then(PgDatabaseError::column)
+                                        .or(err.constraint());
+                                let (table, column) = match err.table() {
+                                        Some(table) => (table.to_string(), column.unwrap_or_default().to_string()),
+                                        None => err.message()
+                                                .rsplit_once(": ")
+                                                .map(|(_, target)| target.split(", ").filter_map(|field| field.split_once('.')).unzip::<_, _, Vec<_>, Vec<_>>())
+                                                .and_then(|(tables, columns)| Some((tables.first()?.to_string(), columns.join(", "))))
+                                                .unwrap_or_default()
+                                };
+                                match err.kind() {
+                                        ErrorKind::ForeignKeyViolation => Self::ForeignKeyViolation {
+                                                table,
+                                                column,
+                                                source: err.into_error()
                                         },
-                                        "23505" => Self::UniqueViolation {
-                                                table: pg_err.table().unwrap().to_string(),
-                                                column: pg_err.column().unwrap().to_string(),
-                                                source: pg_err
+                                        ErrorKind::UniqueViolation => Self::UniqueViolation {
+                                                table,
+                                                column,
+                                                source: err.into_error()
                                         },
-                                        _ => Self::Other(pg_err)
+                                        _ => Self::Other(err.into_error())
                                 }
                         }
                         _ => Self::Other(Box::new(value))
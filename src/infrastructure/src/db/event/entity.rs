@@ -1,4 +1,4 @@
-use domain::models::event::EventModel;
+use domain::models::{event::{EventModel, EventStatusChangeModel}, geo::GeoPoint, recurrence::RecurrenceRule};
 use sqlx::FromRow;
 use time::{OffsetDateTime, PrimitiveDateTime};
 
@@ -11,11 +11,30 @@ pub struct EventEntity {
         pub date: OffsetDateTime,
         pub cost: i32,
         pub address: String,
+        pub capacity: Option<i32>,
+        pub category: Option<String>,
+        pub tags: Vec<String>,
+        pub language: String,
+        pub latitude: Option<f64>,
+        pub longitude: Option<f64>,
+        pub recurrence: Option<String>,
+        #[sqlx(default)]
+        pub distance: Option<f64>,
+        #[sqlx(default)]
+        pub rank: Option<f32>,
         pub status: String,
         pub created_at: PrimitiveDateTime,
         pub updated_at: PrimitiveDateTime
 }
 
+impl EventEntity {
+        pub(crate) fn recurrence_end(&self) -> Option<OffsetDateTime> {
+                self.recurrence.as_ref()
+                        .and_then(|recurrence| recurrence.parse::<RecurrenceRule>().ok())
+                        .and_then(|rule| rule.end(self.date))
+        }
+}
+
 impl From<EventEntity> for EventModel {
         fn from(value: EventEntity) -> Self {
                 EventModel {
@@ -26,9 +45,40 @@ impl From<EventEntity> for EventModel {
                         date: value.date,
                         cost: value.cost as u32,
                         address: value.address,
+                        capacity: value.capacity.map(|capacity| capacity as u32),
+                        category: value.category,
+                        tags: value.tags,
+                        language: value.language.parse().unwrap(),
+                        location: value.latitude.zip(value.longitude).map(|(latitude, longitude)| GeoPoint { latitude, longitude }),
+                        distance: value.distance,
+                        rank: value.rank,
+                        recurrence: value.recurrence.map(|recurrence| recurrence.parse().unwrap()),
                         status: value.status.parse().unwrap(),
                         created_at: value.created_at,
                         updated_at: value.updated_at
                 }
         }
 }
+
+#[derive(Debug, Clone, FromRow)]
+pub struct EventStatusChangeEntity {
+        pub id: i64,
+        pub event_id: i64,
+        pub from_status: String,
+        pub to_status: String,
+        pub changed_by: Option<i64>,
+        pub created_at: PrimitiveDateTime
+}
+
+impl From<EventStatusChangeEntity> for EventStatusChangeModel {
+        fn from(value: EventStatusChangeEntity) -> Self {
+                EventStatusChangeModel {
+                        id: value.id as u64,
+                        event_id: value.event_id as u64,
+                        from: value.from_status.parse().unwrap(),
+                        to: value.to_status.parse().unwrap(),
+                        changed_by: value.changed_by.map(|changed_by| changed_by as u64),
+                        created_at: value.created_at
+                }
+        }
+}
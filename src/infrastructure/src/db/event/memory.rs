@@ -0,0 +1,288 @@
+use async_trait::async_trait;
+use time::OffsetDateTime;
+use domain::models::{cursor::CursorValue, event::{EventFilter, EventOrder, EventSearch, EventStatus, EventUpdate, NewEvent}, geo::{GeoPoint, GeoRadius}, utils::{FilterGroup, FilterOp, Offset, OrderOp, Timestamp}};
+
+use super::repository::EventRepository;
+use super::entity::{EventEntity, EventStatusChangeEntity};
+use crate::db::registration::entity::RegistrationEntity;
+use crate::db::memory::{FilterMatch, MemoryStore, MemoryTables, OrderValue, foreign_key_violation, is_after, matches_condition, matches_filters, now, page, sort};
+use crate::Result;
+
+pub struct MemoryEventRepository {
+        store: MemoryStore
+}
+
+impl MemoryEventRepository {
+        pub fn new(store: MemoryStore) -> Self {
+                Self { store }
+        }
+}
+
+#[async_trait]
+impl EventRepository for MemoryEventRepository {
+        async fn create(&self, event: &NewEvent) -> Result<EventEntity> {
+                let mut tables = self.store.write();
+
+                if !tables.users.contains_key(&(event.organizer_id as i64)) {
+                        return Err(foreign_key_violation("event", "event_organizer_id_fkey"));
+                }
+                if event.category.as_ref().is_some_and(|category| !tables.categories.contains_key(category)) {
+                        return Err(foreign_key_violation("event", "event_category_fkey"));
+                }
+
+                let mut tags = event.tags.clone();
+                tags.sort();
+                tags.dedup();
+
+                let created_at = now();
+                let event = EventEntity {
+                        id: tables.next_id("event"),
+                        organizer_id: event.organizer_id as i64,
+                        title: event.title.clone(),
+                        description: event.description.clone(),
+                        date: event.date,
+                        cost: event.cost as i32,
+                        address: event.address.clone(),
+                        capacity: event.capacity.map(|capacity| capacity as i32),
+                        category: event.category.clone(),
+                        tags,
+                        language: event.language.to_string(),
+                        latitude: event.location.map(|location| location.latitude),
+                        longitude: event.location.map(|location| location.longitude),
+                        recurrence: event.recurrence.as_ref().map(ToString::to_string),
+                        distance: None,
+                        rank: None,
+                        status: EventStatus::OnReview.to_string(),
+                        created_at,
+                        updated_at: created_at
+                };
+                tables.events.insert(event.id, event.clone());
+
+                Ok(event)
+        }
+
+        async fn get(&self, id: i64) -> Result<Option<EventEntity>> {
+                Ok(self.store.read().events.get(&id).cloned())
+        }
+
+        async fn list(&self, offset: Offset, filters: &[FilterGroup<EventFilter>], order_by: &[EventOrder], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<Vec<EventEntity>> {
+                let events = select(&self.store.read(), filters, search, near);
+
+                Ok(page(sort(events, order_by), &offset))
+        }
+
+        async fn list_after(&self, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<EventFilter>], order_by: &[EventOrder], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<Vec<EventEntity>> {
+                let events = select(&self.store.read(), filters, search, near);
+
+                Ok(sort(events, order_by)
+                        .into_iter()
+                        .filter(|event| after.is_none_or(|values| is_after(event, order_by, values)))
+                        .take(limit as usize)
+                        .collect())
+        }
+
+        async fn count(&self, filters: &[FilterGroup<EventFilter>], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<i64> {
+                Ok(select(&self.store.read(), filters, search, near).len() as i64)
+        }
+
+        async fn list_window(&self, from: OffsetDateTime, to: OffsetDateTime, filters: &[FilterGroup<EventFilter>]) -> Result<Vec<EventEntity>> {
+                let tables = self.store.read();
+
+                let mut events: Vec<_> = tables.events.values()
+                        .filter(|event| match event.recurrence {
+                                None => from <= event.date && event.date <= to,
+                                Some(_) => event.date <= to && event.recurrence_end().is_none_or(|end| from <= end)
+                        } || tables.occurrences.values().any(|occurrence|
+                                occurrence.event_id == event.id && occurrence.date.is_some_and(|date| from <= date && date <= to)
+                        ))
+                        .filter(|event| matches_filters(*event, filters))
+                        .cloned()
+                        .collect();
+                events.sort_by_key(|event| (event.date, event.id));
+
+                Ok(events)
+        }
+
+        async fn update(&self, id: i64, changes: &EventUpdate, changed_by: i64) -> Result<Option<EventEntity>> {
+                let mut tables = self.store.write();
+
+                let registered = tables.registered(id);
+                let Some(event) = tables.events.get_mut(&id) else {
+                        return Ok(None);
+                };
+                if changes.capacity.flatten().is_some_and(|capacity| (capacity as usize) < registered) {
+                        return Ok(None);
+                }
+
+                let previous = event.clone();
+
+                if let Some(title) = &changes.title {
+                        event.title = title.clone();
+                }
+                if let Some(description) = &changes.description {
+                        event.description = description.clone();
+                }
+                if let Some(date) = changes.date {
+                        event.date = date;
+                }
+                if let Some(cost) = changes.cost {
+                        event.cost = cost as i32;
+                }
+                if let Some(address) = &changes.address {
+                        event.address = address.clone();
+                }
+                if let Some(capacity) = changes.capacity {
+                        event.capacity = capacity.map(|capacity| capacity as i32);
+                }
+                if let Some(location) = changes.location {
+                        event.latitude = location.map(|location| location.latitude);
+                        event.longitude = location.map(|location| location.longitude);
+                }
+
+                let material = (&event.title, &event.description, event.date, event.cost, &event.address, event.latitude, event.longitude)
+                        != (&previous.title, &previous.description, previous.date, previous.cost, &previous.address, previous.latitude, previous.longitude);
+                if material && event.status == EventStatus::Approved.to_string() {
+                        event.status = EventStatus::OnReview.to_string();
+                }
+                event.updated_at = now();
+
+                let event = event.clone();
+                if event.status != previous.status {
+                        record_status_change(&mut tables, id, previous.status, event.status.clone(), changed_by);
+                }
+
+                if changes.capacity.is_some() && event.status == EventStatus::Approved.to_string() {
+                        let vacant = event.capacity.map_or(usize::MAX, |capacity| capacity as usize - registered);
+                        let promoted: Vec<_> = tables.waitlist.iter()
+                                .filter(|(_, waitlist)| waitlist.event_id == id)
+                                .take(vacant)
+                                .map(|(waitlist_id, waitlist)| (*waitlist_id, waitlist.user_id))
+                                .collect();
+
+                        for (waitlist_id, user_id) in promoted {
+                                tables.waitlist.remove(&waitlist_id);
+                                tables.registrations.insert((user_id, id), RegistrationEntity { user_id, event_id: id, created_at: event.updated_at, updated_at: event.updated_at });
+                        }
+                }
+
+                Ok(Some(event))
+        }
+
+        async fn delete(&self, id: i64) -> Result<Option<EventEntity>> {
+                Ok(self.store.write().delete_event(id))
+        }
+
+        async fn update_status(&self, id: i64, from: EventStatus, to: EventStatus, changed_by: i64) -> Result<Option<EventEntity>> {
+                let mut tables = self.store.write();
+
+                let Some(event) = tables.events.get_mut(&id).filter(|event| event.status == from.to_string()) else {
+                        return Ok(None);
+                };
+
+                event.status = to.to_string();
+                event.updated_at = now();
+
+                let event = event.clone();
+                record_status_change(&mut tables, id, from.to_string(), to.to_string(), changed_by);
+
+                Ok(Some(event))
+        }
+
+        async fn list_status_history(&self, id: i64, offset: Offset) -> Result<Vec<EventStatusChangeEntity>> {
+                let history = self.store.read().status_history.values()
+                        .filter(|change| change.event_id == id)
+                        .cloned()
+                        .collect();
+
+                Ok(page(history, &offset))
+        }
+
+        async fn count_status_history(&self, id: i64) -> Result<i64> {
+                Ok(self.store.read().status_history.values().filter(|change| change.event_id == id).count() as i64)
+        }
+}
+
+fn select(tables: &MemoryTables, filters: &[FilterGroup<EventFilter>], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Vec<EventEntity> {
+        tables.events.values()
+                .filter_map(|event| {
+                        let distance = match near {
+                                Some(near) => Some(distance(event, near).filter(|distance| *distance <= near.radius_km)?),
+                                None => None
+                        };
+
+                        let rank = match search {
+                                Some(search) => Some(rank(event, search)?),
+                                None => None
+                        };
+
+                        matches_filters(event, filters).then(|| EventEntity { distance, rank, ..event.clone() })
+                })
+                .collect()
+}
+
+fn distance(event: &EventEntity, near: &GeoRadius) -> Option<f64> {
+        let (latitude, longitude) = event.latitude.zip(event.longitude)?;
+
+        Some(near.center.distance_km(&GeoPoint { latitude, longitude }))
+}
+
+fn rank(event: &EventEntity, search: &EventSearch) -> Option<f32> {
+        if search.language.is_some_and(|language| language.to_string() != event.language) {
+                return None;
+        }
+
+        let fields = [(event.title.to_lowercase(), 1.0), (event.description.to_lowercase(), 0.4), (event.address.to_lowercase(), 0.2)];
+        let terms: Vec<_> = search.query.split_whitespace().map(str::to_lowercase).collect();
+
+        if terms.is_empty() || !terms.iter().all(|term| fields.iter().any(|(field, _)| field.contains(term.as_str()))) {
+                return None;
+        }
+
+        let weight: f32 = terms.iter()
+                .flat_map(|term| fields.iter().filter(move |(field, _)| field.contains(term.as_str())))
+                .map(|(_, weight)| weight)
+                .sum();
+
+        Some(weight / terms.len() as f32)
+}
+
+fn record_status_change(tables: &mut MemoryTables, event_id: i64, from_status: String, to_status: String, changed_by: i64) {
+        let id = tables.next_id("event_status_history");
+
+        tables.status_history.insert(id, EventStatusChangeEntity { id, event_id, from_status, to_status, changed_by: Some(changed_by), created_at: now() });
+}
+
+impl OrderValue<EventEntity> for EventOrder {
+        fn order_value(&self, event: &EventEntity) -> (CursorValue, &OrderOp) {
+                match self {
+                        EventOrder::Id(op) => (CursorValue::Int(event.id), op),
+                        EventOrder::OrganizerId(op) => (CursorValue::Int(event.organizer_id), op),
+                        EventOrder::Status(op) => (CursorValue::Text(event.status.clone()), op),
+                        EventOrder::Cost(op) => (CursorValue::Int(event.cost as i64), op),
+                        EventOrder::Date(op) => (CursorValue::TimestampTz(event.date), op),
+                        EventOrder::CreatedAt(op) => (CursorValue::Timestamp(event.created_at), op),
+                        EventOrder::UpdatedAt(op) => (CursorValue::Timestamp(event.updated_at), op),
+                        EventOrder::Distance(op) => (event.distance.map_or(CursorValue::Null, CursorValue::Float), op),
+                        EventOrder::Rank(op) => (event.rank.map_or(CursorValue::Null, |rank| CursorValue::Float(rank as f64)), op)
+                }
+        }
+}
+
+impl FilterMatch<EventEntity> for EventFilter {
+        fn matches(&self, event: &EventEntity) -> bool {
+                match self {
+                        EventFilter::OrganizerId(op) => matches_condition(&CursorValue::Int(event.organizer_id), op, |value| CursorValue::Int(*value as i64)),
+                        EventFilter::Cost(op) => matches_condition(&CursorValue::Int(event.cost as i64), op, |value| CursorValue::Int(*value as i64)),
+                        EventFilter::Status(op) => matches_condition(&CursorValue::Text(event.status.clone()), op, |value| CursorValue::Text(value.to_string())),
+                        EventFilter::Title(op) => matches_condition(&CursorValue::Text(event.title.clone()), op, |value| CursorValue::Text(value.clone())),
+                        EventFilter::Category(op) => matches_condition(&event.category.clone().map_or(CursorValue::Null, CursorValue::Text), op, |value| CursorValue::Text(value.clone())),
+                        EventFilter::Tag(FilterOp::Ne(tag)) => !event.tags.contains(tag),
+                        EventFilter::Tag(FilterOp::IsNull) => event.tags.is_empty(),
+                        EventFilter::Tag(FilterOp::NotNull) => !event.tags.is_empty(),
+                        EventFilter::Tag(op) => event.tags.iter().any(|tag| matches_condition(&CursorValue::Text(tag.clone()), op, |value| CursorValue::Text(value.clone()))),
+                        EventFilter::Date(op) => matches_condition(&CursorValue::TimestampTz(event.date), op, |value| CursorValue::TimestampTz(value.0)),
+                        EventFilter::CreatedAt(op) => matches_condition(&CursorValue::Timestamp(event.created_at), op, |value| CursorValue::Timestamp(Timestamp::utc(value))),
+                        EventFilter::UpdatedAt(op) => matches_condition(&CursorValue::Timestamp(event.updated_at), op, |value| CursorValue::Timestamp(Timestamp::utc(value)))
+                }
+        }
+}
@@ -1,3 +1,7 @@
 pub mod postgresql;
 pub mod repository;
-pub mod entity;
\ No newline at end of file
+pub mod entity;
+#[cfg(feature = "memory")]
+pub mod memory;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
\ No newline at end of file
@@ -1,10 +1,11 @@
 use async_trait::async_trait;
-use sqlx::{Pool, Postgres, QueryBuilder};
-use domain::models::{event::{EventFilter, EventOrder, EventUpdate}, utils::Offset};
+use sqlx::{Pool, Postgres, QueryBuilder, query_builder::Separated};
 use time::OffsetDateTime;
+use domain::models::{cursor::CursorValue, event::{EventFilter, EventOrder, EventSearch, EventStatus, EventUpdate, NewEvent, SearchLanguage}, geo::{EARTH_RADIUS_KM, GeoRadius}, utils::{FilterGroup, FilterOp, Offset, Timestamp}};
 
 use super::repository::EventRepository;
-use super::entity::EventEntity;
+use super::entity::{EventEntity, EventStatusChangeEntity};
+use crate::db::{compiler::{FilterColumn, OrderColumn, push_condition, push_filters, push_limit, push_offset, push_order}, keyset::push_keyset};
 use crate::Result;
 
 pub struct PgEventRepository {
@@ -19,32 +20,68 @@ impl PgEventRepository {
 
 #[async_trait]
 impl EventRepository for PgEventRepository {
-        async fn create(&self,
-                organizer_id: i64, title: &str, description: &str,
-                date: OffsetDateTime, cost: i32, address: &str
-        ) -> Result<EventEntity> {
-                sqlx::query_as(
+        async fn create(&self, event: &NewEvent) -> Result<EventEntity> {
+                let mut tx = self.pool.begin().await?;
+
+                let (id,): (i64,) = sqlx::query_as(
                 r#"
-                        INSERT INTO "event" (organizer_id, title, description, date, cost, address)
-                        VALUES ($1, $2, $3, $4, $5, $6)
-                        RETURNING id, organizer_id, title, description, date, cost, address, status, created_at, updated_at
+                        INSERT INTO "event" (organizer_id, title, description, date, cost, address, capacity, category, language, latitude, longitude, recurrence, recurrence_end)
+                        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9::REGCONFIG, $10, $11, $12, $13)
+                        RETURNING id
                 "#
                 )
-                .bind(organizer_id)
-                .bind(title)
-                .bind(description)
-                .bind(date)
-                .bind(cost)
-                .bind(address)
-                .fetch_one(&self.pool)
-                .await
-                .map_err(Into::into)
+                .bind(event.organizer_id as i64)
+                .bind(&event.title)
+                .bind(&event.description)
+                .bind(event.date)
+                .bind(event.cost as i32)
+                .bind(&event.address)
+                .bind(event.capacity.map(|capacity| capacity as i32))
+                .bind(&event.category)
+                .bind(event.language.to_string())
+                .bind(event.location.map(|location| location.latitude))
+                .bind(event.location.map(|location| location.longitude))
+                .bind(event.recurrence.as_ref().map(ToString::to_string))
+                .bind(event.recurrence.as_ref().and_then(|rule| rule.end(event.date)))
+                .fetch_one(&mut *tx)
+                .await?;
+
+                sqlx::query(
+                r#"
+                        INSERT INTO "event_tag" (event_id, tag)
+                        SELECT $1, UNNEST($2::TEXT[])
+                        ON CONFLICT DO NOTHING
+                "#
+                )
+                .bind(id)
+                .bind(&event.tags)
+                .execute(&mut *tx)
+                .await?;
+
+                let event = sqlx::query_as(
+                r#"
+                        SELECT id, organizer_id, title, description, date, cost, address, capacity, category,
+                        ARRAY(SELECT tag FROM "event_tag" WHERE event_id = "event".id ORDER BY tag) AS tags,
+                        language::TEXT AS language, latitude, longitude, recurrence, status, created_at, updated_at
+                        FROM "event"
+                        WHERE id = $1
+                "#
+                )
+                .bind(id)
+                .fetch_one(&mut *tx)
+                .await?;
+
+                tx.commit().await?;
+
+                Ok(event)
         }
 
         async fn get(&self, id: i64) -> Result<Option<EventEntity>> {
                 sqlx::query_as(
                 r#"
-                        SELECT id, organizer_id, title, description, date, cost, address, status, created_at, updated_at
+                        SELECT id, organizer_id, title, description, date, cost, address, capacity, category,
+                        ARRAY(SELECT tag FROM "event_tag" WHERE event_id = "event".id ORDER BY tag) AS tags,
+                        language::TEXT AS language, latitude, longitude, recurrence, status, created_at, updated_at
                         FROM "event"
                         WHERE id = $1
                 "#
@@ -55,45 +92,34 @@ impl EventRepository for PgEventRepository {
                 .map_err(Into::into)
         }
 
-        async fn list(&self, offset: Offset, filters: &[EventFilter], order_by: &[EventOrder]) -> Result<Vec<EventEntity>> {
+        async fn list(&self, offset: Offset, filters: &[FilterGroup<EventFilter>], order_by: &[EventOrder], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<Vec<EventEntity>> {
                 let mut query_builder =
                         QueryBuilder::<Postgres>::new(r#"
-                        SELECT id, organizer_id, title, description, date, cost, address, status, created_at, updated_at
-                        FROM "event"
-                        "#);
+                        SELECT id, organizer_id, title, description, date, cost, address, capacity, category,
+                        ARRAY(SELECT tag FROM "event_tag" WHERE event_id = "event".id ORDER BY tag) AS tags,
+                        language::TEXT AS language, latitude, longitude, distance, rank, recurrence, status, created_at, updated_at"#);
+                push_source(&mut query_builder, filters, search, near, None);
+                push_order(&mut query_builder, order_by);
 
-                if !filters.is_empty() {
-                        let mut separated = query_builder.separated(" AND ");
-                        separated.push_unseparated(" WHERE ");
-
-                        for filter in filters {
-                                match filter {
-                                        EventFilter::OrganizerId(op) => separated.push("organizer_id ").push_unseparated(op.operation() + " ").push_bind_unseparated(*op.value() as i64),
-                                        EventFilter::Cost(op) => separated.push("cost ").push_unseparated(op.operation() + " ").push_bind_unseparated(*op.value() as i32),
-                                        EventFilter::Status(op) => separated.push("status ").push_unseparated(op.operation() + " ").push_bind_unseparated(op.value().to_string()),
-                                        EventFilter::Title(op) => separated.push("title ").push_unseparated(op.operation() + " ").push_bind_unseparated(op.value()),
-                                };
-                        }
-                }
+                push_offset(&mut query_builder, &offset);
 
-                if !order_by.is_empty() {
-                        let mut separated = query_builder.separated(", ");
-                        separated.push_unseparated(" ORDER BY ");
-
-                        for order in order_by {
-                                match order {
-                                        EventOrder::Id(op) => separated.push("id ").push_unseparated(op.to_string()),
-                                        EventOrder::OrganizerId(op) => separated.push("organizer_id ").push_unseparated(op.to_string()),
-                                        EventOrder::Status(op) => separated.push("status ").push_unseparated(op.to_string()),
-                                        EventOrder::Cost(op) => separated.push("cost ").push_unseparated(op.to_string()),
-                                        EventOrder::CreatedAt(op) => separated.push("created_at ").push_unseparated(op.to_string()),
-                                        EventOrder::UpdatedAt(op) => separated.push("updated_at ").push_unseparated(op.to_string()),
-                                };
-                        }
-                }
+                query_builder
+                        .build_query_as()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn list_after(&self, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<EventFilter>], order_by: &[EventOrder], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<Vec<EventEntity>> {
+                let mut query_builder =
+                        QueryBuilder::<Postgres>::new(r#"
+                        SELECT id, organizer_id, title, description, date, cost, address, capacity, category,
+                        ARRAY(SELECT tag FROM "event_tag" WHERE event_id = "event".id ORDER BY tag) AS tags,
+                        language::TEXT AS language, latitude, longitude, distance, rank, recurrence, status, created_at, updated_at"#);
+                push_source(&mut query_builder, filters, search, near, after.map(|after| (order_by, after)));
+                push_order(&mut query_builder, order_by);
 
-                query_builder.push(" LIMIT ").push_bind(offset.limit as i32);
-                query_builder.push(" OFFSET (").push_bind(offset.limit as i32).push(" * (").push_bind(offset.page as i32).push(" - 1))");
+                push_limit(&mut query_builder, limit);
 
                 query_builder
                         .build_query_as()
@@ -102,40 +128,333 @@ impl EventRepository for PgEventRepository {
                         .map_err(Into::into)
         }
 
-        async fn update(&self, id: i64, changes: EventUpdate) -> Result<Option<EventEntity>> {
+        async fn count(&self, filters: &[FilterGroup<EventFilter>], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<i64> {
+                let mut query_builder = QueryBuilder::<Postgres>::new("SELECT COUNT(*)");
+                push_source(&mut query_builder, filters, search, near, None);
+
+                query_builder
+                        .build_query_scalar()
+                        .fetch_one(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn list_window(&self, from: OffsetDateTime, to: OffsetDateTime, filters: &[FilterGroup<EventFilter>]) -> Result<Vec<EventEntity>> {
                 let mut query_builder =
-                        QueryBuilder::<Postgres>::new(r#"UPDATE "event" SET "#);
-
-                match changes {
-                        EventUpdate::Title(title) => query_builder.push("title = ").push_bind(title).push(' '),
-                        EventUpdate::Cost(cost) => query_builder.push("cost = ").push_bind(cost as i32).push(' '),
-                        EventUpdate::Address(address) => query_builder.push("address = ").push_bind(address).push(' '),
-                        EventUpdate::Status(status) => query_builder.push("status = ").push_bind(status.to_string()).push(' '),
-                        EventUpdate::Description(description) => query_builder.push("description = ").push_bind(description).push(' '),
-                };
-
-                query_builder.push("WHERE id = ").push_bind(id).push(' ');
-                query_builder.push(
-                        r#"RETURNING id, organizer_id, title, description, date, cost, address, status, created_at, updated_at"#
-                );
+                        QueryBuilder::<Postgres>::new(r#"
+                        SELECT id, organizer_id, title, description, date, cost, address, capacity, category,
+                        ARRAY(SELECT tag FROM "event_tag" WHERE event_id = "event".id ORDER BY tag) AS tags,
+                        language::TEXT AS language, latitude, longitude, recurrence, status, created_at, updated_at
+                        FROM "event"
+                        WHERE ((recurrence IS NULL AND date BETWEEN "#);
+                query_builder.push_bind(from).push(" AND ").push_bind(to);
+                query_builder.push(") OR (recurrence IS NOT NULL AND date <= ").push_bind(to);
+                query_builder.push(" AND (recurrence_end IS NULL OR recurrence_end >= ").push_bind(from).push(")");
+                query_builder.push(r#") OR EXISTS (SELECT 1 FROM "event_occurrence" o WHERE o.event_id = "event".id AND o.date BETWEEN "#).push_bind(from).push(" AND ").push_bind(to).push("))");
+
+                if !filters.is_empty() {
+                        let mut separated = query_builder.separated(" AND ");
+                        separated.push_unseparated(" AND ");
+
+                        push_filters(&mut separated, filters);
+                }
+
+                query_builder.push(" ORDER BY date, id");
+
                 query_builder
                         .build_query_as()
-                        .fetch_optional(&self.pool)
+                        .fetch_all(&self.pool)
                         .await
                         .map_err(Into::into)
         }
 
+        async fn update(&self, id: i64, changes: &EventUpdate, changed_by: i64) -> Result<Option<EventEntity>> {
+                let mut tx = self.pool.begin().await?;
+
+                let event: Option<EventEntity> = sqlx::query_as(
+                r#"
+                        WITH previous AS (
+                                SELECT id, status, (SELECT COUNT(*) FROM "registration" r WHERE r.event_id = "event".id) AS registered
+                                FROM "event"
+                                WHERE id = $1
+                                FOR UPDATE
+                        ), updated AS (
+                                UPDATE "event" e
+                                SET title = COALESCE($2, e.title),
+                                description = COALESCE($3, e.description),
+                                date = COALESCE($4, e.date),
+                                cost = COALESCE($5, e.cost),
+                                address = COALESCE($6, e.address),
+                                capacity = CASE WHEN $7 THEN $8 ELSE e.capacity END,
+                                latitude = CASE WHEN $12 THEN $13 ELSE e.latitude END,
+                                longitude = CASE WHEN $12 THEN $14 ELSE e.longitude END,
+                                status = CASE
+                                        WHEN e.status = $9
+                                        AND (e.title, e.description, e.date, e.cost, e.address, e.latitude, e.longitude)
+                                        IS DISTINCT FROM (
+                                                COALESCE($2, e.title), COALESCE($3, e.description), COALESCE($4, e.date), COALESCE($5, e.cost), COALESCE($6, e.address),
+                                                CASE WHEN $12 THEN $13 ELSE e.latitude END, CASE WHEN $12 THEN $14 ELSE e.longitude END
+                                        )
+                                        THEN $10
+                                        ELSE e.status
+                                END
+                                FROM previous p
+                                WHERE e.id = p.id
+                                AND (NOT $7 OR $8 IS NULL OR $8 >= p.registered)
+                                RETURNING e.id, e.organizer_id, e.title, e.description, e.date, e.cost, e.address, e.capacity, e.category, e.language, e.latitude, e.longitude, e.recurrence, e.status, e.created_at, e.updated_at
+                        ), history AS (
+                                INSERT INTO "event_status_history" (event_id, from_status, to_status, changed_by)
+                                SELECT u.id, p.status, u.status, $11
+                                FROM updated u
+                                JOIN previous p ON p.id = u.id
+                                WHERE p.status <> u.status
+                        )
+                        SELECT id, organizer_id, title, description, date, cost, address, capacity, category,
+                        ARRAY(SELECT tag FROM "event_tag" t WHERE t.event_id = updated.id ORDER BY tag) AS tags,
+                        language::TEXT AS language, latitude, longitude, recurrence, status, created_at, updated_at
+                        FROM updated
+                "#
+                )
+                .bind(id)
+                .bind(&changes.title)
+                .bind(&changes.description)
+                .bind(changes.date)
+                .bind(changes.cost.map(|cost| cost as i32))
+                .bind(&changes.address)
+                .bind(changes.capacity.is_some())
+                .bind(changes.capacity.flatten().map(|capacity| capacity as i32))
+                .bind(EventStatus::Approved.to_string())
+                .bind(EventStatus::OnReview.to_string())
+                .bind(changed_by)
+                .bind(changes.location.is_some())
+                .bind(changes.location.flatten().map(|location| location.latitude))
+                .bind(changes.location.flatten().map(|location| location.longitude))
+                .fetch_optional(&mut *tx)
+                .await?;
+
+                if let Some(event) = &event && event.recurrence.is_some() && changes.date.is_some() {
+                        sqlx::query(
+                        r#"
+                                UPDATE "event"
+                                SET recurrence_end = $2
+                                WHERE id = $1
+                        "#
+                        )
+                        .bind(id)
+                        .bind(event.recurrence_end())
+                        .execute(&mut *tx)
+                        .await?;
+                }
+
+                if event.is_some() && changes.capacity.is_some() {
+                        sqlx::query(
+                        r#"
+                                WITH promoted AS (
+                                        DELETE FROM "waitlist"
+                                        WHERE id IN (
+                                                SELECT w.id
+                                                FROM "waitlist" w
+                                                JOIN "event" e ON e.id = w.event_id
+                                                WHERE w.event_id = $1
+                                                AND e.status = 'Approved'
+                                                ORDER BY w.id
+                                                LIMIT (
+                                                        SELECT e.capacity - (SELECT COUNT(*) FROM "registration" r WHERE r.event_id = e.id)
+                                                        FROM "event" e
+                                                        WHERE e.id = $1
+                                                )
+                                        )
+                                        RETURNING user_id, event_id
+                                )
+                                INSERT INTO "registration" (user_id, event_id)
+                                SELECT user_id, event_id
+                                FROM promoted
+                        "#
+                        )
+                        .bind(id)
+                        .execute(&mut *tx)
+                        .await?;
+                }
+
+                tx.commit().await?;
+
+                Ok(event)
+        }
+
         async fn delete(&self, id: i64) -> Result<Option<EventEntity>> {
                 sqlx::query_as(
                 r#"
-                        DELETE FROM "event"
-                        WHERE id = $1
-                        RETURNING id, organizer_id, title, description, date, cost, address, status, created_at, updated_at
+                        WITH deleted AS (
+                                DELETE FROM "event"
+                                WHERE id = $1
+                                RETURNING id, organizer_id, title, description, date, cost, address, capacity, category, language, latitude, longitude, recurrence, status, created_at, updated_at
+                        )
+                        SELECT id, organizer_id, title, description, date, cost, address, capacity, category,
+                        ARRAY(SELECT tag FROM "event_tag" t WHERE t.event_id = deleted.id ORDER BY tag) AS tags,
+                        language::TEXT AS language, latitude, longitude, recurrence, status, created_at, updated_at
+                        FROM deleted
+                "#
+                )
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn update_status(&self, id: i64, from: EventStatus, to: EventStatus, changed_by: i64) -> Result<Option<EventEntity>> {
+                sqlx::query_as(
+                r#"
+                        WITH updated AS (
+                                UPDATE "event"
+                                SET status = $3
+                                WHERE id = $1
+                                AND status = $2
+                                RETURNING id, organizer_id, title, description, date, cost, address, capacity, category, language, latitude, longitude, recurrence, status, created_at, updated_at
+                        ), history AS (
+                                INSERT INTO "event_status_history" (event_id, from_status, to_status, changed_by)
+                                SELECT id, $2, $3, $4
+                                FROM updated
+                        )
+                        SELECT id, organizer_id, title, description, date, cost, address, capacity, category,
+                        ARRAY(SELECT tag FROM "event_tag" t WHERE t.event_id = updated.id ORDER BY tag) AS tags,
+                        language::TEXT AS language, latitude, longitude, recurrence, status, created_at, updated_at
+                        FROM updated
                 "#
                 )
                 .bind(id)
+                .bind(from.to_string())
+                .bind(to.to_string())
+                .bind(changed_by)
                 .fetch_optional(&self.pool)
                 .await
                 .map_err(Into::into)
         }
+
+        async fn list_status_history(&self, id: i64, offset: Offset) -> Result<Vec<EventStatusChangeEntity>> {
+                sqlx::query_as(
+                r#"
+                        SELECT id, event_id, from_status, to_status, changed_by, created_at
+                        FROM "event_status_history"
+                        WHERE event_id = $1
+                        ORDER BY id
+                        LIMIT $2
+                        OFFSET $3
+                "#
+                )
+                .bind(id)
+                .bind(i64::from(offset.limit))
+                .bind(offset.skip())
+                .fetch_all(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn count_status_history(&self, id: i64) -> Result<i64> {
+                sqlx::query_scalar(
+                r#"
+                        SELECT COUNT(*) FROM "event_status_history" WHERE event_id = $1
+                "#
+                )
+                .bind(id)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+}
+
+fn push_source<'args>(
+        query_builder: &mut QueryBuilder<'args, Postgres>,
+        filters: &'args [FilterGroup<EventFilter>],
+        search: Option<&'args EventSearch>,
+        near: Option<&'args GeoRadius>,
+        after: Option<(&[EventOrder], &'args [CursorValue])>
+) {
+        query_builder.push(r#" FROM "event" CROSS JOIN LATERAL (SELECT "#);
+
+        if let Some(near) = near {
+                query_builder
+                        .push(EARTH_RADIUS_KM)
+                        .push(" * 2 * ASIN(LEAST(1, SQRT(POWER(SIN(RADIANS(latitude - ")
+                        .push_bind(near.center.latitude)
+                        .push(") / 2), 2) + COS(RADIANS(")
+                        .push_bind(near.center.latitude)
+                        .push(")) * COS(RADIANS(latitude)) * POWER(SIN(RADIANS(longitude - ")
+                        .push_bind(near.center.longitude)
+                        .push(") / 2), 2))))");
+        } else {
+                query_builder.push("NULL::DOUBLE PRECISION");
+        }
+        query_builder.push(" AS distance, ");
+
+        if let Some(search) = search {
+                let (column, language) = search_column(search);
+                query_builder.push("ts_rank(").push(column).push(", websearch_to_tsquery(").push_bind(language.to_string()).push("::REGCONFIG, ").push_bind(&search.query).push("))");
+        } else {
+                query_builder.push("NULL::REAL");
+        }
+        query_builder.push(" AS rank) AS d");
+
+        if !filters.is_empty() || search.is_some() || near.is_some() || after.is_some() {
+                let mut separated = query_builder.separated(" AND ");
+                separated.push_unseparated(" WHERE ");
+
+                if let Some(near) = near {
+                        let delta = (near.radius_km / EARTH_RADIUS_KM).to_degrees();
+                        separated.push("latitude BETWEEN ").push_bind_unseparated(near.center.latitude - delta).push_unseparated(" AND ").push_bind_unseparated(near.center.latitude + delta);
+                        separated.push("distance <= ").push_bind_unseparated(near.radius_km);
+                }
+
+                if let Some(search) = search {
+                        let (column, language) = search_column(search);
+                        separated.push(column).push_unseparated(" @@ websearch_to_tsquery(").push_bind_unseparated(language.to_string()).push_unseparated("::REGCONFIG, ").push_bind_unseparated(&search.query).push_unseparated(")");
+
+                        if let Some(language) = search.language {
+                                separated.push("language = ").push_bind_unseparated(language.to_string()).push_unseparated("::REGCONFIG");
+                        }
+                }
+
+                push_filters(&mut separated, filters);
+
+                if let Some((order_by, values)) = after {
+                        push_keyset(&mut separated, order_by, values);
+                }
+        }
+}
+
+fn search_column(search: &EventSearch) -> (&'static str, SearchLanguage) {
+        match search.language {
+                Some(language) => ("search", language),
+                None => ("search_simple", SearchLanguage::Simple)
+        }
+}
+
+impl OrderColumn for EventOrder {}
+
+impl FilterColumn<Postgres> for EventFilter {
+        fn push_filter<'args>(&'args self, separated: &mut Separated<'_, 'args, Postgres, &'static str>) {
+                match self {
+                        EventFilter::OrganizerId(op) => push_condition(separated, "organizer_id", op, |value| *value as i64),
+                        EventFilter::Cost(op) => push_condition(separated, "cost", op, |value| *value as i32),
+                        EventFilter::Status(op) => push_condition(separated, "status", op, ToString::to_string),
+                        EventFilter::Title(op) => push_condition(separated, "title", op, String::as_str),
+                        EventFilter::Category(op) => push_condition(separated, "category", op, String::as_str),
+                        EventFilter::Tag(FilterOp::Ne(tag)) => {
+                                separated.push_unseparated(r#"NOT EXISTS (SELECT 1 FROM "event_tag" WHERE event_id = "event".id AND tag = "#).push_bind_unseparated(tag).push_unseparated(")");
+                        },
+                        EventFilter::Tag(FilterOp::IsNull) => {
+                                separated.push_unseparated(r#"NOT EXISTS (SELECT 1 FROM "event_tag" WHERE event_id = "event".id)"#);
+                        },
+                        EventFilter::Tag(FilterOp::NotNull) => {
+                                separated.push_unseparated(r#"EXISTS (SELECT 1 FROM "event_tag" WHERE event_id = "event".id)"#);
+                        },
+                        EventFilter::Tag(op) => {
+                                separated.push_unseparated(r#"EXISTS (SELECT 1 FROM "event_tag" WHERE event_id = "event".id AND "#);
+                                push_condition(separated, "tag", op, String::as_str);
+                                separated.push_unseparated(")");
+                        },
+                        EventFilter::Date(op) => push_condition(separated, "date", op, |value| value.0),
+                        EventFilter::CreatedAt(op) => push_condition(separated, "created_at", op, Timestamp::utc),
+                        EventFilter::UpdatedAt(op) => push_condition(separated, "updated_at", op, Timestamp::utc)
+                }
+        }
 }
@@ -1,18 +1,69 @@
 use async_trait::async_trait;
-use domain::models::{event::{EventFilter, EventOrder, EventUpdate}, utils::Offset};
 use time::OffsetDateTime;
+use domain::models::{cursor::CursorValue, event::{EventFilter, EventOrder, EventSearch, EventStatus, EventUpdate, NewEvent}, geo::GeoRadius, utils::{FilterGroup, Offset}};
 
-use super::entity::EventEntity;
+use super::entity::{EventEntity, EventStatusChangeEntity};
 use crate::Result;
 
 #[async_trait]
-pub trait EventRepository {
+pub trait EventRepository: Send + Sync {
         async fn get(&self, id: i64) -> Result<Option<EventEntity>>;
-        async fn list(&self, offset: Offset, filters: &[EventFilter], order_by: &[EventOrder]) -> Result<Vec<EventEntity>>;
-        async fn create(&self,
-                organizer_id: i64, title: &str, description: &str,
-                date: OffsetDateTime, cost: i32, address: &str
-        ) -> Result<EventEntity>;
-        async fn update(&self, id: i64, changes: EventUpdate) -> Result<Option<EventEntity>>;
+        async fn list(&self, offset: Offset, filters: &[FilterGroup<EventFilter>], order_by: &[EventOrder], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<Vec<EventEntity>>;
+        async fn list_after(&self, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<EventFilter>], order_by: &[EventOrder], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<Vec<EventEntity>>;
+        async fn count(&self, filters: &[FilterGroup<EventFilter>], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<i64>;
+        async fn list_window(&self, from: OffsetDateTime, to: OffsetDateTime, filters: &[FilterGroup<EventFilter>]) -> Result<Vec<EventEntity>>;
+        async fn create(&self, event: &NewEvent) -> Result<EventEntity>;
+        async fn update(&self, id: i64, changes: &EventUpdate, changed_by: i64) -> Result<Option<EventEntity>>;
         async fn delete(&self, id: i64) -> Result<Option<EventEntity>>;
+
+        async fn update_status(&self, id: i64, from: EventStatus, to: EventStatus, changed_by: i64) -> Result<Option<EventEntity>>;
+        async fn list_status_history(&self, id: i64, offset: Offset) -> Result<Vec<EventStatusChangeEntity>>;
+        async fn count_status_history(&self, id: i64) -> Result<i64>;
+}
+
+#[async_trait]
+impl<T: EventRepository + ?Sized> EventRepository for Box<T> {
+        async fn get(&self, id: i64) -> Result<Option<EventEntity>> {
+                (**self).get(id).await
+        }
+
+        async fn list(&self, offset: Offset, filters: &[FilterGroup<EventFilter>], order_by: &[EventOrder], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<Vec<EventEntity>> {
+                (**self).list(offset, filters, order_by, search, near).await
+        }
+
+        async fn list_after(&self, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<EventFilter>], order_by: &[EventOrder], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<Vec<EventEntity>> {
+                (**self).list_after(limit, after, filters, order_by, search, near).await
+        }
+
+        async fn count(&self, filters: &[FilterGroup<EventFilter>], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<i64> {
+                (**self).count(filters, search, near).await
+        }
+
+        async fn list_window(&self, from: OffsetDateTime, to: OffsetDateTime, filters: &[FilterGroup<EventFilter>]) -> Result<Vec<EventEntity>> {
+                (**self).list_window(from, to, filters).await
+        }
+
+        async fn create(&self, event: &NewEvent) -> Result<EventEntity> {
+                (**self).create(event).await
+        }
+
+        async fn update(&self, id: i64, changes: &EventUpdate, changed_by: i64) -> Result<Option<EventEntity>> {
+                (**self).update(id, changes, changed_by).await
+        }
+
+        async fn delete(&self, id: i64) -> Result<Option<EventEntity>> {
+                (**self).delete(id).await
+        }
+
+        async fn update_status(&self, id: i64, from: EventStatus, to: EventStatus, changed_by: i64) -> Result<Option<EventEntity>> {
+                (**self).update_status(id, from, to, changed_by).await
+        }
+
+        async fn list_status_history(&self, id: i64, offset: Offset) -> Result<Vec<EventStatusChangeEntity>> {
+                (**self).list_status_history(id, offset).await
+        }
+
+        async fn count_status_history(&self, id: i64) -> Result<i64> {
+                (**self).count_status_history(id).await
+        }
 }
\ No newline at end of file
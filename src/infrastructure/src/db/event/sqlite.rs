@@ -0,0 +1,550 @@
+use async_trait::async_trait;
+use sqlx::{FromRow, Pool, QueryBuilder, Sqlite, query_builder::Separated};
+use time::{OffsetDateTime, PrimitiveDateTime};
+use domain::models::{cursor::CursorValue, event::{EventFilter, EventOrder, EventSearch, EventStatus, EventUpdate, NewEvent}, geo::{EARTH_RADIUS_KM, GeoRadius}, utils::{FilterGroup, FilterOp, Offset}};
+
+use super::repository::EventRepository;
+use super::entity::{EventEntity, EventStatusChangeEntity};
+use crate::db::{compiler::{FilterColumn, push_condition, push_filters, push_limit, push_offset, push_order}, keyset::push_keyset, sqlite::{NOW, split_tags, timestamp, timestamptz}};
+use crate::Result;
+
+pub struct SqliteEventRepository {
+        pool: Pool<Sqlite>
+}
+
+impl SqliteEventRepository {
+        pub fn new(pool: Pool<Sqlite>) -> Self {
+                Self { pool }
+        }
+}
+
+#[derive(FromRow)]
+pub(crate) struct EventRow {
+        id: i64,
+        organizer_id: i64,
+        title: String,
+        description: String,
+        date: OffsetDateTime,
+        cost: i32,
+        address: String,
+        capacity: Option<i32>,
+        category: Option<String>,
+        tags: Option<String>,
+        language: String,
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        recurrence: Option<String>,
+        #[sqlx(default)]
+        distance: Option<f64>,
+        #[sqlx(default)]
+        rank: Option<f32>,
+        status: String,
+        created_at: PrimitiveDateTime,
+        updated_at: PrimitiveDateTime
+}
+
+impl From<EventRow> for EventEntity {
+        fn from(value: EventRow) -> Self {
+                EventEntity {
+                        id: value.id,
+                        organizer_id: value.organizer_id,
+                        title: value.title,
+                        description: value.description,
+                        date: value.date,
+                        cost: value.cost,
+                        address: value.address,
+                        capacity: value.capacity,
+                        category: value.category,
+                        tags: split_tags(value.tags),
+                        language: value.language,
+                        latitude: value.latitude,
+                        longitude: value.longitude,
+                        recurrence: value.recurrence,
+                        distance: value.distance,
+                        rank: value.rank,
+                        status: value.status,
+                        created_at: value.created_at,
+                        updated_at: value.updated_at
+                }
+        }
+}
+
+#[async_trait]
+impl EventRepository for SqliteEventRepository {
+        async fn create(&self, event: &NewEvent) -> Result<EventEntity> {
+                let mut tx = self.pool.begin().await?;
+
+                let (id,): (i64,) = sqlx::query_as(
+                r#"
+                        INSERT INTO "event" (organizer_id, title, description, date, cost, address, capacity, category, language, latitude, longitude, recurrence, recurrence_end)
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                        RETURNING id
+                "#
+                )
+                .bind(event.organizer_id as i64)
+                .bind(&event.title)
+                .bind(&event.description)
+                .bind(timestamptz(&event.date))
+                .bind(event.cost as i32)
+                .bind(&event.address)
+                .bind(event.capacity.map(|capacity| capacity as i32))
+                .bind(&event.category)
+                .bind(event.language.to_string())
+                .bind(event.location.map(|location| location.latitude))
+                .bind(event.location.map(|location| location.longitude))
+                .bind(event.recurrence.as_ref().map(ToString::to_string))
+                .bind(event.recurrence.as_ref().and_then(|rule| rule.end(event.date)).as_ref().map(timestamptz))
+                .fetch_one(&mut *tx)
+                .await?;
+
+                if !event.tags.is_empty() {
+                        let mut query_builder = QueryBuilder::<Sqlite>::new(r#"INSERT OR IGNORE INTO "event_tag" (event_id, tag) "#);
+                        query_builder.push_values(&event.tags, |mut separated, tag| {
+                                separated.push_bind(id).push_bind(tag);
+                        });
+
+                        query_builder
+                                .build()
+                                .execute(&mut *tx)
+                                .await?;
+                }
+
+                let event = select(id)
+                        .fetch_one(&mut *tx)
+                        .await?;
+
+                tx.commit().await?;
+
+                Ok(event.into())
+        }
+
+        async fn get(&self, id: i64) -> Result<Option<EventEntity>> {
+                select(id)
+                        .fetch_optional(&self.pool)
+                        .await
+                        .map(|event| event.map(Into::into))
+                        .map_err(Into::into)
+        }
+
+        async fn list(&self, offset: Offset, filters: &[FilterGroup<EventFilter>], order_by: &[EventOrder], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<Vec<EventEntity>> {
+                let mut query_builder =
+                        QueryBuilder::<Sqlite>::new(r#"
+                        SELECT id, organizer_id, title, description, date, cost, address, capacity, category,
+                        (SELECT group_concat(tag, char(31)) FROM (SELECT tag FROM "event_tag" WHERE event_id = "event".id ORDER BY tag)) AS tags,
+                        language, latitude, longitude, distance, rank, recurrence, status, created_at, updated_at"#);
+                push_source(&mut query_builder, filters, search, near, None);
+                push_order(&mut query_builder, order_by);
+
+                push_offset(&mut query_builder, &offset);
+
+                query_builder
+                        .build_query_as::<EventRow>()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map(|events| events.into_iter().map(Into::into).collect())
+                        .map_err(Into::into)
+        }
+
+        async fn list_after(&self, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<EventFilter>], order_by: &[EventOrder], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<Vec<EventEntity>> {
+                let mut query_builder =
+                        QueryBuilder::<Sqlite>::new(r#"
+                        SELECT id, organizer_id, title, description, date, cost, address, capacity, category,
+                        (SELECT group_concat(tag, char(31)) FROM (SELECT tag FROM "event_tag" WHERE event_id = "event".id ORDER BY tag)) AS tags,
+                        language, latitude, longitude, distance, rank, recurrence, status, created_at, updated_at"#);
+                push_source(&mut query_builder, filters, search, near, after.map(|after| (order_by, after)));
+                push_order(&mut query_builder, order_by);
+
+                push_limit(&mut query_builder, limit);
+
+                query_builder
+                        .build_query_as::<EventRow>()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map(|events| events.into_iter().map(Into::into).collect())
+                        .map_err(Into::into)
+        }
+
+        async fn count(&self, filters: &[FilterGroup<EventFilter>], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<i64> {
+                let mut query_builder = QueryBuilder::<Sqlite>::new("SELECT COUNT(*)");
+                push_source(&mut query_builder, filters, search, near, None);
+
+                query_builder
+                        .build_query_scalar()
+                        .fetch_one(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn list_window(&self, from: OffsetDateTime, to: OffsetDateTime, filters: &[FilterGroup<EventFilter>]) -> Result<Vec<EventEntity>> {
+                let (from, to) = (timestamptz(&from), timestamptz(&to));
+
+                let mut query_builder =
+                        QueryBuilder::<Sqlite>::new(r#"
+                        SELECT id, organizer_id, title, description, date, cost, address, capacity, category,
+                        (SELECT group_concat(tag, char(31)) FROM (SELECT tag FROM "event_tag" WHERE event_id = "event".id ORDER BY tag)) AS tags,
+                        language, latitude, longitude, recurrence, status, created_at, updated_at
+                        FROM "event"
+                        WHERE ((recurrence IS NULL AND date BETWEEN "#);
+                query_builder.push_bind(from.clone()).push(" AND ").push_bind(to.clone());
+                query_builder.push(") OR (recurrence IS NOT NULL AND date <= ").push_bind(to.clone());
+                query_builder.push(" AND (recurrence_end IS NULL OR recurrence_end >= ").push_bind(from.clone()).push(")");
+                query_builder.push(r#") OR EXISTS (SELECT 1 FROM "event_occurrence" o WHERE o.event_id = "event".id AND o.date BETWEEN "#).push_bind(from).push(" AND ").push_bind(to).push("))");
+
+                if !filters.is_empty() {
+                        let mut separated = query_builder.separated(" AND ");
+                        separated.push_unseparated(" AND ");
+
+                        push_filters(&mut separated, filters);
+                }
+
+                query_builder.push(" ORDER BY date, id");
+
+                query_builder
+                        .build_query_as::<EventRow>()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map(|events| events.into_iter().map(Into::into).collect())
+                        .map_err(Into::into)
+        }
+
+        async fn update(&self, id: i64, changes: &EventUpdate, changed_by: i64) -> Result<Option<EventEntity>> {
+                let mut tx = self.pool.begin().await?;
+
+                let Some((from_status,)): Option<(String,)> = sqlx::query_as(
+                r#"
+                        SELECT status
+                        FROM "event"
+                        WHERE id = ?
+                "#
+                )
+                .bind(id)
+                .fetch_optional(&mut *tx)
+                .await? else {
+                        return Ok(None);
+                };
+
+                let Some((to_status,)): Option<(String,)> = sqlx::query_as(&format!(
+                r#"
+                        UPDATE "event"
+                        SET title = COALESCE(?2, title),
+                        description = COALESCE(?3, description),
+                        date = COALESCE(?4, date),
+                        cost = COALESCE(?5, cost),
+                        address = COALESCE(?6, address),
+                        capacity = CASE WHEN ?7 THEN ?8 ELSE capacity END,
+                        latitude = CASE WHEN ?11 THEN ?12 ELSE latitude END,
+                        longitude = CASE WHEN ?11 THEN ?13 ELSE longitude END,
+                        status = CASE
+                                WHEN status = ?9
+                                AND (title, description, date, cost, address, latitude, longitude)
+                                IS NOT (
+                                        COALESCE(?2, title), COALESCE(?3, description), COALESCE(?4, date), COALESCE(?5, cost), COALESCE(?6, address),
+                                        CASE WHEN ?11 THEN ?12 ELSE latitude END, CASE WHEN ?11 THEN ?13 ELSE longitude END
+                                )
+                                THEN ?10
+                                ELSE status
+                        END,
+                        updated_at = {NOW}
+                        WHERE id = ?1
+                        AND (NOT ?7 OR ?8 IS NULL OR ?8 >= (SELECT COUNT(*) FROM "registration" WHERE event_id = ?1))
+                        RETURNING status
+                "#
+                ))
+                .bind(id)
+                .bind(&changes.title)
+                .bind(&changes.description)
+                .bind(changes.date.as_ref().map(timestamptz))
+                .bind(changes.cost.map(|cost| cost as i32))
+                .bind(&changes.address)
+                .bind(changes.capacity.is_some())
+                .bind(changes.capacity.flatten().map(|capacity| capacity as i32))
+                .bind(EventStatus::Approved.to_string())
+                .bind(EventStatus::OnReview.to_string())
+                .bind(changes.location.is_some())
+                .bind(changes.location.flatten().map(|location| location.latitude))
+                .bind(changes.location.flatten().map(|location| location.longitude))
+                .fetch_optional(&mut *tx)
+                .await? else {
+                        return Ok(None);
+                };
+
+                if from_status != to_status {
+                        record_status_change(&mut tx, id, &from_status, &to_status, changed_by).await?;
+                }
+
+                let event: EventEntity = select(id)
+                        .fetch_one(&mut *tx)
+                        .await?
+                        .into();
+
+                if event.recurrence.is_some() && changes.date.is_some() {
+                        sqlx::query(
+                        r#"
+                                UPDATE "event"
+                                SET recurrence_end = ?2
+                                WHERE id = ?1
+                        "#
+                        )
+                        .bind(id)
+                        .bind(event.recurrence_end().as_ref().map(timestamptz))
+                        .execute(&mut *tx)
+                        .await?;
+                }
+
+                if changes.capacity.is_some() {
+                        let promoted: Vec<(i64, i64)> = sqlx::query_as(
+                        r#"
+                                DELETE FROM "waitlist"
+                                WHERE id IN (
+                                        SELECT w.id
+                                        FROM "waitlist" w
+                                        JOIN "event" e ON e.id = w.event_id
+                                        WHERE w.event_id = ?1
+                                        AND e.status = 'Approved'
+                                        ORDER BY w.id
+                                        LIMIT COALESCE((
+                                                SELECT e.capacity - (SELECT COUNT(*) FROM "registration" r WHERE r.event_id = e.id)
+                                                FROM "event" e
+                                                WHERE e.id = ?1
+                                        ), -1)
+                                )
+                                RETURNING user_id, event_id
+                        "#
+                        )
+                        .bind(id)
+                        .fetch_all(&mut *tx)
+                        .await?;
+
+                        for (user_id, event_id) in promoted {
+                                sqlx::query(
+                                r#"
+                                        INSERT INTO "registration" (user_id, event_id)
+                                        VALUES (?, ?)
+                                "#
+                                )
+                                .bind(user_id)
+                                .bind(event_id)
+                                .execute(&mut *tx)
+                                .await?;
+                        }
+                }
+
+                tx.commit().await?;
+
+                Ok(Some(event))
+        }
+
+        async fn delete(&self, id: i64) -> Result<Option<EventEntity>> {
+                let mut tx = self.pool.begin().await?;
+
+                let event = select(id)
+                        .fetch_optional(&mut *tx)
+                        .await?;
+
+                sqlx::query(
+                r#"
+                        DELETE FROM "event"
+                        WHERE id = ?
+                "#
+                )
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+
+                tx.commit().await?;
+
+                Ok(event.map(Into::into))
+        }
+
+        async fn update_status(&self, id: i64, from: EventStatus, to: EventStatus, changed_by: i64) -> Result<Option<EventEntity>> {
+                let mut tx = self.pool.begin().await?;
+
+                let updated: Option<i64> = sqlx::query_scalar(&format!(
+                r#"
+                        UPDATE "event"
+                        SET status = ?3,
+                        updated_at = {NOW}
+                        WHERE id = ?1
+                        AND status = ?2
+                        RETURNING id
+                "#
+                ))
+                .bind(id)
+                .bind(from.to_string())
+                .bind(to.to_string())
+                .fetch_optional(&mut *tx)
+                .await?;
+
+                if updated.is_none() {
+                        return Ok(None);
+                }
+
+                record_status_change(&mut tx, id, &from.to_string(), &to.to_string(), changed_by).await?;
+
+                let event = select(id)
+                        .fetch_one(&mut *tx)
+                        .await?;
+
+                tx.commit().await?;
+
+                Ok(Some(event.into()))
+        }
+
+        async fn list_status_history(&self, id: i64, offset: Offset) -> Result<Vec<EventStatusChangeEntity>> {
+                sqlx::query_as(
+                r#"
+                        SELECT id, event_id, from_status, to_status, changed_by, created_at
+                        FROM "event_status_history"
+                        WHERE event_id = ?1
+                        ORDER BY id
+                        LIMIT ?2
+                        OFFSET ?3
+                "#
+                )
+                .bind(id)
+                .bind(i64::from(offset.limit))
+                .bind(offset.skip())
+                .fetch_all(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn count_status_history(&self, id: i64) -> Result<i64> {
+                sqlx::query_scalar(
+                r#"
+                        SELECT COUNT(*) FROM "event_status_history" WHERE event_id = ?
+                "#
+                )
+                .bind(id)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+}
+
+fn select<'q>(id: i64) -> sqlx::query::QueryAs<'q, Sqlite, EventRow, sqlx::sqlite::SqliteArguments<'q>> {
+        sqlx::query_as(
+        r#"
+                SELECT id, organizer_id, title, description, date, cost, address, capacity, category,
+                (SELECT group_concat(tag, char(31)) FROM (SELECT tag FROM "event_tag" WHERE event_id = "event".id ORDER BY tag)) AS tags,
+                language, latitude, longitude, recurrence, status, created_at, updated_at
+                FROM "event"
+                WHERE id = ?
+        "#
+        )
+        .bind(id)
+}
+
+async fn record_status_change(tx: &mut sqlx::SqliteConnection, event_id: i64, from_status: &str, to_status: &str, changed_by: i64) -> Result<()> {
+        sqlx::query(
+        r#"
+                INSERT INTO "event_status_history" (event_id, from_status, to_status, changed_by)
+                VALUES (?, ?, ?, ?)
+        "#
+        )
+        .bind(event_id)
+        .bind(from_status)
+        .bind(to_status)
+        .bind(changed_by)
+        .execute(tx)
+        .await?;
+
+        Ok(())
+}
+
+fn push_source<'args>(
+        query_builder: &mut QueryBuilder<'args, Sqlite>,
+        filters: &'args [FilterGroup<EventFilter>],
+        search: Option<&'args EventSearch>,
+        near: Option<&'args GeoRadius>,
+        after: Option<(&[EventOrder], &'args [CursorValue])>
+) {
+        query_builder.push(" FROM (SELECT e.*, ");
+
+        if let Some(near) = near {
+                query_builder
+                        .push(EARTH_RADIUS_KM)
+                        .push(" * 2 * ASIN(MIN(1, SQRT(POWER(SIN(RADIANS(e.latitude - ")
+                        .push_bind(near.center.latitude)
+                        .push(") / 2), 2) + COS(RADIANS(")
+                        .push_bind(near.center.latitude)
+                        .push(")) * COS(RADIANS(e.latitude)) * POWER(SIN(RADIANS(e.longitude - ")
+                        .push_bind(near.center.longitude)
+                        .push(") / 2), 2))))");
+        } else {
+                query_builder.push("NULL");
+        }
+        query_builder.push(" AS distance, ");
+
+        match search {
+                Some(search) => {
+                        let terms = search.query
+                                .split_whitespace()
+                                .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+                                .collect::<Vec<_>>()
+                                .join(" ");
+
+                        if terms.is_empty() {
+                                query_builder.push(r#"NULL AS rank FROM "event" e WHERE FALSE"#);
+                        } else {
+                                query_builder
+                                        .push(r#"-bm25("event_search", 1.0, 0.4, 0.2) AS rank FROM "event" e JOIN "event_search" ON "event_search".rowid = e.id WHERE "event_search" MATCH "#)
+                                        .push_bind(terms);
+
+                                if let Some(language) = search.language {
+                                        query_builder.push(" AND e.language = ").push_bind(language.to_string());
+                                }
+                        }
+                },
+                None => {
+                        query_builder.push(r#"NULL AS rank FROM "event" e"#);
+                }
+        }
+        query_builder.push(r#") AS "event""#);
+
+        if !filters.is_empty() || near.is_some() || after.is_some() {
+                let mut separated = query_builder.separated(" AND ");
+                separated.push_unseparated(" WHERE ");
+
+                if let Some(near) = near {
+                        let delta = (near.radius_km / EARTH_RADIUS_KM).to_degrees();
+                        separated.push("latitude BETWEEN ").push_bind_unseparated(near.center.latitude - delta).push_unseparated(" AND ").push_bind_unseparated(near.center.latitude + delta);
+                        separated.push("distance <= ").push_bind_unseparated(near.radius_km);
+                }
+
+                push_filters(&mut separated, filters);
+
+                if let Some((order_by, values)) = after {
+                        push_keyset(&mut separated, order_by, values);
+                }
+        }
+}
+
+impl FilterColumn<Sqlite> for EventFilter {
+        fn push_filter<'args>(&'args self, separated: &mut Separated<'_, 'args, Sqlite, &'static str>) {
+                match self {
+                        EventFilter::OrganizerId(op) => push_condition(separated, "organizer_id", op, |value| *value as i64),
+                        EventFilter::Cost(op) => push_condition(separated, "cost", op, |value| *value as i32),
+                        EventFilter::Status(op) => push_condition(separated, "status", op, ToString::to_string),
+                        EventFilter::Title(op) => push_condition(separated, "title", op, String::as_str),
+                        EventFilter::Category(op) => push_condition(separated, "category", op, String::as_str),
+                        EventFilter::Tag(FilterOp::Ne(tag)) => {
+                                separated.push_unseparated(r#"NOT EXISTS (SELECT 1 FROM "event_tag" WHERE event_id = "event".id AND tag = "#).push_bind_unseparated(tag).push_unseparated(")");
+                        },
+                        EventFilter::Tag(FilterOp::IsNull) => {
+                                separated.push_unseparated(r#"NOT EXISTS (SELECT 1 FROM "event_tag" WHERE event_id = "event".id)"#);
+                        },
+                        EventFilter::Tag(FilterOp::NotNull) => {
+                                separated.push_unseparated(r#"EXISTS (SELECT 1 FROM "event_tag" WHERE event_id = "event".id)"#);
+                        },
+                        EventFilter::Tag(op) => {
+                                separated.push_unseparated(r#"EXISTS (SELECT 1 FROM "event_tag" WHERE event_id = "event".id AND "#);
+                                push_condition(separated, "tag", op, String::as_str);
+                                separated.push_unseparated(")");
+                        },
+                        EventFilter::Date(op) => push_condition(separated, "date", op, |value| timestamptz(&value.0)),
+                        EventFilter::CreatedAt(op) => push_condition(separated, "created_at", op, |value| timestamp(&value.utc())),
+                        EventFilter::UpdatedAt(op) => push_condition(separated, "updated_at", op, |value| timestamp(&value.utc()))
+                }
+        }
+}
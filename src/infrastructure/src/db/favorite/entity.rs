@@ -1,4 +1,4 @@
-use domain::models::{event::EventModel, favorite::{FavoriteEventModel, FavoriteModel}};
+use domain::models::{event::EventModel, favorite::{FavoriteEventModel, FavoriteModel}, geo::GeoPoint};
 use sqlx::FromRow;
 
 use time::{OffsetDateTime, PrimitiveDateTime};
@@ -31,6 +31,13 @@ pub struct FavoriteEventProjection {
         pub event_date: OffsetDateTime,
         pub event_cost: i32,
         pub event_address: String,
+        pub event_capacity: Option<i32>,
+        pub event_category: Option<String>,
+        pub event_tags: Vec<String>,
+        pub event_language: String,
+        pub event_latitude: Option<f64>,
+        pub event_longitude: Option<f64>,
+        pub event_recurrence: Option<String>,
         pub event_status: String,
         pub event_created_at: PrimitiveDateTime,
         pub event_updated_at: PrimitiveDateTime,
@@ -48,13 +55,21 @@ impl From<FavoriteEventProjection> for FavoriteEventModel {
                         date: value.event_date,
                         cost: value.event_cost as u32,
                         address: value.event_address,
+                        capacity: value.event_capacity.map(|capacity| capacity as u32),
+                        category: value.event_category,
+                        tags: value.event_tags,
+                        language: value.event_language.parse().unwrap(),
+                        location: value.event_latitude.zip(value.event_longitude).map(|(latitude, longitude)| GeoPoint { latitude, longitude }),
+                        distance: None,
+                        rank: None,
+                        recurrence: value.event_recurrence.map(|recurrence| recurrence.parse().unwrap()),
                         status: value.event_status.parse().unwrap(),
                         created_at: value.event_created_at,
                         updated_at: value.event_updated_at
                 };
 
                 FavoriteEventModel {
-                        event: event.into(),
+                        event,
                         created_at: value.favorite_created_at,
                         updated_at: value.favorite_updated_at
                 }
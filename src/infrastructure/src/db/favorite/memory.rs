@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use domain::models::{cursor::CursorValue, favorite::{FavoriteFilter, FavoriteOrder}, utils::{FilterGroup, Offset, OrderOp}};
+
+use super::entity::{FavoriteEntity, FavoriteEventProjection};
+use super::repository::FavoriteRepository;
+use crate::db::{event::entity::EventEntity, memory::{FilterMatch, MemoryStore, MemoryTables, OrderValue, foreign_key_violation, is_after, matches_condition, matches_filters, now, page, sort, unique_violation}};
+use crate::Result;
+
+pub struct MemoryFavoriteRepository {
+        store: MemoryStore
+}
+
+impl MemoryFavoriteRepository {
+        pub fn new(store: MemoryStore) -> Self {
+                Self { store }
+        }
+}
+
+#[async_trait]
+impl FavoriteRepository for MemoryFavoriteRepository {
+        async fn get(&self, user_id: i64, event_id: i64) -> Result<Option<FavoriteEventProjection>> {
+                let tables = self.store.read();
+
+                Ok(tables.favorites.get(&(user_id, event_id))
+                        .zip(tables.events.get(&event_id))
+                        .map(|(favorite, event)| project(favorite, event)))
+        }
+
+        async fn list(&self, user_id: i64, offset: Offset, filters: &[FilterGroup<FavoriteFilter>], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventProjection>> {
+                let favorites = select(&self.store.read(), user_id, filters);
+
+                Ok(page(sort(favorites, order_by), &offset))
+        }
+
+        async fn list_after(&self, user_id: i64, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<FavoriteFilter>], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventProjection>> {
+                let favorites = select(&self.store.read(), user_id, filters);
+
+                Ok(sort(favorites, order_by)
+                        .into_iter()
+                        .filter(|favorite| after.is_none_or(|values| is_after(favorite, order_by, values)))
+                        .take(limit as usize)
+                        .collect())
+        }
+
+        async fn count(&self, user_id: i64, filters: &[FilterGroup<FavoriteFilter>]) -> Result<i64> {
+                Ok(select(&self.store.read(), user_id, filters).len() as i64)
+        }
+
+        async fn create(&self, user_id: i64, event_id: i64) -> Result<FavoriteEntity> {
+                let mut tables = self.store.write();
+
+                if !tables.users.contains_key(&user_id) {
+                        return Err(foreign_key_violation("favorite", "favorite_user_id_fkey"));
+                }
+                if !tables.events.contains_key(&event_id) {
+                        return Err(foreign_key_violation("favorite", "favorite_event_id_fkey"));
+                }
+                if tables.favorites.contains_key(&(user_id, event_id)) {
+                        return Err(unique_violation("favorite", "favorite_pkey"));
+                }
+
+                let created_at = now();
+                let favorite = FavoriteEntity { user_id, event_id, created_at, updated_at: created_at };
+                tables.favorites.insert((user_id, event_id), favorite.clone());
+
+                Ok(favorite)
+        }
+
+        async fn delete(&self, user_id: i64, event_id: i64) -> Result<Option<FavoriteEntity>> {
+                Ok(self.store.write().favorites.remove(&(user_id, event_id)))
+        }
+}
+
+fn select(tables: &MemoryTables, user_id: i64, filters: &[FilterGroup<FavoriteFilter>]) -> Vec<FavoriteEventProjection> {
+        tables.favorites.values()
+                .filter(|favorite| favorite.user_id == user_id)
+                .filter_map(|favorite| tables.events.get(&favorite.event_id).map(|event| project(favorite, event)))
+                .filter(|favorite| matches_filters(favorite, filters))
+                .collect()
+}
+
+fn project(favorite: &FavoriteEntity, event: &EventEntity) -> FavoriteEventProjection {
+        FavoriteEventProjection {
+                event_id: event.id,
+                event_organizer_id: event.organizer_id,
+                event_title: event.title.clone(),
+                event_description: event.description.clone(),
+                event_date: event.date,
+                event_cost: event.cost,
+                event_address: event.address.clone(),
+                event_capacity: event.capacity,
+                event_category: event.category.clone(),
+                event_tags: event.tags.clone(),
+                event_language: event.language.clone(),
+                event_latitude: event.latitude,
+                event_longitude: event.longitude,
+                event_recurrence: event.recurrence.clone(),
+                event_status: event.status.clone(),
+                event_created_at: event.created_at,
+                event_updated_at: event.updated_at,
+                favorite_created_at: favorite.created_at,
+                favorite_updated_at: favorite.updated_at
+        }
+}
+
+impl FilterMatch<FavoriteEventProjection> for FavoriteFilter {
+        fn matches(&self, favorite: &FavoriteEventProjection) -> bool {
+                match self {
+                        FavoriteFilter::EventId(op) => matches_condition(&CursorValue::Int(favorite.event_id), op, |value| CursorValue::Int(*value as i64))
+                }
+        }
+}
+
+impl OrderValue<FavoriteEventProjection> for FavoriteOrder {
+        fn order_value(&self, favorite: &FavoriteEventProjection) -> (CursorValue, &OrderOp) {
+                match self {
+                        FavoriteOrder::EventId(op) => (CursorValue::Int(favorite.event_id), op),
+                        FavoriteOrder::CreatedAt(op) => (CursorValue::Timestamp(favorite.favorite_created_at), op),
+                        FavoriteOrder::UpdatedAt(op) => (CursorValue::Timestamp(favorite.favorite_updated_at), op)
+                }
+        }
+}
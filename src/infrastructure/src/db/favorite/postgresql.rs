@@ -1,9 +1,10 @@
 use async_trait::async_trait;
-use sqlx::{Pool, Postgres, QueryBuilder};
-use domain::models::{favorite::{FavoriteFilter, FavoriteOrder}, utils::Offset};
+use sqlx::{Pool, Postgres, QueryBuilder, query_builder::Separated};
+use domain::models::{cursor::CursorValue, favorite::{FavoriteFilter, FavoriteOrder}, utils::{FilterGroup, Offset}};
 
 use super::entity::{FavoriteEntity, FavoriteEventProjection};
 use super::repository::FavoriteRepository;
+use crate::db::{compiler::{FilterColumn, OrderColumn, push_condition, push_filters, push_limit, push_offset, push_order}, keyset::push_keyset};
 use crate::Result;
 
 pub struct PgFavoriteRepository {
@@ -29,6 +30,13 @@ impl FavoriteRepository for PgFavoriteRepository {
                         e.date AS event_date,
                         e.cost AS event_cost,
                         e.address AS event_address,
+                        e.capacity AS event_capacity,
+                        e.category AS event_category,
+                        ARRAY(SELECT t.tag FROM "event_tag" t WHERE t.event_id = e.id ORDER BY t.tag) AS event_tags,
+                        e.language::TEXT AS event_language,
+                        e.latitude AS event_latitude,
+                        e.longitude AS event_longitude,
+                        e.recurrence AS event_recurrence,
                         e.status AS event_status,
                         e.created_at AS event_created_at,
                         e.updated_at AS event_updated_at,
@@ -47,7 +55,7 @@ impl FavoriteRepository for PgFavoriteRepository {
                 .map_err(Into::into)
         }
 
-        async fn list(&self, user_id: i64, offset: Offset, filters: &[FavoriteFilter], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventProjection>> {
+        async fn list(&self, user_id: i64, offset: Offset, filters: &[FilterGroup<FavoriteFilter>], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventProjection>> {
                 let mut query_builder =
                         QueryBuilder::<Postgres>::new(r#"
                         SELECT
@@ -58,43 +66,59 @@ impl FavoriteRepository for PgFavoriteRepository {
                         e.date AS event_date,
                         e.cost AS event_cost,
                         e.address AS event_address,
+                        e.capacity AS event_capacity,
+                        e.category AS event_category,
+                        ARRAY(SELECT t.tag FROM "event_tag" t WHERE t.event_id = e.id ORDER BY t.tag) AS event_tags,
+                        e.language::TEXT AS event_language,
+                        e.latitude AS event_latitude,
+                        e.longitude AS event_longitude,
+                        e.recurrence AS event_recurrence,
                         e.status AS event_status,
                         e.created_at AS event_created_at,
                         e.updated_at AS event_updated_at,
                         f.created_at AS favorite_created_at,
-                        f.updated_at AS favorite_updated_at
-                        FROM "favorite" f
-                        RIGHT JOIN "event" e ON f.event_id = e.id
-                        WHERE f.user_id = "#
+                        f.updated_at AS favorite_updated_at"#
                 );
-                query_builder.push_bind(user_id);
+                push_source(&mut query_builder, user_id, filters, None);
+                push_order(&mut query_builder, order_by);
 
-                if !filters.is_empty() {
-                        let mut separated = query_builder.separated(" AND ");
-                        separated.push_unseparated(" AND ");
+                push_offset(&mut query_builder, &offset);
 
-                        for filter in filters {
-                                match filter {
-                                        FavoriteFilter::EventId(op) => separated.push("event_id ").push_unseparated(op.operation() + " ").push_bind_unseparated(*op.value() as i64)
-                                };
-                        }
-                }
+                query_builder
+                        .build_query_as()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
 
-                if !order_by.is_empty() {
-                        let mut separated = query_builder.separated(", ");
-                        separated.push_unseparated(" ORDER BY ");
-
-                        for order in order_by {
-                                match order {
-                                        FavoriteOrder::EventId(op) => separated.push("event_id ").push_unseparated(op.to_string()),
-                                        FavoriteOrder::CreatedAt(op) => separated.push("created_at ").push_unseparated(op.to_string()),
-                                        FavoriteOrder::UpdatedAt(op) => separated.push("updated_at ").push_unseparated(op.to_string()),
-                                };
-                        }
-                }
+        async fn list_after(&self, user_id: i64, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<FavoriteFilter>], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventProjection>> {
+                let mut query_builder =
+                        QueryBuilder::<Postgres>::new(r#"
+                        SELECT
+                        e.id AS event_id,
+                        e.organizer_id AS event_organizer_id,
+                        e.title AS event_title,
+                        e.description AS event_description,
+                        e.date AS event_date,
+                        e.cost AS event_cost,
+                        e.address AS event_address,
+                        e.capacity AS event_capacity,
+                        e.category AS event_category,
+                        ARRAY(SELECT t.tag FROM "event_tag" t WHERE t.event_id = e.id ORDER BY t.tag) AS event_tags,
+                        e.language::TEXT AS event_language,
+                        e.latitude AS event_latitude,
+                        e.longitude AS event_longitude,
+                        e.recurrence AS event_recurrence,
+                        e.status AS event_status,
+                        e.created_at AS event_created_at,
+                        e.updated_at AS event_updated_at,
+                        f.created_at AS favorite_created_at,
+                        f.updated_at AS favorite_updated_at"#
+                );
+                push_source(&mut query_builder, user_id, filters, after.map(|after| (order_by, after)));
+                push_order(&mut query_builder, order_by);
 
-                query_builder.push(" LIMIT ").push_bind(offset.limit as i32);
-                query_builder.push(" OFFSET (").push_bind(offset.limit as i32).push(" * (").push_bind(offset.page as i32).push(" - 1))");
+                push_limit(&mut query_builder, limit);
 
                 query_builder
                         .build_query_as()
@@ -103,6 +127,17 @@ impl FavoriteRepository for PgFavoriteRepository {
                         .map_err(Into::into)
         }
 
+        async fn count(&self, user_id: i64, filters: &[FilterGroup<FavoriteFilter>]) -> Result<i64> {
+                let mut query_builder = QueryBuilder::<Postgres>::new("SELECT COUNT(*)");
+                push_source(&mut query_builder, user_id, filters, None);
+
+                query_builder
+                        .build_query_scalar()
+                        .fetch_one(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
         async fn create(&self, user_id: i64, event_id: i64) -> Result<FavoriteEntity> {
                 sqlx::query_as(
                         r#"
@@ -134,3 +169,30 @@ impl FavoriteRepository for PgFavoriteRepository {
                 .map_err(Into::into)
         }
 }
+
+fn push_source<'args>(query_builder: &mut QueryBuilder<'args, Postgres>, user_id: i64, filters: &'args [FilterGroup<FavoriteFilter>], after: Option<(&[FavoriteOrder], &'args [CursorValue])>) {
+        query_builder.push(r#" FROM "favorite" f RIGHT JOIN "event" e ON f.event_id = e.id WHERE f.user_id = "#).push_bind(user_id);
+
+        if !filters.is_empty() || after.is_some() {
+                let mut separated = query_builder.separated(" AND ");
+                separated.push_unseparated(" AND ");
+
+                push_filters(&mut separated, filters);
+
+                if let Some((order_by, values)) = after {
+                        push_keyset(&mut separated, order_by, values);
+                }
+        }
+}
+
+impl FilterColumn<Postgres> for FavoriteFilter {
+        fn push_filter<'args>(&'args self, separated: &mut Separated<'_, 'args, Postgres, &'static str>) {
+                match self {
+                        FavoriteFilter::EventId(op) => push_condition(separated, "f.event_id", op, |value| *value as i64)
+                }
+        }
+}
+
+impl OrderColumn for FavoriteOrder {
+        const QUALIFIER: &'static str = "f.";
+}
@@ -1,13 +1,42 @@
 use async_trait::async_trait;
-use domain::models::{favorite::{FavoriteFilter, FavoriteOrder}, utils::Offset};
+use domain::models::{cursor::CursorValue, favorite::{FavoriteFilter, FavoriteOrder}, utils::{FilterGroup, Offset}};
 
 use super::entity::{FavoriteEntity, FavoriteEventProjection};
 use crate::Result;
 
 #[async_trait]
-pub trait FavoriteRepository {
+pub trait FavoriteRepository: Send + Sync {
         async fn get(&self, user_id: i64, event_id: i64) -> Result<Option<FavoriteEventProjection>>;
-        async fn list(&self, user_id: i64, offset: Offset, filters: &[FavoriteFilter], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventProjection>>;
+        async fn list(&self, user_id: i64, offset: Offset, filters: &[FilterGroup<FavoriteFilter>], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventProjection>>;
+        async fn list_after(&self, user_id: i64, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<FavoriteFilter>], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventProjection>>;
+        async fn count(&self, user_id: i64, filters: &[FilterGroup<FavoriteFilter>]) -> Result<i64>;
         async fn create(&self, user_id: i64, event_id: i64) -> Result<FavoriteEntity>;
         async fn delete(&self, user_id: i64, event_id: i64) -> Result<Option<FavoriteEntity>>;
+}
+
+#[async_trait]
+impl<T: FavoriteRepository + ?Sized> FavoriteRepository for Box<T> {
+        async fn get(&self, user_id: i64, event_id: i64) -> Result<Option<FavoriteEventProjection>> {
+                (**self).get(user_id, event_id).await
+        }
+
+        async fn list(&self, user_id: i64, offset: Offset, filters: &[FilterGroup<FavoriteFilter>], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventProjection>> {
+                (**self).list(user_id, offset, filters, order_by).await
+        }
+
+        async fn list_after(&self, user_id: i64, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<FavoriteFilter>], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventProjection>> {
+                (**self).list_after(user_id, limit, after, filters, order_by).await
+        }
+
+        async fn count(&self, user_id: i64, filters: &[FilterGroup<FavoriteFilter>]) -> Result<i64> {
+                (**self).count(user_id, filters).await
+        }
+
+        async fn create(&self, user_id: i64, event_id: i64) -> Result<FavoriteEntity> {
+                (**self).create(user_id, event_id).await
+        }
+
+        async fn delete(&self, user_id: i64, event_id: i64) -> Result<Option<FavoriteEntity>> {
+                (**self).delete(user_id, event_id).await
+        }
 }
\ No newline at end of file
@@ -0,0 +1,247 @@
+use async_trait::async_trait;
+use sqlx::{FromRow, Pool, QueryBuilder, Sqlite, query_builder::Separated};
+use time::{OffsetDateTime, PrimitiveDateTime};
+use domain::models::{cursor::CursorValue, favorite::{FavoriteFilter, FavoriteOrder}, utils::{FilterGroup, Offset}};
+
+use super::entity::{FavoriteEntity, FavoriteEventProjection};
+use super::repository::FavoriteRepository;
+use crate::db::{compiler::{FilterColumn, push_condition, push_filters, push_limit, push_offset, push_order}, keyset::push_keyset, sqlite::split_tags};
+use crate::Result;
+
+pub struct SqliteFavoriteRepository {
+        pool: Pool<Sqlite>
+}
+
+impl SqliteFavoriteRepository {
+        pub fn new(pool: Pool<Sqlite>) -> Self {
+                Self { pool }
+        }
+}
+
+#[derive(FromRow)]
+struct FavoriteEventRow {
+        event_id: i64,
+        event_organizer_id: i64,
+        event_title: String,
+        event_description: String,
+        event_date: OffsetDateTime,
+        event_cost: i32,
+        event_address: String,
+        event_capacity: Option<i32>,
+        event_category: Option<String>,
+        event_tags: Option<String>,
+        event_language: String,
+        event_latitude: Option<f64>,
+        event_longitude: Option<f64>,
+        event_recurrence: Option<String>,
+        event_status: String,
+        event_created_at: PrimitiveDateTime,
+        event_updated_at: PrimitiveDateTime,
+        favorite_created_at: PrimitiveDateTime,
+        favorite_updated_at: PrimitiveDateTime
+}
+
+impl From<FavoriteEventRow> for FavoriteEventProjection {
+        fn from(value: FavoriteEventRow) -> Self {
+                FavoriteEventProjection {
+                        event_id: value.event_id,
+                        event_organizer_id: value.event_organizer_id,
+                        event_title: value.event_title,
+                        event_description: value.event_description,
+                        event_date: value.event_date,
+                        event_cost: value.event_cost,
+                        event_address: value.event_address,
+                        event_capacity: value.event_capacity,
+                        event_category: value.event_category,
+                        event_tags: split_tags(value.event_tags),
+                        event_language: value.event_language,
+                        event_latitude: value.event_latitude,
+                        event_longitude: value.event_longitude,
+                        event_recurrence: value.event_recurrence,
+                        event_status: value.event_status,
+                        event_created_at: value.event_created_at,
+                        event_updated_at: value.event_updated_at,
+                        favorite_created_at: value.favorite_created_at,
+                        favorite_updated_at: value.favorite_updated_at
+                }
+        }
+}
+
+#[async_trait]
+impl FavoriteRepository for SqliteFavoriteRepository {
+        async fn get(&self, user_id: i64, event_id: i64) -> Result<Option<FavoriteEventProjection>> {
+                sqlx::query_as::<_, FavoriteEventRow>(
+                r#"
+                        SELECT
+                        e.id AS event_id,
+                        e.organizer_id AS event_organizer_id,
+                        e.title AS event_title,
+                        e.description AS event_description,
+                        e.date AS event_date,
+                        e.cost AS event_cost,
+                        e.address AS event_address,
+                        e.capacity AS event_capacity,
+                        e.category AS event_category,
+                        (SELECT group_concat(tag, char(31)) FROM (SELECT t.tag FROM "event_tag" t WHERE t.event_id = e.id ORDER BY t.tag)) AS event_tags,
+                        e.language AS event_language,
+                        e.latitude AS event_latitude,
+                        e.longitude AS event_longitude,
+                        e.recurrence AS event_recurrence,
+                        e.status AS event_status,
+                        e.created_at AS event_created_at,
+                        e.updated_at AS event_updated_at,
+                        f.created_at AS favorite_created_at,
+                        f.updated_at AS favorite_updated_at
+                        FROM "favorite" f
+                        JOIN "event" e ON f.event_id = e.id
+                        WHERE f.user_id = ?
+                        AND f.event_id = ?
+                "#
+                )
+                .bind(user_id)
+                .bind(event_id)
+                .fetch_optional(&self.pool)
+                .await
+                .map(|favorite| favorite.map(Into::into))
+                .map_err(Into::into)
+        }
+
+        async fn list(&self, user_id: i64, offset: Offset, filters: &[FilterGroup<FavoriteFilter>], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventProjection>> {
+                let mut query_builder =
+                        QueryBuilder::<Sqlite>::new(r#"
+                        SELECT
+                        e.id AS event_id,
+                        e.organizer_id AS event_organizer_id,
+                        e.title AS event_title,
+                        e.description AS event_description,
+                        e.date AS event_date,
+                        e.cost AS event_cost,
+                        e.address AS event_address,
+                        e.capacity AS event_capacity,
+                        e.category AS event_category,
+                        (SELECT group_concat(tag, char(31)) FROM (SELECT t.tag FROM "event_tag" t WHERE t.event_id = e.id ORDER BY t.tag)) AS event_tags,
+                        e.language AS event_language,
+                        e.latitude AS event_latitude,
+                        e.longitude AS event_longitude,
+                        e.recurrence AS event_recurrence,
+                        e.status AS event_status,
+                        e.created_at AS event_created_at,
+                        e.updated_at AS event_updated_at,
+                        f.created_at AS favorite_created_at,
+                        f.updated_at AS favorite_updated_at"#
+                );
+                push_source(&mut query_builder, user_id, filters, None);
+                push_order(&mut query_builder, order_by);
+
+                push_offset(&mut query_builder, &offset);
+
+                query_builder
+                        .build_query_as::<FavoriteEventRow>()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map(|favorites| favorites.into_iter().map(Into::into).collect())
+                        .map_err(Into::into)
+        }
+
+        async fn list_after(&self, user_id: i64, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<FavoriteFilter>], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventProjection>> {
+                let mut query_builder =
+                        QueryBuilder::<Sqlite>::new(r#"
+                        SELECT
+                        e.id AS event_id,
+                        e.organizer_id AS event_organizer_id,
+                        e.title AS event_title,
+                        e.description AS event_description,
+                        e.date AS event_date,
+                        e.cost AS event_cost,
+                        e.address AS event_address,
+                        e.capacity AS event_capacity,
+                        e.category AS event_category,
+                        (SELECT group_concat(tag, char(31)) FROM (SELECT t.tag FROM "event_tag" t WHERE t.event_id = e.id ORDER BY t.tag)) AS event_tags,
+                        e.language AS event_language,
+                        e.latitude AS event_latitude,
+                        e.longitude AS event_longitude,
+                        e.recurrence AS event_recurrence,
+                        e.status AS event_status,
+                        e.created_at AS event_created_at,
+                        e.updated_at AS event_updated_at,
+                        f.created_at AS favorite_created_at,
+                        f.updated_at AS favorite_updated_at"#
+                );
+                push_source(&mut query_builder, user_id, filters, after.map(|after| (order_by, after)));
+                push_order(&mut query_builder, order_by);
+
+                push_limit(&mut query_builder, limit);
+
+                query_builder
+                        .build_query_as::<FavoriteEventRow>()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map(|favorites| favorites.into_iter().map(Into::into).collect())
+                        .map_err(Into::into)
+        }
+
+        async fn count(&self, user_id: i64, filters: &[FilterGroup<FavoriteFilter>]) -> Result<i64> {
+                let mut query_builder = QueryBuilder::<Sqlite>::new("SELECT COUNT(*)");
+                push_source(&mut query_builder, user_id, filters, None);
+
+                query_builder
+                        .build_query_scalar()
+                        .fetch_one(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn create(&self, user_id: i64, event_id: i64) -> Result<FavoriteEntity> {
+                sqlx::query_as(
+                        r#"
+                        INSERT INTO "favorite" (user_id, event_id)
+                        VALUES (?, ?)
+                        RETURNING user_id, event_id, created_at, updated_at
+                        "#
+                )
+                .bind(user_id)
+                .bind(event_id)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn delete(&self, user_id: i64, event_id: i64) -> Result<Option<FavoriteEntity>>  {
+                sqlx::query_as(
+                        r#"
+                        DELETE FROM "favorite"
+                        WHERE user_id = ?
+                        AND event_id = ?
+                        RETURNING user_id, event_id, created_at, updated_at
+                        "#
+                )
+                .bind(user_id)
+                .bind(event_id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+}
+
+fn push_source<'args>(query_builder: &mut QueryBuilder<'args, Sqlite>, user_id: i64, filters: &'args [FilterGroup<FavoriteFilter>], after: Option<(&[FavoriteOrder], &'args [CursorValue])>) {
+        query_builder.push(r#" FROM "favorite" f JOIN "event" e ON f.event_id = e.id WHERE f.user_id = "#).push_bind(user_id);
+
+        if !filters.is_empty() || after.is_some() {
+                let mut separated = query_builder.separated(" AND ");
+                separated.push_unseparated(" AND ");
+
+                push_filters(&mut separated, filters);
+
+                if let Some((order_by, values)) = after {
+                        push_keyset(&mut separated, order_by, values);
+                }
+        }
+}
+
+impl FilterColumn<Sqlite> for FavoriteFilter {
+        fn push_filter<'args>(&'args self, separated: &mut Separated<'_, 'args, Sqlite, &'static str>) {
+                match self {
+                        FavoriteFilter::EventId(op) => push_condition(separated, "f.event_id", op, |value| *value as i64)
+                }
+        }
+}
@@ -0,0 +1,43 @@
+use domain::models::{cursor::CursorValue, utils::{OrderField, OrderOp}};
+use sqlx::query_builder::Separated;
+
+use super::compiler::{Dialect, OrderColumn};
+
+pub fn push_keyset<'args, DB: Dialect, O: OrderColumn>(separated: &mut Separated<'_, 'args, DB, &'static str>, order_by: &[O], values: &'args [CursorValue]) {
+        let (keys, values): (Vec<_>, Vec<_>) = order_by.iter()
+                .map(OrderField::field)
+                .zip(values)
+                .unzip();
+
+        separated.push("(FALSE");
+
+        for (position, ((column, op), value)) in keys.iter().zip(values.iter().copied()).enumerate() {
+                separated.push_unseparated(" OR (TRUE");
+
+                for ((column, _), value) in keys.iter().zip(values.iter().copied()).take(position) {
+                        separated.push_unseparated(" AND ").push_unseparated(O::QUALIFIER).push_unseparated(*column);
+                        push_comparison(separated, " = ", value);
+                }
+
+                match (op, value) {
+                        (OrderOp::Asc, CursorValue::Null) => separated.push_unseparated(" AND FALSE"),
+                        (OrderOp::Desc, CursorValue::Null) => separated.push_unseparated(" AND ").push_unseparated(O::QUALIFIER).push_unseparated(*column).push_unseparated(" IS NOT NULL"),
+                        (OrderOp::Asc, value) => push_comparison(separated.push_unseparated(" AND ").push_unseparated(O::QUALIFIER).push_unseparated(*column), " > ", value),
+                        (OrderOp::Desc, value) => push_comparison(separated.push_unseparated(" AND ").push_unseparated(O::QUALIFIER).push_unseparated(*column), " < ", value)
+                };
+
+                separated.push_unseparated(")");
+        }
+
+        separated.push_unseparated(")");
+}
+
+fn push_comparison<'s, 'q, 'args, DB: Dialect>(separated: &'s mut Separated<'q, 'args, DB, &'static str>, operation: &'static str, value: &'args CursorValue) -> &'s mut Separated<'q, 'args, DB, &'static str> {
+        match value {
+                CursorValue::Null => separated.push_unseparated(" IS NULL"),
+                value => {
+                        DB::push_value(separated.push_unseparated(operation), value);
+                        separated
+                }
+        }
+}
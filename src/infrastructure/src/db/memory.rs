@@ -0,0 +1,251 @@
+use std::{cmp::Ordering, collections::BTreeMap, sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard}};
+
+use domain::models::{cursor::CursorValue, utils::{FilterGroup, FilterOp, Offset, OrderOp}};
+use time::{OffsetDateTime, PrimitiveDateTime};
+use uuid::Uuid;
+
+use super::{
+        category::entity::CategoryEntity,
+        error::DbError,
+        event::entity::{EventEntity, EventStatusChangeEntity},
+        favorite::entity::FavoriteEntity,
+        occurrence::entity::OccurrenceEntity,
+        registration::entity::RegistrationEntity,
+        session::entity::{SecurityEventEntity, SessionEntity},
+        user::entity::UserEntity,
+        waitlist::entity::WaitlistEntity
+};
+
+const CATEGORIES: [&str; 9] = ["concert", "conference", "exhibition", "festival", "meetup", "music", "sport", "theatre", "workshop"];
+
+#[derive(Debug, thiserror::Error)]
+#[error("{0} constraint violated")]
+struct ConstraintError(&'static str);
+
+#[derive(Debug, Default)]
+pub(crate) struct MemoryTables {
+        pub users: BTreeMap<i64, UserEntity>,
+        pub events: BTreeMap<i64, EventEntity>,
+        pub status_history: BTreeMap<i64, EventStatusChangeEntity>,
+        pub categories: BTreeMap<String, CategoryEntity>,
+        pub favorites: BTreeMap<(i64, i64), FavoriteEntity>,
+        pub registrations: BTreeMap<(i64, i64), RegistrationEntity>,
+        pub waitlist: BTreeMap<i64, WaitlistEntity>,
+        pub occurrences: BTreeMap<(i64, OffsetDateTime), OccurrenceEntity>,
+        pub sessions: BTreeMap<Uuid, (String, SessionEntity)>,
+        pub security_events: BTreeMap<i64, SecurityEventEntity>,
+        sequences: BTreeMap<&'static str, i64>
+}
+
+impl MemoryTables {
+        pub fn next_id(&mut self, table: &'static str) -> i64 {
+                let id = self.sequences.entry(table).or_default();
+                *id += 1;
+                *id
+        }
+
+        pub fn delete_user(&mut self, id: i64) -> Option<UserEntity> {
+                let user = self.users.remove(&id)?;
+
+                let events: Vec<_> = self.events.values()
+                        .filter(|event| event.organizer_id == id)
+                        .map(|event| event.id)
+                        .collect();
+                for event_id in events {
+                        self.delete_event(event_id);
+                }
+
+                self.favorites.retain(|(user_id, _), _| *user_id != id);
+                self.registrations.retain(|(user_id, _), _| *user_id != id);
+                self.waitlist.retain(|_, waitlist| waitlist.user_id != id);
+                self.sessions.retain(|_, (_, session)| session.user_id != id);
+                self.security_events.retain(|_, event| event.user_id != id);
+                self.status_history.values_mut()
+                        .filter(|change| change.changed_by == Some(id))
+                        .for_each(|change| change.changed_by = None);
+
+                Some(user)
+        }
+
+        pub fn delete_event(&mut self, id: i64) -> Option<EventEntity> {
+                let event = self.events.remove(&id)?;
+
+                self.favorites.retain(|(_, event_id), _| *event_id != id);
+                self.registrations.retain(|(_, event_id), _| *event_id != id);
+                self.waitlist.retain(|_, waitlist| waitlist.event_id != id);
+                self.status_history.retain(|_, change| change.event_id != id);
+                self.occurrences.retain(|(event_id, _), _| *event_id != id);
+
+                Some(event)
+        }
+
+        pub fn delete_category(&mut self, name: &str) -> Option<CategoryEntity> {
+                let category = self.categories.remove(name)?;
+
+                let updated_at = now();
+                self.events.values_mut()
+                        .filter(|event| event.category.as_deref() == Some(name))
+                        .for_each(|event| {
+                                event.category = None;
+                                event.updated_at = updated_at;
+                        });
+
+                Some(category)
+        }
+
+        pub fn registered(&self, event_id: i64) -> usize {
+                self.registrations.keys().filter(|(_, id)| *id == event_id).count()
+        }
+
+        pub fn queue(&self, event_id: i64) -> impl Iterator<Item = (i64, &WaitlistEntity)> {
+                self.waitlist.values()
+                        .filter(move |waitlist| waitlist.event_id == event_id)
+                        .zip(1..)
+                        .map(|(waitlist, position)| (position, waitlist))
+        }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryStore {
+        tables: Arc<RwLock<MemoryTables>>
+}
+
+impl MemoryStore {
+        pub fn new() -> Self {
+                let created_at = now();
+                let categories = CATEGORIES.iter()
+                        .map(|name| (name.to_string(), CategoryEntity { name: name.to_string(), created_at, updated_at: created_at }))
+                        .collect();
+
+                Self {
+                        tables: Arc::new(RwLock::new(MemoryTables { categories, ..Default::default() }))
+                }
+        }
+
+        pub(crate) fn read(&self) -> RwLockReadGuard<'_, MemoryTables> {
+                self.tables.read().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        pub(crate) fn write(&self) -> RwLockWriteGuard<'_, MemoryTables> {
+                self.tables.write().unwrap_or_else(PoisonError::into_inner)
+        }
+}
+
+impl Default for MemoryStore {
+        fn default() -> Self {
+                Self::new()
+        }
+}
+
+pub(crate) trait FilterMatch<E> {
+        fn matches(&self, entity: &E) -> bool;
+}
+
+pub(crate) trait OrderValue<E> {
+        fn order_value(&self, entity: &E) -> (CursorValue, &OrderOp);
+}
+
+pub(crate) fn now() -> PrimitiveDateTime {
+        let now = OffsetDateTime::now_utc();
+        PrimitiveDateTime::new(now.date(), now.time())
+}
+
+pub(crate) fn unique_violation(table: &str, constraint: &'static str) -> DbError {
+        DbError::UniqueViolation {
+                table: table.to_string(),
+                column: constraint.to_string(),
+                source: Box::new(ConstraintError(constraint))
+        }
+}
+
+pub(crate) fn foreign_key_violation(table: &str, constraint: &'static str) -> DbError {
+        DbError::ForeignKeyViolation {
+                table: table.to_string(),
+                column: constraint.to_string(),
+                source: Box::new(ConstraintError(constraint))
+        }
+}
+
+pub(crate) fn matches_filters<E, F: FilterMatch<E>>(entity: &E, filters: &[FilterGroup<F>]) -> bool {
+        filters.iter().all(|FilterGroup(group)| group.iter().any(|filter| filter.matches(entity)))
+}
+
+pub(crate) fn matches_condition<T>(value: &CursorValue, op: &FilterOp<T>, bind: impl Fn(&T) -> CursorValue) -> bool {
+        if let CursorValue::Null = value {
+                return matches!(op, FilterOp::IsNull);
+        }
+
+        let compare = |other: &T| compare(value, &bind(other));
+        match op {
+                FilterOp::Eq(other) => compare(other).is_eq(),
+                FilterOp::Ne(other) => compare(other).is_ne(),
+                FilterOp::Gt(other) => compare(other).is_gt(),
+                FilterOp::Lt(other) => compare(other).is_lt(),
+                FilterOp::Gte(other) => compare(other).is_ge(),
+                FilterOp::Lte(other) => compare(other).is_le(),
+                FilterOp::In(others) => others.iter().any(|other| compare(other).is_eq()),
+                FilterOp::Between(from, to) => compare(from).is_ge() && compare(to).is_le(),
+                FilterOp::Contains(needle) => text(value).contains(&needle.to_lowercase()),
+                FilterOp::StartsWith(needle) => text(value).starts_with(&needle.to_lowercase()),
+                FilterOp::IsNull => false,
+                FilterOp::NotNull => true
+        }
+}
+
+pub(crate) fn sort<E, O: OrderValue<E>>(rows: Vec<E>, order_by: &[O]) -> Vec<E> {
+        let mut rows: Vec<_> = rows.into_iter()
+                .map(|row| (order_by.iter().map(|order| order.order_value(&row)).collect::<Vec<_>>(), row))
+                .collect();
+
+        rows.sort_by(|(left, _), (right, _)| compare_keys(left, right.iter().map(|(value, _)| value)));
+
+        rows.into_iter().map(|(_, row)| row).collect()
+}
+
+pub(crate) fn is_after<E, O: OrderValue<E>>(entity: &E, order_by: &[O], values: &[CursorValue]) -> bool {
+        let keys: Vec<_> = order_by.iter().map(|order| order.order_value(entity)).collect();
+        compare_keys(&keys, values.iter()).is_gt()
+}
+
+pub(crate) fn page<E>(rows: Vec<E>, offset: &Offset) -> Vec<E> {
+        rows.into_iter()
+                .skip(usize::try_from(offset.skip()).unwrap_or(usize::MAX))
+                .take(offset.limit as usize)
+                .collect()
+}
+
+fn compare_keys<'a>(keys: &[(CursorValue, &OrderOp)], values: impl Iterator<Item = &'a CursorValue>) -> Ordering {
+        keys.iter()
+                .zip(values)
+                .map(|((key, op), value)| match op {
+                        OrderOp::Asc => compare(key, value),
+                        OrderOp::Desc => compare(key, value).reverse()
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+}
+
+fn compare(left: &CursorValue, right: &CursorValue) -> Ordering {
+        match (left, right) {
+                (CursorValue::Null, CursorValue::Null) => Ordering::Equal,
+                (CursorValue::Null, _) => Ordering::Greater,
+                (_, CursorValue::Null) => Ordering::Less,
+                (CursorValue::Int(left), CursorValue::Int(right)) => left.cmp(right),
+                (CursorValue::Float(left), CursorValue::Float(right)) => left.total_cmp(right),
+                (CursorValue::Text(left), CursorValue::Text(right)) => left.cmp(right),
+                (CursorValue::Timestamp(left), CursorValue::Timestamp(right)) => left.cmp(right),
+                (CursorValue::TimestampTz(left), CursorValue::TimestampTz(right)) => left.cmp(right),
+                _ => Ordering::Equal
+        }
+}
+
+fn text(value: &CursorValue) -> String {
+        match value {
+                CursorValue::Int(value) => value.to_string(),
+                CursorValue::Float(value) => value.to_string(),
+                CursorValue::Text(value) => value.to_lowercase(),
+                CursorValue::Timestamp(value) => value.to_string(),
+                CursorValue::TimestampTz(value) => value.to_string(),
+                CursorValue::Null => String::new()
+        }
+}
@@ -1,6 +1,16 @@
 pub mod user;
+pub mod category;
+pub mod compiler;
 pub mod event;
 pub mod favorite;
+pub mod keyset;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+#[cfg(feature = "memory")]
+pub mod memory;
+pub mod occurrence;
+pub mod registration;
+pub mod waitlist;
 pub mod provider;
-pub mod refresh;
+pub mod session;
 pub mod error;
\ No newline at end of file
@@ -0,0 +1,29 @@
+use domain::models::occurrence::OccurrenceOverrideModel;
+use sqlx::FromRow;
+
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+#[derive(Debug, Clone, FromRow)]
+pub struct OccurrenceEntity {
+        pub event_id: i64,
+        pub original_date: OffsetDateTime,
+        pub date: Option<OffsetDateTime>,
+        pub address: Option<String>,
+        pub cancelled: bool,
+        pub created_at: PrimitiveDateTime,
+        pub updated_at: PrimitiveDateTime
+}
+
+impl From<OccurrenceEntity> for OccurrenceOverrideModel {
+        fn from(value: OccurrenceEntity) -> Self {
+                OccurrenceOverrideModel {
+                        event_id: value.event_id as u64,
+                        original_date: value.original_date,
+                        date: value.date,
+                        address: value.address,
+                        cancelled: value.cancelled,
+                        created_at: value.created_at,
+                        updated_at: value.updated_at
+                }
+        }
+}
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use domain::models::occurrence::OccurrenceOverride;
+use time::OffsetDateTime;
+
+use super::entity::OccurrenceEntity;
+use super::repository::OccurrenceRepository;
+use crate::db::memory::{MemoryStore, foreign_key_violation, now};
+use crate::Result;
+
+pub struct MemoryOccurrenceRepository {
+        store: MemoryStore
+}
+
+impl MemoryOccurrenceRepository {
+        pub fn new(store: MemoryStore) -> Self {
+                Self { store }
+        }
+}
+
+#[async_trait]
+impl OccurrenceRepository for MemoryOccurrenceRepository {
+        async fn list(&self, event_ids: &[i64], from: OffsetDateTime, to: OffsetDateTime) -> Result<Vec<OccurrenceEntity>> {
+                let window = |date: OffsetDateTime| from <= date && date <= to;
+
+                Ok(self.store.read().occurrences.values()
+                        .filter(|occurrence| event_ids.contains(&occurrence.event_id))
+                        .filter(|occurrence| window(occurrence.original_date) || occurrence.date.is_some_and(window))
+                        .cloned()
+                        .collect())
+        }
+
+        async fn list_all(&self, event_ids: &[i64]) -> Result<Vec<OccurrenceEntity>> {
+                Ok(self.store.read().occurrences.values()
+                        .filter(|occurrence| event_ids.contains(&occurrence.event_id))
+                        .cloned()
+                        .collect())
+        }
+
+        async fn upsert(&self, event_id: i64, original_date: OffsetDateTime, occurrence: &OccurrenceOverride) -> Result<OccurrenceEntity> {
+                let mut tables = self.store.write();
+
+                if !tables.events.contains_key(&event_id) {
+                        return Err(foreign_key_violation("event_occurrence", "event_occurrence_event_id_fkey"));
+                }
+
+                let updated_at = now();
+                let entity = tables.occurrences.entry((event_id, original_date))
+                        .and_modify(|entity| entity.updated_at = updated_at)
+                        .or_insert_with(|| OccurrenceEntity {
+                                event_id,
+                                original_date,
+                                date: None,
+                                address: None,
+                                cancelled: false,
+                                created_at: updated_at,
+                                updated_at
+                        });
+                entity.date = occurrence.date;
+                entity.address = occurrence.address.clone();
+                entity.cancelled = occurrence.cancelled;
+
+                Ok(entity.clone())
+        }
+
+        async fn delete(&self, event_id: i64, original_date: OffsetDateTime) -> Result<Option<OccurrenceEntity>> {
+                Ok(self.store.write().occurrences.remove(&(event_id, original_date)))
+        }
+}
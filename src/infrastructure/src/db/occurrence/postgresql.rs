@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use domain::models::occurrence::OccurrenceOverride;
+use time::OffsetDateTime;
+
+use super::entity::OccurrenceEntity;
+use super::repository::OccurrenceRepository;
+use crate::Result;
+
+pub struct PgOccurrenceRepository {
+        pool: Pool<Postgres>
+}
+
+impl PgOccurrenceRepository {
+        pub fn new(pool: Pool<Postgres>) -> Self {
+                Self { pool }
+        }
+}
+
+#[async_trait]
+impl OccurrenceRepository for PgOccurrenceRepository {
+        async fn list(&self, event_ids: &[i64], from: OffsetDateTime, to: OffsetDateTime) -> Result<Vec<OccurrenceEntity>> {
+                sqlx::query_as(
+                        r#"
+                        SELECT event_id, original_date, date, address, cancelled, created_at, updated_at
+                        FROM "event_occurrence"
+                        WHERE event_id = ANY($1)
+                        AND (original_date BETWEEN $2 AND $3 OR date BETWEEN $2 AND $3)
+                        "#
+                )
+                .bind(event_ids)
+                .bind(from)
+                .bind(to)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn list_all(&self, event_ids: &[i64]) -> Result<Vec<OccurrenceEntity>> {
+                sqlx::query_as(
+                        r#"
+                        SELECT event_id, original_date, date, address, cancelled, created_at, updated_at
+                        FROM "event_occurrence"
+                        WHERE event_id = ANY($1)
+                        ORDER BY event_id, original_date
+                        "#
+                )
+                .bind(event_ids)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn upsert(&self, event_id: i64, original_date: OffsetDateTime, occurrence: &OccurrenceOverride) -> Result<OccurrenceEntity> {
+                sqlx::query_as(
+                        r#"
+                        INSERT INTO "event_occurrence" (event_id, original_date, date, address, cancelled)
+                        VALUES ($1, $2, $3, $4, $5)
+                        ON CONFLICT (event_id, original_date) DO UPDATE
+                        SET date = EXCLUDED.date,
+                        address = EXCLUDED.address,
+                        cancelled = EXCLUDED.cancelled
+                        RETURNING event_id, original_date, date, address, cancelled, created_at, updated_at
+                        "#
+                )
+                .bind(event_id)
+                .bind(original_date)
+                .bind(occurrence.date)
+                .bind(&occurrence.address)
+                .bind(occurrence.cancelled)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn delete(&self, event_id: i64, original_date: OffsetDateTime) -> Result<Option<OccurrenceEntity>> {
+                sqlx::query_as(
+                        r#"
+                        DELETE FROM "event_occurrence"
+                        WHERE event_id = $1
+                        AND original_date = $2
+                        RETURNING event_id, original_date, date, address, cancelled, created_at, updated_at
+                        "#
+                )
+                .bind(event_id)
+                .bind(original_date)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+}
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use domain::models::occurrence::OccurrenceOverride;
+use time::OffsetDateTime;
+
+use super::entity::OccurrenceEntity;
+use crate::Result;
+
+#[async_trait]
+pub trait OccurrenceRepository: Send + Sync {
+        async fn list(&self, event_ids: &[i64], from: OffsetDateTime, to: OffsetDateTime) -> Result<Vec<OccurrenceEntity>>;
+        async fn list_all(&self, event_ids: &[i64]) -> Result<Vec<OccurrenceEntity>>;
+        async fn upsert(&self, event_id: i64, original_date: OffsetDateTime, occurrence: &OccurrenceOverride) -> Result<OccurrenceEntity>;
+        async fn delete(&self, event_id: i64, original_date: OffsetDateTime) -> Result<Option<OccurrenceEntity>>;
+}
+
+#[async_trait]
+impl<T: OccurrenceRepository + ?Sized> OccurrenceRepository for Box<T> {
+        async fn list(&self, event_ids: &[i64], from: OffsetDateTime, to: OffsetDateTime) -> Result<Vec<OccurrenceEntity>> {
+                (**self).list(event_ids, from, to).await
+        }
+
+        async fn list_all(&self, event_ids: &[i64]) -> Result<Vec<OccurrenceEntity>> {
+                (**self).list_all(event_ids).await
+        }
+
+        async fn upsert(&self, event_id: i64, original_date: OffsetDateTime, occurrence: &OccurrenceOverride) -> Result<OccurrenceEntity> {
+                (**self).upsert(event_id, original_date, occurrence).await
+        }
+
+        async fn delete(&self, event_id: i64, original_date: OffsetDateTime) -> Result<Option<OccurrenceEntity>> {
+                (**self).delete(event_id, original_date).await
+        }
+}
@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use sqlx::{Pool, QueryBuilder, Sqlite};
+use domain::models::occurrence::OccurrenceOverride;
+use time::OffsetDateTime;
+
+use super::entity::OccurrenceEntity;
+use super::repository::OccurrenceRepository;
+use crate::db::sqlite::{NOW, timestamptz};
+use crate::Result;
+
+pub struct SqliteOccurrenceRepository {
+        pool: Pool<Sqlite>
+}
+
+impl SqliteOccurrenceRepository {
+        pub fn new(pool: Pool<Sqlite>) -> Self {
+                Self { pool }
+        }
+}
+
+#[async_trait]
+impl OccurrenceRepository for SqliteOccurrenceRepository {
+        async fn list(&self, event_ids: &[i64], from: OffsetDateTime, to: OffsetDateTime) -> Result<Vec<OccurrenceEntity>> {
+                if event_ids.is_empty() {
+                        return Ok(Vec::new());
+                }
+
+                let (from, to) = (timestamptz(&from), timestamptz(&to));
+
+                let mut query_builder =
+                        QueryBuilder::<Sqlite>::new(r#"
+                        SELECT event_id, original_date, date, address, cancelled, created_at, updated_at
+                        FROM "event_occurrence"
+                        WHERE event_id IN ("#);
+                let mut separated = query_builder.separated(", ");
+                for event_id in event_ids {
+                        separated.push_bind(*event_id);
+                }
+                query_builder.push(") AND (original_date BETWEEN ").push_bind(&from).push(" AND ").push_bind(&to);
+                query_builder.push(" OR date BETWEEN ").push_bind(&from).push(" AND ").push_bind(&to).push(")");
+
+                query_builder
+                        .build_query_as()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn list_all(&self, event_ids: &[i64]) -> Result<Vec<OccurrenceEntity>> {
+                if event_ids.is_empty() {
+                        return Ok(Vec::new());
+                }
+
+                let mut query_builder =
+                        QueryBuilder::<Sqlite>::new(r#"
+                        SELECT event_id, original_date, date, address, cancelled, created_at, updated_at
+                        FROM "event_occurrence"
+                        WHERE event_id IN ("#);
+                let mut separated = query_builder.separated(", ");
+                for event_id in event_ids {
+                        separated.push_bind(*event_id);
+                }
+                query_builder.push(") ORDER BY event_id, original_date");
+
+                query_builder
+                        .build_query_as()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn upsert(&self, event_id: i64, original_date: OffsetDateTime, occurrence: &OccurrenceOverride) -> Result<OccurrenceEntity> {
+                sqlx::query_as(&format!(
+                        r#"
+                        INSERT INTO "event_occurrence" (event_id, original_date, date, address, cancelled)
+                        VALUES (?, ?, ?, ?, ?)
+                        ON CONFLICT (event_id, original_date) DO UPDATE
+                        SET date = excluded.date,
+                        address = excluded.address,
+                        cancelled = excluded.cancelled,
+                        updated_at = {NOW}
+                        RETURNING event_id, original_date, date, address, cancelled, created_at, updated_at
+                        "#
+                ))
+                .bind(event_id)
+                .bind(timestamptz(&original_date))
+                .bind(occurrence.date.as_ref().map(timestamptz))
+                .bind(&occurrence.address)
+                .bind(occurrence.cancelled)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn delete(&self, event_id: i64, original_date: OffsetDateTime) -> Result<Option<OccurrenceEntity>> {
+                sqlx::query_as(
+                        r#"
+                        DELETE FROM "event_occurrence"
+                        WHERE event_id = ?
+                        AND original_date = ?
+                        RETURNING event_id, original_date, date, address, cancelled, created_at, updated_at
+                        "#
+                )
+                .bind(event_id)
+                .bind(timestamptz(&original_date))
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+}
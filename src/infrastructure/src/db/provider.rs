@@ -1,55 +1,42 @@
-use std::{sync::LazyLock, time::Duration};
-
+use domain::config::DatabaseConfig;
 use sqlx::{Pool, Postgres, postgres::PgPoolOptions};
+#[cfg(feature = "sqlite")]
+use std::str::FromStr;
+#[cfg(feature = "sqlite")]
+use sqlx::{Sqlite, sqlite::{SqliteConnectOptions, SqlitePoolOptions}};
+
+#[cfg(feature = "memory")]
+use crate::db::{category::memory::MemoryCategoryRepository, session::memory::MemorySessionRepository, event::memory::MemoryEventRepository, favorite::memory::MemoryFavoriteRepository, memory::MemoryStore, occurrence::memory::MemoryOccurrenceRepository, registration::memory::MemoryRegistrationRepository, user::memory::MemoryUserRepository, waitlist::memory::MemoryWaitlistRepository};
+#[cfg(feature = "sqlite")]
+use crate::db::{category::sqlite::SqliteCategoryRepository, session::sqlite::SqliteSessionRepository, event::sqlite::SqliteEventRepository, favorite::sqlite::SqliteFavoriteRepository, occurrence::sqlite::SqliteOccurrenceRepository, registration::sqlite::SqliteRegistrationRepository, user::sqlite::SqliteUserRepository, waitlist::sqlite::SqliteWaitlistRepository};
+use crate::{Result, db::{category::repository::CategoryRepository, session::repository::SessionRepository, event::repository::EventRepository, favorite::repository::FavoriteRepository, occurrence::repository::OccurrenceRepository, registration::repository::RegistrationRepository, user::repository::UserRepository, waitlist::repository::WaitlistRepository, category::postgresql::PgCategoryRepository, session::postgresql::PgSessionRepository, event::postgresql::PgEventRepository, favorite::postgresql::PgFavoriteRepository, occurrence::postgresql::PgOccurrenceRepository, registration::postgresql::PgRegistrationRepository, user::postgresql::PgUserRepository, waitlist::postgresql::PgWaitlistRepository}};
+
+pub trait Provider: Send + Sync {
+        fn provide_user_repository(&self) -> Box<dyn UserRepository>;
+        fn provide_event_repository(&self) -> Box<dyn EventRepository>;
+        fn provide_category_repository(&self) -> Box<dyn CategoryRepository>;
+        fn provide_favorite_repository(&self) -> Box<dyn FavoriteRepository>;
+        fn provide_occurrence_repository(&self) -> Box<dyn OccurrenceRepository>;
+        fn provide_registration_repository(&self) -> Box<dyn RegistrationRepository>;
+        fn provide_waitlist_repository(&self) -> Box<dyn WaitlistRepository>;
+        fn provide_session_repository(&self) -> Box<dyn SessionRepository>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+        Postgres,
+        Sqlite
+}
 
-use crate::{Result, db::{refresh::postgresql::PgRefreshRepository, event::postgresql::PgEventRepository, favorite::postgresql::PgFavoriteRepository, user::postgresql::PgUserRepository}};
-
-static DATABASE_URL: LazyLock<String> = LazyLock::new(|| {
-        dotenvy::var("DATABASE_URL")
-                .expect("DATABASE_URL var should be set")
-});
-
-static MIN_CONNECTIONS: LazyLock<u32> = LazyLock::new(|| {
-        dotenvy::var("MIN_CONNECTIONS")
-                .expect("MIN_CONNECTIONS var should be set")
-                .parse()
-                .expect("MIN_CONNECTIONS should be valid u32")
-});
-
-static MAX_CONNECTIONS: LazyLock<u32> = LazyLock::new(|| {
-        dotenvy::var("MAX_CONNECTIONS")
-                .expect("MAX_CONNECTIONS var should be set")
-                .parse()
-                .expect("MAX_CONNECTIONS should be valid u32")
-});
-
-static ACQUIRE_TIMEOUT: LazyLock<Duration> = LazyLock::new(|| {
-        dotenvy::var("ACQUIRE_TIMEOUT")
-                .expect("ACQUIRE_TIMEOUT var should be set")
-                .parse()
-                .map(Duration::from_secs)
-                .expect("ACQUIRE_TIMEOUT should be valid u64")
-});
-
-static IDLE_TIMEOUT: LazyLock<Option<Duration>> = LazyLock::new(|| {
-        dotenvy::var("IDLE_TIMEOUT")
-                .ok()
-                .map(|s|
-                        s.parse()
-                        .map(Duration::from_secs)
-                        .expect("IDLE_TIMEOUT should be valid u64")
-                )
-});
-
-static MAX_LIFETIME: LazyLock<Option<Duration>> = LazyLock::new(|| {
-        dotenvy::var("MAX_LIFETIME")
-                .ok()
-                .map(|s|
-                        s.parse()
-                        .map(Duration::from_secs)
-                        .expect("MAX_LIFETIME should be valid u64")
-                )
-});
+impl Backend {
+        pub fn from_config(config: &DatabaseConfig) -> Self {
+                if config.url.starts_with("sqlite:") {
+                        Self::Sqlite
+                } else {
+                        Self::Postgres
+                }
+        }
+}
 
 #[derive(Debug, Clone)]
 pub struct PgProvider {
@@ -57,34 +44,164 @@ pub struct PgProvider {
 }
 
 impl PgProvider {
-        pub async fn new() -> Result<Self> {
+        pub async fn new(config: &DatabaseConfig) -> Result<Self> {
                 let pool = PgPoolOptions::new()
-                        .min_connections(*MIN_CONNECTIONS)
-                        .max_connections(*MAX_CONNECTIONS)
-                        .acquire_timeout(*ACQUIRE_TIMEOUT)
-                        .idle_timeout(*IDLE_TIMEOUT)
-                        .max_lifetime(*MAX_LIFETIME)
-                        .connect(&DATABASE_URL)
+                        .min_connections(config.min_connections)
+                        .max_connections(config.max_connections)
+                        .acquire_timeout(config.acquire_timeout)
+                        .idle_timeout(config.idle_timeout)
+                        .max_lifetime(config.max_lifetime)
+                        .connect(&config.url)
                         .await?;
 
                 sqlx::migrate!().run(&pool).await?;
 
                 Ok(Self { pool })
         }
+}
+
+impl Provider for PgProvider {
+        fn provide_user_repository(&self) -> Box<dyn UserRepository> {
+                Box::new(PgUserRepository::new(self.pool.clone()))
+        }
+
+        fn provide_event_repository(&self) -> Box<dyn EventRepository> {
+                Box::new(PgEventRepository::new(self.pool.clone()))
+        }
+
+        fn provide_category_repository(&self) -> Box<dyn CategoryRepository> {
+                Box::new(PgCategoryRepository::new(self.pool.clone()))
+        }
+
+        fn provide_favorite_repository(&self) -> Box<dyn FavoriteRepository> {
+                Box::new(PgFavoriteRepository::new(self.pool.clone()))
+        }
+
+        fn provide_occurrence_repository(&self) -> Box<dyn OccurrenceRepository> {
+                Box::new(PgOccurrenceRepository::new(self.pool.clone()))
+        }
+
+        fn provide_registration_repository(&self) -> Box<dyn RegistrationRepository> {
+                Box::new(PgRegistrationRepository::new(self.pool.clone()))
+        }
+
+        fn provide_waitlist_repository(&self) -> Box<dyn WaitlistRepository> {
+                Box::new(PgWaitlistRepository::new(self.pool.clone()))
+        }
+
+        fn provide_session_repository(&self) -> Box<dyn SessionRepository> {
+                Box::new(PgSessionRepository::new(self.pool.clone()))
+        }
+}
+
+#[cfg(feature = "sqlite")]
+#[derive(Debug, Clone)]
+pub struct SqliteProvider {
+        pool: Pool<Sqlite>
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteProvider {
+        pub async fn new(config: &DatabaseConfig) -> Result<Self> {
+                let options = SqliteConnectOptions::from_str(&config.url)?
+                        .create_if_missing(true);
+
+                let pool = SqlitePoolOptions::new()
+                        .min_connections(config.min_connections)
+                        .max_connections(config.max_connections)
+                        .acquire_timeout(config.acquire_timeout)
+                        .idle_timeout(config.idle_timeout)
+                        .max_lifetime(config.max_lifetime)
+                        .connect_with(options)
+                        .await?;
+
+                sqlx::migrate!("./sqlite_migrations").run(&pool).await?;
+                // distance search needs SQLite built with math functions, see .cargo/config.toml
+                sqlx::query("SELECT ASIN(1)").execute(&pool).await?;
+
+                Ok(Self { pool })
+        }
+}
+
+#[cfg(feature = "sqlite")]
+impl Provider for SqliteProvider {
+        fn provide_user_repository(&self) -> Box<dyn UserRepository> {
+                Box::new(SqliteUserRepository::new(self.pool.clone()))
+        }
+
+        fn provide_event_repository(&self) -> Box<dyn EventRepository> {
+                Box::new(SqliteEventRepository::new(self.pool.clone()))
+        }
+
+        fn provide_category_repository(&self) -> Box<dyn CategoryRepository> {
+                Box::new(SqliteCategoryRepository::new(self.pool.clone()))
+        }
+
+        fn provide_favorite_repository(&self) -> Box<dyn FavoriteRepository> {
+                Box::new(SqliteFavoriteRepository::new(self.pool.clone()))
+        }
+
+        fn provide_occurrence_repository(&self) -> Box<dyn OccurrenceRepository> {
+                Box::new(SqliteOccurrenceRepository::new(self.pool.clone()))
+        }
+
+        fn provide_registration_repository(&self) -> Box<dyn RegistrationRepository> {
+                Box::new(SqliteRegistrationRepository::new(self.pool.clone()))
+        }
+
+        fn provide_waitlist_repository(&self) -> Box<dyn WaitlistRepository> {
+                Box::new(SqliteWaitlistRepository::new(self.pool.clone()))
+        }
+
+        fn provide_session_repository(&self) -> Box<dyn SessionRepository> {
+                Box::new(SqliteSessionRepository::new(self.pool.clone()))
+        }
+}
+
+#[cfg(feature = "memory")]
+#[derive(Debug, Clone, Default)]
+pub struct MemoryProvider {
+        store: MemoryStore
+}
+
+#[cfg(feature = "memory")]
+impl MemoryProvider {
+        pub fn new() -> Self {
+                Self { store: MemoryStore::new() }
+        }
+}
+
+#[cfg(feature = "memory")]
+impl Provider for MemoryProvider {
+        fn provide_user_repository(&self) -> Box<dyn UserRepository> {
+                Box::new(MemoryUserRepository::new(self.store.clone()))
+        }
+
+        fn provide_event_repository(&self) -> Box<dyn EventRepository> {
+                Box::new(MemoryEventRepository::new(self.store.clone()))
+        }
+
+        fn provide_category_repository(&self) -> Box<dyn CategoryRepository> {
+                Box::new(MemoryCategoryRepository::new(self.store.clone()))
+        }
+
+        fn provide_favorite_repository(&self) -> Box<dyn FavoriteRepository> {
+                Box::new(MemoryFavoriteRepository::new(self.store.clone()))
+        }
 
-        pub fn provide_user_repository(&self) -> PgUserRepository {
-                PgUserRepository::new(self.pool.clone())
+        fn provide_occurrence_repository(&self) -> Box<dyn OccurrenceRepository> {
+                Box::new(MemoryOccurrenceRepository::new(self.store.clone()))
         }
 
-        pub fn provide_event_repository(&self) -> PgEventRepository {
-                PgEventRepository::new(self.pool.clone())
+        fn provide_registration_repository(&self) -> Box<dyn RegistrationRepository> {
+                Box::new(MemoryRegistrationRepository::new(self.store.clone()))
         }
 
-        pub fn provide_favorite_repository(&self) -> PgFavoriteRepository {
-                PgFavoriteRepository::new(self.pool.clone())
+        fn provide_waitlist_repository(&self) -> Box<dyn WaitlistRepository> {
+                Box::new(MemoryWaitlistRepository::new(self.store.clone()))
         }
 
-        pub fn provide_refresh_repository(&self) -> PgRefreshRepository {
-                PgRefreshRepository::new(self.pool.clone())
+        fn provide_session_repository(&self) -> Box<dyn SessionRepository> {
+                Box::new(MemorySessionRepository::new(self.store.clone()))
         }
 }
\ No newline at end of file
@@ -1,3 +0,0 @@
-pub mod repository;
-pub mod postgresql;
-pub mod entity;
\ No newline at end of file
@@ -0,0 +1,23 @@
+use domain::models::registration::RegistrationModel;
+use sqlx::FromRow;
+
+use time::PrimitiveDateTime;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RegistrationEntity {
+        pub user_id: i64,
+        pub event_id: i64,
+        pub created_at: PrimitiveDateTime,
+        pub updated_at: PrimitiveDateTime
+}
+
+impl From<RegistrationEntity> for RegistrationModel {
+        fn from(value: RegistrationEntity) -> Self {
+                RegistrationModel {
+                        user_id: value.user_id as u64,
+                        event_id: value.event_id as u64,
+                        created_at: value.created_at,
+                        updated_at: value.updated_at
+                }
+        }
+}
@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use domain::models::{cursor::CursorValue, event::EventStatus, registration::{RegistrationFilter, RegistrationOrder}, utils::{FilterGroup, Offset, OrderOp}};
+
+use super::entity::RegistrationEntity;
+use super::repository::RegistrationRepository;
+use crate::db::{event::entity::EventEntity, memory::{FilterMatch, MemoryStore, MemoryTables, OrderValue, foreign_key_violation, matches_condition, matches_filters, now, page, sort, unique_violation}};
+use crate::Result;
+
+pub struct MemoryRegistrationRepository {
+        store: MemoryStore
+}
+
+impl MemoryRegistrationRepository {
+        pub fn new(store: MemoryStore) -> Self {
+                Self { store }
+        }
+}
+
+#[async_trait]
+impl RegistrationRepository for MemoryRegistrationRepository {
+        async fn get(&self, user_id: i64, event_id: i64) -> Result<Option<RegistrationEntity>> {
+                Ok(self.store.read().registrations.get(&(user_id, event_id)).cloned())
+        }
+
+        async fn list(&self, event_id: i64, offset: Offset, filters: &[FilterGroup<RegistrationFilter>], order_by: &[RegistrationOrder]) -> Result<Vec<RegistrationEntity>> {
+                let registrations = self.store.read().registrations.values()
+                        .filter(|registration| registration.event_id == event_id && matches_filters(*registration, filters))
+                        .cloned()
+                        .collect();
+
+                Ok(page(sort(registrations, order_by), &offset))
+        }
+
+        async fn count(&self, event_id: i64, filters: &[FilterGroup<RegistrationFilter>]) -> Result<i64> {
+                Ok(self.store.read().registrations.values()
+                        .filter(|registration| registration.event_id == event_id && matches_filters(*registration, filters))
+                        .count() as i64)
+        }
+
+        async fn list_events(&self, user_id: i64, offset: Offset) -> Result<Vec<EventEntity>> {
+                let tables = self.store.read();
+
+                let mut events: Vec<_> = tables.registrations.keys()
+                        .filter(|(id, _)| *id == user_id)
+                        .filter_map(|(_, event_id)| tables.events.get(event_id).cloned())
+                        .collect();
+                events.sort_by_key(|event| (event.date, event.id));
+
+                Ok(page(events, &offset))
+        }
+
+        async fn create(&self, user_id: i64, event_id: i64) -> Result<Option<RegistrationEntity>> {
+                let mut tables = self.store.write();
+
+                let Some(event) = tables.events.get(&event_id) else {
+                        return Ok(None);
+                };
+                if event.status != EventStatus::Approved.to_string()
+                        || event.capacity.is_some_and(|capacity| capacity as usize <= tables.registered(event_id)) {
+                        return Ok(None);
+                }
+
+                let registration = insert(&mut tables, user_id, event_id)?;
+                tables.waitlist.retain(|_, waitlist| waitlist.user_id != user_id || waitlist.event_id != event_id);
+
+                Ok(Some(registration))
+        }
+
+        async fn delete(&self, user_id: i64, event_id: i64) -> Result<Option<RegistrationEntity>> {
+                let mut tables = self.store.write();
+
+                let Some(registration) = tables.registrations.remove(&(user_id, event_id)) else {
+                        return Ok(None);
+                };
+
+                let vacant = tables.events.get(&event_id)
+                        .filter(|event| event.status == EventStatus::Approved.to_string())
+                        .is_some_and(|event| event.capacity.is_none_or(|capacity| capacity as usize > tables.registered(event_id)));
+                let promoted = tables.waitlist.iter()
+                        .find(|(_, waitlist)| waitlist.event_id == event_id)
+                        .map(|(id, waitlist)| (*id, waitlist.user_id));
+
+                if let Some((id, promoted)) = promoted.filter(|_| vacant) {
+                        tables.waitlist.remove(&id);
+                        insert(&mut tables, promoted, event_id)?;
+                }
+
+                Ok(Some(registration))
+        }
+}
+
+fn insert(tables: &mut MemoryTables, user_id: i64, event_id: i64) -> Result<RegistrationEntity> {
+        if !tables.users.contains_key(&user_id) {
+                return Err(foreign_key_violation("registration", "registration_user_id_fkey"));
+        }
+        if tables.registrations.contains_key(&(user_id, event_id)) {
+                return Err(unique_violation("registration", "registration_pkey"));
+        }
+
+        let created_at = now();
+        let registration = RegistrationEntity { user_id, event_id, created_at, updated_at: created_at };
+        tables.registrations.insert((user_id, event_id), registration.clone());
+
+        Ok(registration)
+}
+
+impl FilterMatch<RegistrationEntity> for RegistrationFilter {
+        fn matches(&self, registration: &RegistrationEntity) -> bool {
+                match self {
+                        RegistrationFilter::UserId(op) => matches_condition(&CursorValue::Int(registration.user_id), op, |value| CursorValue::Int(*value as i64))
+                }
+        }
+}
+
+impl OrderValue<RegistrationEntity> for RegistrationOrder {
+        fn order_value(&self, registration: &RegistrationEntity) -> (CursorValue, &OrderOp) {
+                match self {
+                        RegistrationOrder::UserId(op) => (CursorValue::Int(registration.user_id), op),
+                        RegistrationOrder::CreatedAt(op) => (CursorValue::Timestamp(registration.created_at), op),
+                        RegistrationOrder::UpdatedAt(op) => (CursorValue::Timestamp(registration.updated_at), op)
+                }
+        }
+}
@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use domain::models::{registration::{RegistrationFilter, RegistrationOrder}, utils::{FilterGroup, Offset}};
+
+use super::entity::RegistrationEntity;
+use crate::db::event::entity::EventEntity;
+use crate::Result;
+
+#[async_trait]
+pub trait RegistrationRepository: Send + Sync {
+        async fn get(&self, user_id: i64, event_id: i64) -> Result<Option<RegistrationEntity>>;
+        async fn list(&self, event_id: i64, offset: Offset, filters: &[FilterGroup<RegistrationFilter>], order_by: &[RegistrationOrder]) -> Result<Vec<RegistrationEntity>>;
+        async fn count(&self, event_id: i64, filters: &[FilterGroup<RegistrationFilter>]) -> Result<i64>;
+        async fn list_events(&self, user_id: i64, offset: Offset) -> Result<Vec<EventEntity>>;
+        async fn create(&self, user_id: i64, event_id: i64) -> Result<Option<RegistrationEntity>>;
+        async fn delete(&self, user_id: i64, event_id: i64) -> Result<Option<RegistrationEntity>>;
+}
+
+#[async_trait]
+impl<T: RegistrationRepository + ?Sized> RegistrationRepository for Box<T> {
+        async fn get(&self, user_id: i64, event_id: i64) -> Result<Option<RegistrationEntity>> {
+                (**self).get(user_id, event_id).await
+        }
+
+        async fn list(&self, event_id: i64, offset: Offset, filters: &[FilterGroup<RegistrationFilter>], order_by: &[RegistrationOrder]) -> Result<Vec<RegistrationEntity>> {
+                (**self).list(event_id, offset, filters, order_by).await
+        }
+
+        async fn count(&self, event_id: i64, filters: &[FilterGroup<RegistrationFilter>]) -> Result<i64> {
+                (**self).count(event_id, filters).await
+        }
+
+        async fn list_events(&self, user_id: i64, offset: Offset) -> Result<Vec<EventEntity>> {
+                (**self).list_events(user_id, offset).await
+        }
+
+        async fn create(&self, user_id: i64, event_id: i64) -> Result<Option<RegistrationEntity>> {
+                (**self).create(user_id, event_id).await
+        }
+
+        async fn delete(&self, user_id: i64, event_id: i64) -> Result<Option<RegistrationEntity>> {
+                (**self).delete(user_id, event_id).await
+        }
+}
@@ -0,0 +1,202 @@
+use async_trait::async_trait;
+use sqlx::{Pool, QueryBuilder, Sqlite, query_builder::Separated};
+use domain::models::{registration::{RegistrationFilter, RegistrationOrder}, utils::{FilterGroup, Offset}};
+
+use super::entity::RegistrationEntity;
+use crate::db::{event::{entity::EventEntity, sqlite::EventRow}, compiler::{FilterColumn, push_condition, push_filters, push_offset, push_order}};
+use super::repository::RegistrationRepository;
+use crate::Result;
+
+pub struct SqliteRegistrationRepository {
+        pool: Pool<Sqlite>
+}
+
+impl SqliteRegistrationRepository {
+        pub fn new(pool: Pool<Sqlite>) -> Self {
+                Self { pool }
+        }
+}
+
+#[async_trait]
+impl RegistrationRepository for SqliteRegistrationRepository {
+        async fn get(&self, user_id: i64, event_id: i64) -> Result<Option<RegistrationEntity>> {
+                sqlx::query_as(
+                        r#"
+                        SELECT user_id, event_id, created_at, updated_at
+                        FROM "registration"
+                        WHERE user_id = ?
+                        AND event_id = ?
+                        "#
+                )
+                .bind(user_id)
+                .bind(event_id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn list(&self, event_id: i64, offset: Offset, filters: &[FilterGroup<RegistrationFilter>], order_by: &[RegistrationOrder]) -> Result<Vec<RegistrationEntity>> {
+                let mut query_builder =
+                        QueryBuilder::<Sqlite>::new(r#"
+                        SELECT user_id, event_id, created_at, updated_at"#
+                );
+                push_source(&mut query_builder, event_id, filters);
+
+                push_order(&mut query_builder, order_by);
+
+                push_offset(&mut query_builder, &offset);
+
+                query_builder
+                        .build_query_as()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn count(&self, event_id: i64, filters: &[FilterGroup<RegistrationFilter>]) -> Result<i64> {
+                let mut query_builder = QueryBuilder::<Sqlite>::new("SELECT COUNT(*)");
+                push_source(&mut query_builder, event_id, filters);
+
+                query_builder
+                        .build_query_scalar()
+                        .fetch_one(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn list_events(&self, user_id: i64, offset: Offset) -> Result<Vec<EventEntity>> {
+                sqlx::query_as::<_, EventRow>(
+                        r#"
+                        SELECT e.id, e.organizer_id, e.title, e.description, e.date, e.cost, e.address, e.capacity, e.category,
+                        (SELECT group_concat(tag, char(31)) FROM (SELECT t.tag FROM "event_tag" t WHERE t.event_id = e.id ORDER BY t.tag)) AS tags,
+                        e.language, e.latitude, e.longitude, e.recurrence, e.status, e.created_at, e.updated_at
+                        FROM "registration" r
+                        JOIN "event" e ON r.event_id = e.id
+                        WHERE r.user_id = ?1
+                        ORDER BY e.date, e.id
+                        LIMIT ?2
+                        OFFSET ?3
+                        "#
+                )
+                .bind(user_id)
+                .bind(i64::from(offset.limit))
+                .bind(offset.skip())
+                .fetch_all(&self.pool)
+                .await
+                .map(|events| events.into_iter().map(Into::into).collect())
+                .map_err(Into::into)
+        }
+
+        async fn create(&self, user_id: i64, event_id: i64) -> Result<Option<RegistrationEntity>> {
+                let mut transaction = self.pool.begin().await?;
+
+                let registration = sqlx::query_as(
+                        r#"
+                        INSERT INTO "registration" (user_id, event_id)
+                        SELECT ?1, e.id
+                        FROM "event" e
+                        WHERE e.id = ?2
+                        AND e.status = 'Approved'
+                        AND (e.capacity IS NULL OR e.capacity > (SELECT COUNT(*) FROM "registration" r WHERE r.event_id = e.id))
+                        RETURNING user_id, event_id, created_at, updated_at
+                        "#
+                )
+                .bind(user_id)
+                .bind(event_id)
+                .fetch_optional(&mut *transaction)
+                .await?;
+
+                if registration.is_some() {
+                        sqlx::query(
+                                r#"
+                                DELETE FROM "waitlist"
+                                WHERE user_id = ?
+                                AND event_id = ?
+                                "#
+                        )
+                        .bind(user_id)
+                        .bind(event_id)
+                        .execute(&mut *transaction)
+                        .await?;
+                }
+
+                transaction.commit().await?;
+
+                Ok(registration)
+        }
+
+        async fn delete(&self, user_id: i64, event_id: i64) -> Result<Option<RegistrationEntity>> {
+                let mut transaction = self.pool.begin().await?;
+
+                let registration = sqlx::query_as(
+                        r#"
+                        DELETE FROM "registration"
+                        WHERE user_id = ?
+                        AND event_id = ?
+                        RETURNING user_id, event_id, created_at, updated_at
+                        "#
+                )
+                .bind(user_id)
+                .bind(event_id)
+                .fetch_optional(&mut *transaction)
+                .await?;
+
+                if registration.is_some() {
+                        let promoted: Option<(i64, i64)> = sqlx::query_as(
+                                r#"
+                                DELETE FROM "waitlist"
+                                WHERE id = (
+                                        SELECT w.id
+                                        FROM "waitlist" w
+                                        JOIN "event" e ON e.id = w.event_id
+                                        WHERE w.event_id = ?1
+                                        AND e.status = 'Approved'
+                                        AND (e.capacity IS NULL OR e.capacity > (SELECT COUNT(*) FROM "registration" r WHERE r.event_id = ?1))
+                                        ORDER BY w.id
+                                        LIMIT 1
+                                )
+                                RETURNING user_id, event_id
+                                "#
+                        )
+                        .bind(event_id)
+                        .fetch_optional(&mut *transaction)
+                        .await?;
+
+                        if let Some((user_id, event_id)) = promoted {
+                                sqlx::query(
+                                        r#"
+                                        INSERT INTO "registration" (user_id, event_id)
+                                        VALUES (?, ?)
+                                        "#
+                                )
+                                .bind(user_id)
+                                .bind(event_id)
+                                .execute(&mut *transaction)
+                                .await?;
+                        }
+                }
+
+                transaction.commit().await?;
+
+                Ok(registration)
+        }
+}
+
+fn push_source<'args>(query_builder: &mut QueryBuilder<'args, Sqlite>, event_id: i64, filters: &'args [FilterGroup<RegistrationFilter>]) {
+        query_builder.push(r#" FROM "registration" WHERE event_id = "#).push_bind(event_id);
+
+        if !filters.is_empty() {
+                let mut separated = query_builder.separated(" AND ");
+                separated.push_unseparated(" AND ");
+
+                push_filters(&mut separated, filters);
+        }
+}
+
+impl FilterColumn<Sqlite> for RegistrationFilter {
+        fn push_filter<'args>(&'args self, separated: &mut Separated<'_, 'args, Sqlite, &'static str>) {
+                match self {
+                        RegistrationFilter::UserId(op) => push_condition(separated, "user_id", op, |value| *value as i64)
+                }
+        }
+}
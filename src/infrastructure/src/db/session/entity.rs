@@ -0,0 +1,58 @@
+use domain::models::session::{SecurityEventModel, SessionMetadata, SessionModel};
+use sqlx::FromRow;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct SessionEntity {
+        pub id: Uuid,
+        pub user_id: i64,
+        pub device: Option<String>,
+        pub user_agent: Option<String>,
+        pub ip: Option<String>,
+        pub created_at: PrimitiveDateTime,
+        pub last_used_at: PrimitiveDateTime,
+        pub revoked_at: Option<PrimitiveDateTime>
+}
+
+impl From<SessionEntity> for SessionModel {
+        fn from(value: SessionEntity) -> Self {
+                SessionModel {
+                        id: value.id,
+                        user_id: value.user_id as u64,
+                        metadata: SessionMetadata {
+                                device: value.device,
+                                user_agent: value.user_agent,
+                                ip: value.ip
+                        },
+                        created_at: value.created_at,
+                        last_used_at: value.last_used_at,
+                        revoked_at: value.revoked_at
+                }
+        }
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct SecurityEventEntity {
+        pub id: i64,
+        pub user_id: i64,
+        pub session_id: Option<Uuid>,
+        pub kind: String,
+        pub user_agent: Option<String>,
+        pub ip: Option<String>,
+        pub created_at: PrimitiveDateTime
+}
+
+impl From<SecurityEventEntity> for SecurityEventModel {
+        fn from(value: SecurityEventEntity) -> Self {
+                SecurityEventModel {
+                        id: value.id as u64,
+                        user_id: value.user_id as u64,
+                        session_id: value.session_id,
+                        kind: value.kind.parse().unwrap(),
+                        user_agent: value.user_agent,
+                        ip: value.ip,
+                        created_at: value.created_at
+                }
+        }
+}
\ No newline at end of file
@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+use domain::models::session::{SecurityEventKind, SessionMetadata};
+use uuid::Uuid;
+
+use super::{entity::{SecurityEventEntity, SessionEntity}, repository::{SessionRepository, hash_eq}};
+use crate::db::memory::{MemoryStore, foreign_key_violation, now};
+use crate::Result;
+
+pub struct MemorySessionRepository {
+        store: MemoryStore
+}
+
+impl MemorySessionRepository {
+        pub fn new(store: MemoryStore) -> Self {
+                Self { store }
+        }
+}
+
+#[async_trait]
+impl SessionRepository for MemorySessionRepository {
+        async fn get(&self, id: Uuid) -> Result<Option<SessionEntity>> {
+                Ok(self.store.read().sessions.get(&id).map(|(_, session)| session.clone()))
+        }
+
+        async fn list(&self, user_id: i64) -> Result<Vec<SessionEntity>> {
+                let mut sessions: Vec<_> = self.store.read().sessions.values()
+                        .map(|(_, session)| session)
+                        .filter(|session| session.user_id == user_id && session.revoked_at.is_none())
+                        .cloned()
+                        .collect();
+                sessions.sort_by(|a, b| b.last_used_at.cmp(&a.last_used_at).then(b.created_at.cmp(&a.created_at)));
+
+                Ok(sessions)
+        }
+
+        async fn create(&self, id: Uuid, user_id: i64, token_hash: &str, metadata: &SessionMetadata) -> Result<SessionEntity> {
+                let mut tables = self.store.write();
+
+                if !tables.users.contains_key(&user_id) {
+                        return Err(foreign_key_violation("session", "session_user_id_fkey"));
+                }
+
+                let now = now();
+                let session = SessionEntity {
+                        id,
+                        user_id,
+                        device: metadata.device.clone(),
+                        user_agent: metadata.user_agent.clone(),
+                        ip: metadata.ip.clone(),
+                        created_at: now,
+                        last_used_at: now,
+                        revoked_at: None
+                };
+                tables.sessions.insert(id, (token_hash.to_string(), session.clone()));
+
+                Ok(session)
+        }
+
+        async fn rotate(&self, id: Uuid, old_hash: &str, token_hash: &str) -> Result<Option<SessionEntity>> {
+                let mut tables = self.store.write();
+
+                let Some((current, session)) = tables.sessions.get_mut(&id)
+                        .filter(|(current, session)| hash_eq(current, old_hash) && session.revoked_at.is_none()) else {
+                        return Ok(None);
+                };
+                *current = token_hash.to_string();
+                session.last_used_at = now();
+
+                Ok(Some(session.clone()))
+        }
+
+        async fn revoke(&self, id: Uuid, user_id: i64) -> Result<Option<SessionEntity>> {
+                let mut tables = self.store.write();
+
+                let Some((_, session)) = tables.sessions.get_mut(&id)
+                        .filter(|(_, session)| session.user_id == user_id && session.revoked_at.is_none()) else {
+                        return Ok(None);
+                };
+                session.revoked_at = Some(now());
+
+                Ok(Some(session.clone()))
+        }
+
+        async fn revoke_all(&self, user_id: i64) -> Result<u64> {
+                let now = now();
+                let mut tables = self.store.write();
+
+                let mut revoked = 0;
+                for (_, session) in tables.sessions.values_mut()
+                        .filter(|(_, session)| session.user_id == user_id && session.revoked_at.is_none()) {
+                        session.revoked_at = Some(now);
+                        revoked += 1;
+                }
+
+                Ok(revoked)
+        }
+
+        async fn revoke_reused(&self, id: Uuid, metadata: &SessionMetadata) -> Result<Option<SessionEntity>> {
+                let now = now();
+                let mut tables = self.store.write();
+
+                let Some((_, session)) = tables.sessions.get_mut(&id).filter(|(_, session)| session.revoked_at.is_none()) else {
+                        return Ok(None);
+                };
+                session.revoked_at = Some(now);
+                let session = session.clone();
+
+                let event_id = tables.next_id("security_event");
+                tables.security_events.insert(event_id, SecurityEventEntity {
+                        id: event_id,
+                        user_id: session.user_id,
+                        session_id: Some(session.id),
+                        kind: SecurityEventKind::RefreshTokenReuse.to_string(),
+                        user_agent: metadata.user_agent.clone(),
+                        ip: metadata.ip.clone(),
+                        created_at: now
+                });
+
+                Ok(Some(session))
+        }
+
+        async fn list_security_events(&self, user_id: i64) -> Result<Vec<SecurityEventEntity>> {
+                Ok(self.store.read().security_events.values()
+                        .rev()
+                        .filter(|event| event.user_id == user_id)
+                        .cloned()
+                        .collect())
+        }
+}
@@ -0,0 +1,7 @@
+pub mod repository;
+pub mod postgresql;
+pub mod entity;
+#[cfg(feature = "memory")]
+pub mod memory;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
\ No newline at end of file
@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use domain::models::session::SessionMetadata;
+use subtle::ConstantTimeEq;
+use uuid::Uuid;
+
+use crate::Result;
+use super::entity::{SecurityEventEntity, SessionEntity};
+
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+        async fn get(&self, id: Uuid) -> Result<Option<SessionEntity>>;
+        async fn list(&self, user_id: i64) -> Result<Vec<SessionEntity>>;
+        async fn create(&self, id: Uuid, user_id: i64, token_hash: &str, metadata: &SessionMetadata) -> Result<SessionEntity>;
+        async fn rotate(&self, id: Uuid, old_hash: &str, token_hash: &str) -> Result<Option<SessionEntity>>;
+        async fn revoke(&self, id: Uuid, user_id: i64) -> Result<Option<SessionEntity>>;
+        async fn revoke_all(&self, user_id: i64) -> Result<u64>;
+        async fn revoke_reused(&self, id: Uuid, metadata: &SessionMetadata) -> Result<Option<SessionEntity>>;
+        async fn list_security_events(&self, user_id: i64) -> Result<Vec<SecurityEventEntity>>;
+}
+
+#[async_trait]
+impl<T: SessionRepository + ?Sized> SessionRepository for Box<T> {
+        async fn get(&self, id: Uuid) -> Result<Option<SessionEntity>> {
+                (**self).get(id).await
+        }
+
+        async fn list(&self, user_id: i64) -> Result<Vec<SessionEntity>> {
+                (**self).list(user_id).await
+        }
+
+        async fn create(&self, id: Uuid, user_id: i64, token_hash: &str, metadata: &SessionMetadata) -> Result<SessionEntity> {
+                (**self).create(id, user_id, token_hash, metadata).await
+        }
+
+        async fn rotate(&self, id: Uuid, old_hash: &str, token_hash: &str) -> Result<Option<SessionEntity>> {
+                (**self).rotate(id, old_hash, token_hash).await
+        }
+
+        async fn revoke(&self, id: Uuid, user_id: i64) -> Result<Option<SessionEntity>> {
+                (**self).revoke(id, user_id).await
+        }
+
+        async fn revoke_all(&self, user_id: i64) -> Result<u64> {
+                (**self).revoke_all(user_id).await
+        }
+
+        async fn revoke_reused(&self, id: Uuid, metadata: &SessionMetadata) -> Result<Option<SessionEntity>> {
+                (**self).revoke_reused(id, metadata).await
+        }
+
+        async fn list_security_events(&self, user_id: i64) -> Result<Vec<SecurityEventEntity>> {
+                (**self).list_security_events(user_id).await
+        }
+}
+
+pub(crate) fn hash_eq(a: &str, b: &str) -> bool {
+        a.as_bytes().ct_eq(b.as_bytes()).into()
+}
\ No newline at end of file
@@ -0,0 +1,195 @@
+use async_trait::async_trait;
+use domain::models::session::{SecurityEventKind, SessionMetadata};
+use sqlx::{Pool, Sqlite};
+use uuid::Uuid;
+
+use super::{entity::{SecurityEventEntity, SessionEntity}, repository::{SessionRepository, hash_eq}};
+use crate::{Result, db::sqlite::NOW};
+
+pub struct SqliteSessionRepository {
+        pool: Pool<Sqlite>
+}
+
+impl SqliteSessionRepository {
+        pub fn new(pool: Pool<Sqlite>) -> Self {
+                Self { pool }
+        }
+}
+
+#[async_trait]
+impl SessionRepository for SqliteSessionRepository {
+        async fn get(&self, id: Uuid) -> Result<Option<SessionEntity>> {
+                sqlx::query_as(
+                r#"
+                        SELECT id, user_id, device, user_agent, ip, created_at, last_used_at, revoked_at
+                        FROM "session"
+                        WHERE id = ?
+                "#
+                )
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn list(&self, user_id: i64) -> Result<Vec<SessionEntity>> {
+                sqlx::query_as(
+                r#"
+                        SELECT id, user_id, device, user_agent, ip, created_at, last_used_at, revoked_at
+                        FROM "session"
+                        WHERE user_id = ?
+                        AND revoked_at IS NULL
+                        ORDER BY last_used_at DESC, created_at DESC
+                "#
+                )
+                .bind(user_id)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn create(&self, id: Uuid, user_id: i64, token_hash: &str, metadata: &SessionMetadata) -> Result<SessionEntity> {
+                sqlx::query_as(
+                r#"
+                        INSERT INTO "session" (id, user_id, token_hash, device, user_agent, ip)
+                        VALUES (?, ?, ?, ?, ?, ?)
+                        RETURNING id, user_id, device, user_agent, ip, created_at, last_used_at, revoked_at
+                "#
+                )
+                .bind(id)
+                .bind(user_id)
+                .bind(token_hash)
+                .bind(&metadata.device)
+                .bind(&metadata.user_agent)
+                .bind(&metadata.ip)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn rotate(&self, id: Uuid, old_hash: &str, token_hash: &str) -> Result<Option<SessionEntity>> {
+                let mut transaction = self.pool.begin().await?;
+
+                let current: Option<Option<String>> = sqlx::query_scalar(
+                r#"
+                        SELECT token_hash
+                        FROM "session"
+                        WHERE id = ?
+                        AND revoked_at IS NULL
+                "#
+                )
+                .bind(id)
+                .fetch_optional(&mut *transaction)
+                .await?;
+
+                if !current.flatten().is_some_and(|current| hash_eq(&current, old_hash)) {
+                        return Ok(None);
+                }
+
+                let session = sqlx::query_as(&format!(
+                r#"
+                        UPDATE "session"
+                        SET token_hash = ?, last_used_at = {NOW}
+                        WHERE id = ?
+                        AND token_hash = ?
+                        AND revoked_at IS NULL
+                        RETURNING id, user_id, device, user_agent, ip, created_at, last_used_at, revoked_at
+                "#
+                ))
+                .bind(token_hash)
+                .bind(id)
+                .bind(old_hash)
+                .fetch_optional(&mut *transaction)
+                .await?;
+
+                transaction.commit().await?;
+
+                Ok(session)
+        }
+
+        async fn revoke(&self, id: Uuid, user_id: i64) -> Result<Option<SessionEntity>> {
+                sqlx::query_as(&format!(
+                r#"
+                        UPDATE "session"
+                        SET revoked_at = {NOW}
+                        WHERE id = ?
+                        AND user_id = ?
+                        AND revoked_at IS NULL
+                        RETURNING id, user_id, device, user_agent, ip, created_at, last_used_at, revoked_at
+                "#
+                ))
+                .bind(id)
+                .bind(user_id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn revoke_all(&self, user_id: i64) -> Result<u64> {
+                sqlx::query(&format!(
+                r#"
+                        UPDATE "session"
+                        SET revoked_at = {NOW}
+                        WHERE user_id = ?
+                        AND revoked_at IS NULL
+                "#
+                ))
+                .bind(user_id)
+                .execute(&self.pool)
+                .await
+                .map(|res| res.rows_affected())
+                .map_err(Into::into)
+        }
+
+        async fn revoke_reused(&self, id: Uuid, metadata: &SessionMetadata) -> Result<Option<SessionEntity>> {
+                let mut transaction = self.pool.begin().await?;
+
+                let session: Option<SessionEntity> = sqlx::query_as(&format!(
+                r#"
+                        UPDATE "session"
+                        SET revoked_at = {NOW}
+                        WHERE id = ?
+                        AND revoked_at IS NULL
+                        RETURNING id, user_id, device, user_agent, ip, created_at, last_used_at, revoked_at
+                "#
+                ))
+                .bind(id)
+                .fetch_optional(&mut *transaction)
+                .await?;
+
+                if let Some(session) = &session {
+                        sqlx::query(
+                        r#"
+                                INSERT INTO "security_event" (user_id, session_id, kind, user_agent, ip)
+                                VALUES (?, ?, ?, ?, ?)
+                        "#
+                        )
+                        .bind(session.user_id)
+                        .bind(session.id)
+                        .bind(SecurityEventKind::RefreshTokenReuse.to_string())
+                        .bind(&metadata.user_agent)
+                        .bind(&metadata.ip)
+                        .execute(&mut *transaction)
+                        .await?;
+                }
+
+                transaction.commit().await?;
+
+                Ok(session)
+        }
+
+        async fn list_security_events(&self, user_id: i64) -> Result<Vec<SecurityEventEntity>> {
+                sqlx::query_as(
+                r#"
+                        SELECT id, user_id, session_id, kind, user_agent, ip, created_at
+                        FROM "security_event"
+                        WHERE user_id = ?
+                        ORDER BY id DESC
+                "#
+                )
+                .bind(user_id)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+}
@@ -0,0 +1,18 @@
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset, format_description::BorrowedFormatItem, macros::format_description};
+
+pub(crate) const NOW: &str = "strftime('%Y-%m-%d %H:%M:%f', 'now')";
+
+const TIMESTAMP: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond digits:3]");
+const TIMESTAMPTZ: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:6]Z");
+
+pub(crate) fn timestamp(value: &PrimitiveDateTime) -> String {
+        value.format(TIMESTAMP).expect("timestamp should be formattable")
+}
+
+pub(crate) fn timestamptz(value: &OffsetDateTime) -> String {
+        value.to_offset(UtcOffset::UTC).format(TIMESTAMPTZ).expect("timestamp should be formattable")
+}
+
+pub(crate) fn split_tags(tags: Option<String>) -> Vec<String> {
+        tags.map(|tags| tags.split('\x1f').map(ToString::to_string).collect()).unwrap_or_default()
+}
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use domain::models::{cursor::CursorValue, user::{UserFilter, UserOrder, UserRole, UserUpdate}, utils::{FilterGroup, Offset, OrderOp}};
+
+use super::repository::UserRepository;
+use super::entity::UserEntity;
+use crate::db::memory::{FilterMatch, MemoryStore, OrderValue, is_after, matches_condition, matches_filters, now, page, sort, unique_violation};
+use crate::Result;
+
+pub struct MemoryUserRepository {
+        store: MemoryStore
+}
+
+impl MemoryUserRepository {
+        pub fn new(store: MemoryStore) -> Self {
+                Self { store }
+        }
+}
+
+#[async_trait]
+impl UserRepository for MemoryUserRepository {
+        async fn get(&self, id: i64) -> Result<Option<UserEntity>> {
+                Ok(self.store.read().users.get(&id).cloned())
+        }
+
+        async fn list(&self, offset: Offset, filters: &[FilterGroup<UserFilter>], order_by: &[UserOrder]) -> Result<Vec<UserEntity>> {
+                let users = self.store.read().users.values()
+                        .filter(|user| matches_filters(*user, filters))
+                        .cloned()
+                        .collect();
+
+                Ok(page(sort(users, order_by), &offset))
+        }
+
+        async fn list_after(&self, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<UserFilter>], order_by: &[UserOrder]) -> Result<Vec<UserEntity>> {
+                let users = self.store.read().users.values()
+                        .filter(|user| matches_filters(*user, filters))
+                        .cloned()
+                        .collect();
+
+                Ok(sort(users, order_by)
+                        .into_iter()
+                        .filter(|user| after.is_none_or(|values| is_after(user, order_by, values)))
+                        .take(limit as usize)
+                        .collect())
+        }
+
+        async fn count(&self, filters: &[FilterGroup<UserFilter>]) -> Result<i64> {
+                Ok(self.store.read().users.values().filter(|user| matches_filters(*user, filters)).count() as i64)
+        }
+
+        async fn create(&self, login: &str, password_hash: &str) -> Result<UserEntity> {
+                let mut tables = self.store.write();
+
+                if tables.users.values().any(|user| user.login == login) {
+                        return Err(unique_violation("user", "user_login_key"));
+                }
+
+                let created_at = now();
+                let user = UserEntity {
+                        id: tables.next_id("user"),
+                        login: login.to_string(),
+                        password_hash: password_hash.to_string(),
+                        role: UserRole::User.to_string(),
+                        created_at,
+                        updated_at: created_at
+                };
+                tables.users.insert(user.id, user.clone());
+
+                Ok(user)
+        }
+
+        async fn update(&self, id: i64, changes: UserUpdate) -> Result<Option<UserEntity>> {
+                let mut tables = self.store.write();
+
+                if let UserUpdate::Login(login) = &changes
+                        && tables.users.values().any(|user| user.id != id && user.login == *login)
+                {
+                        return Err(unique_violation("user", "user_login_key"));
+                }
+
+                let Some(user) = tables.users.get_mut(&id) else {
+                        return Ok(None);
+                };
+
+                match changes {
+                        UserUpdate::Login(login) => user.login = login,
+                        UserUpdate::Password(password) => user.password_hash = password,
+                        UserUpdate::Role(role) => user.role = role.to_string()
+                }
+                user.updated_at = now();
+
+                Ok(Some(user.clone()))
+        }
+
+        async fn delete(&self, id: i64) -> Result<Option<UserEntity>> {
+                Ok(self.store.write().delete_user(id))
+        }
+
+        async fn get_by_login(&self, login: &str) -> Result<Option<UserEntity>> {
+                Ok(self.store.read().users.values().find(|user| user.login == login).cloned())
+        }
+}
+
+impl FilterMatch<UserEntity> for UserFilter {
+        fn matches(&self, user: &UserEntity) -> bool {
+                match self {
+                        UserFilter::Login(op) => matches_condition(&CursorValue::Text(user.login.clone()), op, |value| CursorValue::Text(value.clone())),
+                        UserFilter::Role(op) => matches_condition(&CursorValue::Text(user.role.clone()), op, |value| CursorValue::Text(value.to_string()))
+                }
+        }
+}
+
+impl OrderValue<UserEntity> for UserOrder {
+        fn order_value(&self, user: &UserEntity) -> (CursorValue, &OrderOp) {
+                match self {
+                        UserOrder::Id(op) => (CursorValue::Int(user.id), op),
+                        UserOrder::Role(op) => (CursorValue::Text(user.role.clone()), op),
+                        UserOrder::CreatedAt(op) => (CursorValue::Timestamp(user.created_at), op),
+                        UserOrder::UpdatedAt(op) => (CursorValue::Timestamp(user.updated_at), op)
+                }
+        }
+}
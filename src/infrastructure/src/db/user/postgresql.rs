@@ -1,10 +1,11 @@
 use async_trait::async_trait;
-use sqlx::{Pool, Postgres, query_builder::QueryBuilder};
+use sqlx::{Pool, Postgres, query_builder::{QueryBuilder, Separated}};
 
-use domain::models::{user::{UserUpdate, UserFilter, UserOrder}, utils::Offset};
+use domain::models::{cursor::CursorValue, user::{UserUpdate, UserFilter, UserOrder}, utils::{FilterGroup, Offset}};
 
 use super::repository::UserRepository;
 use super::entity::UserEntity;
+use crate::db::{compiler::{FilterColumn, OrderColumn, push_condition, push_filters, push_limit, push_offset, push_order}, keyset::push_keyset};
 use crate::Result;
 
 pub struct PgUserRepository {
@@ -33,40 +34,31 @@ impl UserRepository for PgUserRepository {
                 .map_err(Into::into)
         }
 
-        async fn list(&self, offset: Offset, filters: &[UserFilter], order_by: &[UserOrder]) -> Result<Vec<UserEntity>> {
+        async fn list(&self, offset: Offset, filters: &[FilterGroup<UserFilter>], order_by: &[UserOrder]) -> Result<Vec<UserEntity>> {
                 let mut query_builder =
                         QueryBuilder::<Postgres>::new(r#"
-                        SELECT id, login, password_hash, role, created_at, updated_at FROM "user"
-                        "#);
-
-                if !filters.is_empty() {
-                        let mut separated = query_builder.separated(" AND ");
-                        separated.push_unseparated(" WHERE ");
-
-                        for filter in filters {
-                                match filter {
-                                        UserFilter::Login(op) => separated.push("login ").push_unseparated(op.operation() + " ").push_bind_unseparated(op.value()),
-                                        UserFilter::Role(op) => separated.push("role ").push_unseparated(op.operation() + " ").push_bind_unseparated(op.value().to_string()),
-                                };
-                        }
-                }
+                        SELECT id, login, password_hash, role, created_at, updated_at"#);
+                push_source(&mut query_builder, filters, None);
+                push_order(&mut query_builder, order_by);
 
-                if !order_by.is_empty() {
-                        let mut separated = query_builder.separated(", ");
-                        separated.push_unseparated(" ORDER BY ");
-
-                        for order in order_by {
-                                match order {
-                                        UserOrder::Id(op) => separated.push("id ").push_unseparated(op.to_string()),
-                                        UserOrder::Role(op) => separated.push("role ").push_unseparated(op.to_string()),
-                                        UserOrder::CreatedAt(op) => separated.push("created_at ").push_unseparated(op.to_string()),
-                                        UserOrder::UpdatedAt(op) => separated.push("updated_at ").push_unseparated(op.to_string()),
-                                };
-                        }
-                }
+                push_offset(&mut query_builder, &offset);
+
+                query_builder
+                        .build_query_as()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn list_after(&self, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<UserFilter>], order_by: &[UserOrder]) -> Result<Vec<UserEntity>> {
+                let mut query_builder =
+                        QueryBuilder::<Postgres>::new(r#"
+                        SELECT id, login, password_hash, role, created_at, updated_at"#
+                );
+                push_source(&mut query_builder, filters, after.map(|after| (order_by, after)));
+                push_order(&mut query_builder, order_by);
 
-                query_builder.push(" LIMIT ").push_bind(offset.limit as i32);
-                query_builder.push(" OFFSET (").push_bind(offset.limit as i32).push(" * (").push_bind(offset.page as i32).push(" - 1))");
+                push_limit(&mut query_builder, limit);
 
                 query_builder
                         .build_query_as()
@@ -75,6 +67,17 @@ impl UserRepository for PgUserRepository {
                         .map_err(Into::into)
         }
 
+        async fn count(&self, filters: &[FilterGroup<UserFilter>]) -> Result<i64> {
+                let mut query_builder = QueryBuilder::<Postgres>::new("SELECT COUNT(*)");
+                push_source(&mut query_builder, filters, None);
+
+                query_builder
+                        .build_query_scalar()
+                        .fetch_one(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
         async fn create(&self, login: &str, password_hash: &str) -> Result<UserEntity> {
                 sqlx::query_as(
                         r#"
@@ -139,3 +142,29 @@ impl UserRepository for PgUserRepository {
                 .map_err(Into::into)
         }
 }
+
+fn push_source<'args>(query_builder: &mut QueryBuilder<'args, Postgres>, filters: &'args [FilterGroup<UserFilter>], after: Option<(&[UserOrder], &'args [CursorValue])>) {
+        query_builder.push(r#" FROM "user""#);
+
+        if !filters.is_empty() || after.is_some() {
+                let mut separated = query_builder.separated(" AND ");
+                separated.push_unseparated(" WHERE ");
+
+                push_filters(&mut separated, filters);
+
+                if let Some((order_by, values)) = after {
+                        push_keyset(&mut separated, order_by, values);
+                }
+        }
+}
+
+impl FilterColumn<Postgres> for UserFilter {
+        fn push_filter<'args>(&'args self, separated: &mut Separated<'_, 'args, Postgres, &'static str>) {
+                match self {
+                        UserFilter::Login(op) => push_condition(separated, "login", op, String::as_str),
+                        UserFilter::Role(op) => push_condition(separated, "role", op, ToString::to_string)
+                }
+        }
+}
+
+impl OrderColumn for UserOrder {}
@@ -1,16 +1,53 @@
 use async_trait::async_trait;
-use domain::models::{user::{UserUpdate, UserFilter, UserOrder}, utils::Offset};
+use domain::models::{cursor::CursorValue, user::{UserUpdate, UserFilter, UserOrder}, utils::{FilterGroup, Offset}};
 
 use super::entity::UserEntity;
 use crate::Result;
 
 #[async_trait]
-pub trait UserRepository {
+pub trait UserRepository: Send + Sync {
         async fn get(&self, id: i64) -> Result<Option<UserEntity>>;
-        async fn list(&self, offset: Offset, filters: &[UserFilter], order_by: &[UserOrder]) -> Result<Vec<UserEntity>>;
+        async fn list(&self, offset: Offset, filters: &[FilterGroup<UserFilter>], order_by: &[UserOrder]) -> Result<Vec<UserEntity>>;
+        async fn list_after(&self, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<UserFilter>], order_by: &[UserOrder]) -> Result<Vec<UserEntity>>;
+        async fn count(&self, filters: &[FilterGroup<UserFilter>]) -> Result<i64>;
         async fn create(&self, login: &str, password_hash: &str) -> Result<UserEntity>;
         async fn update(&self, id: i64, changes: UserUpdate) -> Result<Option<UserEntity>>;
         async fn delete(&self, id: i64) -> Result<Option<UserEntity>>;
 
         async fn get_by_login(&self, login: &str) -> Result<Option<UserEntity>>;
+}
+
+#[async_trait]
+impl<T: UserRepository + ?Sized> UserRepository for Box<T> {
+        async fn get(&self, id: i64) -> Result<Option<UserEntity>> {
+                (**self).get(id).await
+        }
+
+        async fn list(&self, offset: Offset, filters: &[FilterGroup<UserFilter>], order_by: &[UserOrder]) -> Result<Vec<UserEntity>> {
+                (**self).list(offset, filters, order_by).await
+        }
+
+        async fn list_after(&self, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<UserFilter>], order_by: &[UserOrder]) -> Result<Vec<UserEntity>> {
+                (**self).list_after(limit, after, filters, order_by).await
+        }
+
+        async fn count(&self, filters: &[FilterGroup<UserFilter>]) -> Result<i64> {
+                (**self).count(filters).await
+        }
+
+        async fn create(&self, login: &str, password_hash: &str) -> Result<UserEntity> {
+                (**self).create(login, password_hash).await
+        }
+
+        async fn update(&self, id: i64, changes: UserUpdate) -> Result<Option<UserEntity>> {
+                (**self).update(id, changes).await
+        }
+
+        async fn delete(&self, id: i64) -> Result<Option<UserEntity>> {
+                (**self).delete(id).await
+        }
+
+        async fn get_by_login(&self, login: &str) -> Result<Option<UserEntity>> {
+                (**self).get_by_login(login).await
+        }
 }
\ No newline at end of file
@@ -0,0 +1,169 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite, query_builder::{QueryBuilder, Separated}};
+
+use domain::models::{cursor::CursorValue, user::{UserUpdate, UserFilter, UserOrder}, utils::{FilterGroup, Offset}};
+
+use super::repository::UserRepository;
+use super::entity::UserEntity;
+use crate::db::{compiler::{FilterColumn, push_condition, push_filters, push_limit, push_offset, push_order}, keyset::push_keyset, sqlite::NOW};
+use crate::Result;
+
+pub struct SqliteUserRepository {
+        pool: Pool<Sqlite>
+}
+
+impl SqliteUserRepository {
+        pub fn new(pool: Pool<Sqlite>) -> Self {
+                Self { pool }
+        }
+}
+
+#[async_trait]
+impl UserRepository for SqliteUserRepository {
+        async fn get(&self, id: i64) -> Result<Option<UserEntity>> {
+                sqlx::query_as(
+                        r#"
+                        SELECT id, login, password_hash, role, created_at, updated_at
+                        FROM "user"
+                        WHERE id = ?
+                        "#
+                )
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn list(&self, offset: Offset, filters: &[FilterGroup<UserFilter>], order_by: &[UserOrder]) -> Result<Vec<UserEntity>> {
+                let mut query_builder =
+                        QueryBuilder::<Sqlite>::new(r#"
+                        SELECT id, login, password_hash, role, created_at, updated_at"#);
+                push_source(&mut query_builder, filters, None);
+                push_order(&mut query_builder, order_by);
+
+                push_offset(&mut query_builder, &offset);
+
+                query_builder
+                        .build_query_as()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn list_after(&self, limit: u32, after: Option<&[CursorValue]>, filters: &[FilterGroup<UserFilter>], order_by: &[UserOrder]) -> Result<Vec<UserEntity>> {
+                let mut query_builder =
+                        QueryBuilder::<Sqlite>::new(r#"
+                        SELECT id, login, password_hash, role, created_at, updated_at"#
+                );
+                push_source(&mut query_builder, filters, after.map(|after| (order_by, after)));
+                push_order(&mut query_builder, order_by);
+
+                push_limit(&mut query_builder, limit);
+
+                query_builder
+                        .build_query_as()
+                        .fetch_all(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn count(&self, filters: &[FilterGroup<UserFilter>]) -> Result<i64> {
+                let mut query_builder = QueryBuilder::<Sqlite>::new("SELECT COUNT(*)");
+                push_source(&mut query_builder, filters, None);
+
+                query_builder
+                        .build_query_scalar()
+                        .fetch_one(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn create(&self, login: &str, password_hash: &str) -> Result<UserEntity> {
+                sqlx::query_as(
+                        r#"
+                        INSERT INTO "user" (login, password_hash)
+                        VALUES (?, ?)
+                        RETURNING id, login, password_hash, role, created_at, updated_at
+                        "#
+                )
+                .bind(login)
+                .bind(password_hash)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn update(&self, id: i64, changes: UserUpdate) -> Result<Option<UserEntity>> {
+                let mut query_builder =
+                        QueryBuilder::<Sqlite>::new(r#"UPDATE "user" SET "#);
+
+                match changes {
+                        UserUpdate::Login(login) => query_builder.push("login = ").push_bind(login),
+                        UserUpdate::Password(password) => query_builder.push("password_hash = ").push_bind(password),
+                        UserUpdate::Role(role) => query_builder.push("role = ").push_bind(role.to_string())
+                };
+
+                query_builder.push(", updated_at = ").push(NOW).push(' ');
+                query_builder.push("WHERE id = ").push_bind(id).push(' ');
+                query_builder.push(
+                        r#"RETURNING id, login, password_hash, role, created_at, updated_at"#
+                );
+                query_builder
+                        .build_query_as()
+                        .fetch_optional(&self.pool)
+                        .await
+                        .map_err(Into::into)
+        }
+
+        async fn delete(&self, id: i64) -> Result<Option<UserEntity>> {
+                sqlx::query_as(
+                        r#"
+                        DELETE FROM "user"
+                        WHERE id = ?
+                        RETURNING id, login, password_hash, role, created_at, updated_at
+                        "#
+                )
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn get_by_login(&self, login: &str) -> Result<Option<UserEntity>> {
+                sqlx::query_as(
+                        r#"
+                        SELECT id, login, password_hash, role, created_at, updated_at
+                        FROM "user"
+                        WHERE login = ?
+                        "#
+                )
+                .bind(login)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+}
+
+fn push_source<'args>(query_builder: &mut QueryBuilder<'args, Sqlite>, filters: &'args [FilterGroup<UserFilter>], after: Option<(&[UserOrder], &'args [CursorValue])>) {
+        query_builder.push(r#" FROM "user""#);
+
+        if !filters.is_empty() || after.is_some() {
+                let mut separated = query_builder.separated(" AND ");
+                separated.push_unseparated(" WHERE ");
+
+                push_filters(&mut separated, filters);
+
+                if let Some((order_by, values)) = after {
+                        push_keyset(&mut separated, order_by, values);
+                }
+        }
+}
+
+impl FilterColumn<Sqlite> for UserFilter {
+        fn push_filter<'args>(&'args self, separated: &mut Separated<'_, 'args, Sqlite, &'static str>) {
+                match self {
+                        UserFilter::Login(op) => push_condition(separated, "login", op, String::as_str),
+                        UserFilter::Role(op) => push_condition(separated, "role", op, ToString::to_string)
+                }
+        }
+}
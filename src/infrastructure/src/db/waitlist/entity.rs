@@ -0,0 +1,25 @@
+use domain::models::waitlist::WaitlistModel;
+use sqlx::FromRow;
+
+use time::PrimitiveDateTime;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct WaitlistEntity {
+        pub user_id: i64,
+        pub event_id: i64,
+        pub position: i64,
+        pub created_at: PrimitiveDateTime,
+        pub updated_at: PrimitiveDateTime
+}
+
+impl From<WaitlistEntity> for WaitlistModel {
+        fn from(value: WaitlistEntity) -> Self {
+                WaitlistModel {
+                        user_id: value.user_id as u64,
+                        event_id: value.event_id as u64,
+                        position: value.position as u64,
+                        created_at: value.created_at,
+                        updated_at: value.updated_at
+                }
+        }
+}
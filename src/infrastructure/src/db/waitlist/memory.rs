@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use domain::models::{event::EventStatus, utils::Offset};
+
+use super::entity::WaitlistEntity;
+use super::repository::WaitlistRepository;
+use crate::db::memory::{MemoryStore, MemoryTables, foreign_key_violation, now, page, unique_violation};
+use crate::Result;
+
+pub struct MemoryWaitlistRepository {
+        store: MemoryStore
+}
+
+impl MemoryWaitlistRepository {
+        pub fn new(store: MemoryStore) -> Self {
+                Self { store }
+        }
+}
+
+#[async_trait]
+impl WaitlistRepository for MemoryWaitlistRepository {
+        async fn get(&self, user_id: i64, event_id: i64) -> Result<Option<WaitlistEntity>> {
+                Ok(position(&self.store.read(), user_id, event_id))
+        }
+
+        async fn list(&self, event_id: i64, offset: Offset) -> Result<Vec<WaitlistEntity>> {
+                let queue = self.store.read().queue(event_id)
+                        .map(|(position, waitlist)| WaitlistEntity { position, ..waitlist.clone() })
+                        .collect();
+
+                Ok(page(queue, &offset))
+        }
+
+        async fn count(&self, event_id: i64) -> Result<i64> {
+                Ok(self.store.read().queue(event_id).count() as i64)
+        }
+
+        async fn create(&self, user_id: i64, event_id: i64) -> Result<Option<WaitlistEntity>> {
+                let mut tables = self.store.write();
+
+                let full = tables.events.get(&event_id)
+                        .filter(|event| event.status == EventStatus::Approved.to_string())
+                        .is_some_and(|event| event.capacity.is_some_and(|capacity| capacity as usize <= tables.registered(event_id)));
+                if !full || tables.registrations.contains_key(&(user_id, event_id)) {
+                        return Ok(None);
+                }
+
+                if !tables.users.contains_key(&user_id) {
+                        return Err(foreign_key_violation("waitlist", "waitlist_user_id_fkey"));
+                }
+                if tables.waitlist.values().any(|waitlist| waitlist.user_id == user_id && waitlist.event_id == event_id) {
+                        return Err(unique_violation("waitlist", "waitlist_pkey"));
+                }
+
+                let id = tables.next_id("waitlist");
+                let created_at = now();
+                tables.waitlist.insert(id, WaitlistEntity { user_id, event_id, position: 0, created_at, updated_at: created_at });
+
+                Ok(position(&tables, user_id, event_id))
+        }
+
+        async fn delete(&self, user_id: i64, event_id: i64) -> Result<Option<WaitlistEntity>> {
+                let mut tables = self.store.write();
+
+                let waitlist = position(&tables, user_id, event_id);
+                tables.waitlist.retain(|_, waitlist| waitlist.user_id != user_id || waitlist.event_id != event_id);
+
+                Ok(waitlist)
+        }
+}
+
+fn position(tables: &MemoryTables, user_id: i64, event_id: i64) -> Option<WaitlistEntity> {
+        tables.queue(event_id)
+                .find(|(_, waitlist)| waitlist.user_id == user_id)
+                .map(|(position, waitlist)| WaitlistEntity { position, ..waitlist.clone() })
+}
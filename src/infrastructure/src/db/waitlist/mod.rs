@@ -0,0 +1,7 @@
+pub mod postgresql;
+pub mod repository;
+pub mod entity;
+#[cfg(feature = "memory")]
+pub mod memory;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
\ No newline at end of file
@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use domain::models::utils::Offset;
+
+use super::entity::WaitlistEntity;
+use super::repository::WaitlistRepository;
+use crate::Result;
+
+pub struct PgWaitlistRepository {
+        pool: Pool<Postgres>
+}
+
+impl PgWaitlistRepository {
+        pub fn new(pool: Pool<Postgres>) -> Self {
+                Self { pool }
+        }
+}
+
+#[async_trait]
+impl WaitlistRepository for PgWaitlistRepository {
+        async fn get(&self, user_id: i64, event_id: i64) -> Result<Option<WaitlistEntity>> {
+                sqlx::query_as(
+                        r#"
+                        SELECT user_id, event_id, position, created_at, updated_at
+                        FROM (
+                                SELECT user_id, event_id, ROW_NUMBER() OVER (ORDER BY id) AS position, created_at, updated_at
+                                FROM "waitlist"
+                                WHERE event_id = $2
+                        ) w
+                        WHERE user_id = $1
+                        "#
+                )
+                .bind(user_id)
+                .bind(event_id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn list(&self, event_id: i64, offset: Offset) -> Result<Vec<WaitlistEntity>> {
+                sqlx::query_as(
+                        r#"
+                        SELECT user_id, event_id, ROW_NUMBER() OVER (ORDER BY id) AS position, created_at, updated_at
+                        FROM "waitlist"
+                        WHERE event_id = $1
+                        ORDER BY position
+                        LIMIT $2
+                        OFFSET $3
+                        "#
+                )
+                .bind(event_id)
+                .bind(i64::from(offset.limit))
+                .bind(offset.skip())
+                .fetch_all(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn count(&self, event_id: i64) -> Result<i64> {
+                sqlx::query_scalar(
+                        r#"
+                        SELECT COUNT(*) FROM "waitlist" WHERE event_id = $1
+                        "#
+                )
+                .bind(event_id)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+
+        async fn create(&self, user_id: i64, event_id: i64) -> Result<Option<WaitlistEntity>> {
+                let mut transaction = self.pool.begin().await?;
+
+                sqlx::query(
+                        r#"
+                        SELECT id
+                        FROM "event"
+                        WHERE id = $1
+                        FOR UPDATE
+                        "#
+                )
+                .bind(event_id)
+                .execute(&mut *transaction)
+                .await?;
+
+                let inserted: Option<i64> = sqlx::query_scalar(
+                        r#"
+                        INSERT INTO "waitlist" (user_id, event_id)
+                        SELECT $1, e.id
+                        FROM "event" e
+                        WHERE e.id = $2
+                        AND e.status = 'Approved'
+                        AND e.capacity <= (SELECT COUNT(*) FROM "registration" r WHERE r.event_id = e.id)
+                        AND NOT EXISTS (
+                                SELECT 1
+                                FROM "registration"
+                                WHERE user_id = $1
+                                AND event_id = $2
+                        )
+                        RETURNING id
+                        "#
+                )
+                .bind(user_id)
+                .bind(event_id)
+                .fetch_optional(&mut *transaction)
+                .await?;
+
+                if inserted.is_none() {
+                        return Ok(None);
+                }
+
+                let waitlist = sqlx::query_as(
+                        r#"
+                        SELECT user_id, event_id, position, created_at, updated_at
+                        FROM (
+                                SELECT user_id, event_id, ROW_NUMBER() OVER (ORDER BY id) AS position, created_at, updated_at
+                                FROM "waitlist"
+                                WHERE event_id = $2
+                        ) w
+                        WHERE user_id = $1
+                        "#
+                )
+                .bind(user_id)
+                .bind(event_id)
+                .fetch_optional(&mut *transaction)
+                .await?;
+
+                transaction.commit().await?;
+
+                Ok(waitlist)
+        }
+
+        async fn delete(&self, user_id: i64, event_id: i64) -> Result<Option<WaitlistEntity>> {
+                sqlx::query_as(
+                        r#"
+                        WITH queue AS (
+                                SELECT id, user_id, event_id, ROW_NUMBER() OVER (ORDER BY id) AS position
+                                FROM "waitlist"
+                                WHERE event_id = $2
+                        )
+                        DELETE FROM "waitlist" w
+                        USING queue q
+                        WHERE w.id = q.id
+                        AND q.user_id = $1
+                        RETURNING w.user_id, w.event_id, q.position, w.created_at, w.updated_at
+                        "#
+                )
+                .bind(user_id)
+                .bind(event_id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(Into::into)
+        }
+}
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use domain::models::utils::Offset;
+
+use super::entity::WaitlistEntity;
+use crate::Result;
+
+#[async_trait]
+pub trait WaitlistRepository: Send + Sync {
+        async fn get(&self, user_id: i64, event_id: i64) -> Result<Option<WaitlistEntity>>;
+        async fn list(&self, event_id: i64, offset: Offset) -> Result<Vec<WaitlistEntity>>;
+        async fn count(&self, event_id: i64) -> Result<i64>;
+        async fn create(&self, user_id: i64, event_id: i64) -> Result<Option<WaitlistEntity>>;
+        async fn delete(&self, user_id: i64, event_id: i64) -> Result<Option<WaitlistEntity>>;
+}
+
+#[async_trait]
+impl<T: WaitlistRepository + ?Sized> WaitlistRepository for Box<T> {
+        async fn get(&self, user_id: i64, event_id: i64) -> Result<Option<WaitlistEntity>> {
+                (**self).get(user_id, event_id).await
+        }
+
+        async fn list(&self, event_id: i64, offset: Offset) -> Result<Vec<WaitlistEntity>> {
+                (**self).list(event_id, offset).await
+        }
+
+        async fn count(&self, event_id: i64) -> Result<i64> {
+                (**self).count(event_id).await
+        }
+
+        async fn create(&self, user_id: i64, event_id: i64) -> Result<Option<WaitlistEntity>> {
+                (**self).create(user_id, event_id).await
+        }
+
+        async fn delete(&self, user_id: i64, event_id: i64) -> Result<Option<WaitlistEntity>> {
+                (**self).delete(user_id, event_id).await
+        }
+}
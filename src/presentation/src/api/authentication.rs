@@ -1,26 +1,43 @@
 use std::future::{Ready, ready};
 
-use actix_web::{FromRequest, HttpMessage, dev::ServiceRequest};
+use actix_web::{FromRequest, HttpMessage, dev::ServiceRequest, web::Data};
 use actix_web_grants::authorities::AttachAuthorities;
 use actix_web_httpauth::extractors::bearer::BearerAuth;
+use di::container::DiContainer;
 use domain::models::token::Claims;
+use use_case::error::ServiceError;
+
+use super::error::{HandlerError, unauthorized};
 
 pub async fn validator(
         req: ServiceRequest,
-        jwt: BearerAuth
+        jwt: Option<BearerAuth>
 ) -> Result<ServiceRequest, (actix_web::Error, ServiceRequest)> {
+        let Some(jwt) = jwt else {
+                return Err((unauthorized("missing_token", "Missing bearer token"), req))
+        };
         let claims = Claims::decode_from(jwt.token());
         match claims {
                 Ok(claims) => {
                         if !claims.is_access() {
-                                return Err((actix_web::error::ErrorUnauthorized("Use of refresh token"), req))
+                                return Err((unauthorized("invalid_token", "Use of refresh token"), req))
+                        }
+                        let Some(session_id) = claims.sid else {
+                                return Err((unauthorized("invalid_token", "Missing session"), req))
+                        };
+                        let container = req.app_data::<Data<DiContainer>>().cloned()
+                                .expect("DiContainer should be registered as app data");
+                        match container.create_session_service().is_active(session_id).await {
+                                Ok(true) => {},
+                                Ok(false) => return Err((unauthorized("revoked_token", "Session has been revoked"), req)),
+                                Err(err) => return Err((HandlerError::from(err).into(), req))
                         }
                         req.extensions_mut().insert(claims.clone());
                         req.attach([claims.role]);
                         Ok(req)
                 }
-                Err(_) => {
-                        Err((actix_web::error::ErrorUnauthorized("Could not parse token"), req))
+                Err(err) => {
+                        Err((HandlerError::from(ServiceError::from(err)).into(), req))
                 }
         }
 }
@@ -40,7 +57,7 @@ impl FromRequest for ClaimsExtractor {
         fn from_request(req: &actix_web::HttpRequest, _: &mut actix_web::dev::Payload) -> Self::Future {
                 match req.extensions().get::<Claims>() {
                         Some(claims) => ready(Ok(Self(claims.clone()))),
-                        None => ready(Err(actix_web::error::ErrorUnauthorized("Missing claims")))
+                        None => ready(Err(unauthorized("missing_claims", "Missing claims")))
                 }
         }
 }
\ No newline at end of file
@@ -0,0 +1,121 @@
+use actix_web::{HttpResponse, get, post, web::{Data, Path, Query}};
+use di::container::DiContainer;
+use domain::models::{event::{EventId, EventModel}, user::UserId, utils::Offset};
+use use_case::error::ServiceError;
+use utoipa_actix_web::service_config::ServiceConfig;
+
+use crate::api::{HandlerError, authentication::ClaimsExtractor, event::types::EventIdParam, user::types::UserIdParam};
+
+use super::{ics::render_calendar, types::{CalendarQuery, CalendarTokenResponse}};
+
+use super::super::error::{BadRequest, Forbidden, InternalServerError, NotFound, Result, Unauthorized};
+
+const CALENDAR_CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+const CALENDAR_FEED_PAGE: u32 = 500;
+
+pub fn event_calendar_app_config(cfg: &mut ServiceConfig) {
+        cfg
+        .service(get_event_calendar);
+}
+
+pub fn user_calendar_app_config(cfg: &mut ServiceConfig) {
+        cfg
+        .service(get_user_calendar);
+}
+
+pub fn calendar_token_app_config(cfg: &mut ServiceConfig) {
+        cfg
+        .service(create_calendar_token);
+}
+
+#[utoipa::path(params(EventIdParam), responses((status = 400, response = BadRequest), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
+#[get("/{event_id}.ics")]
+async fn get_event_calendar(container: Data<DiContainer>, path: Path<EventIdParam>) -> Result<HttpResponse> {
+        let event_id = path.into_inner().try_into()?;
+        let event_service = container.create_event_service();
+
+        let occurrence_service = container.create_occurrence_service();
+
+        let event = event_service.get(event_id).await?;
+        let overrides = occurrence_service.list_overrides(&[event.id]).await?;
+
+        let response_body = render_calendar(&event.title.clone(), &[event], &overrides);
+        let response = HttpResponse::Ok().content_type(CALENDAR_CONTENT_TYPE).body(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(UserIdParam, CalendarQuery), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
+#[get("/{user_id}/calendar.ics")]
+async fn get_user_calendar(container: Data<DiContainer>, path: Path<UserIdParam>, query: Query<CalendarQuery>) -> Result<HttpResponse> {
+        let user_id: UserId = path.into_inner().try_into()?;
+        let session_service = container.create_session_service();
+        let user_service = container.create_user_service();
+
+        let claims = session_service.verify_calendar(&query.into_inner().token).await?;
+
+        if claims.sub != user_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let user = user_service.get(user_id).await?;
+
+        if user.role != claims.role {
+                return Err(ServiceError::Revoked(claims.jti.to_string()).into());
+        }
+
+        let favorite_service = container.create_favorite_service();
+        let registration_service = container.create_registration_service();
+        let occurrence_service = container.create_occurrence_service();
+        let mut events: Vec<EventModel> = Vec::new();
+
+        for page in 1.. {
+                let offset = Offset { page, limit: CALENDAR_FEED_PAGE };
+                let favorites = favorite_service.list(user_id, offset, &[], &[]).await?;
+                let exhausted = u64::from(page) * u64::from(CALENDAR_FEED_PAGE) >= favorites.total;
+
+                events.extend(favorites.items.into_iter().map(|favorite| favorite.event));
+                if exhausted {
+                        break;
+                }
+        }
+
+        for page in 1.. {
+                let offset = Offset { page, limit: CALENDAR_FEED_PAGE };
+                let registered = registration_service.list_events(user_id, offset).await?;
+                let exhausted = registered.len() < CALENDAR_FEED_PAGE as usize;
+
+                events.extend(registered);
+                if exhausted {
+                        break;
+                }
+        }
+
+        events.sort_by_key(|event| (event.date, event.id));
+        events.dedup_by_key(|event| event.id);
+
+        let event_ids: Vec<EventId> = events.iter().map(|event| event.id).collect();
+        let overrides = occurrence_service.list_overrides(&event_ids).await?;
+
+        let response_body = render_calendar("Events", &events, &overrides);
+        let response = HttpResponse::Ok().content_type(CALENDAR_CONTENT_TYPE).body(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(UserIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 500, response = InternalServerError)))]
+#[post("/{user_id}/calendar")]
+async fn create_calendar_token(container: Data<DiContainer>, path: Path<UserIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let user_id: UserId = path.into_inner().try_into()?;
+        let claims = claims.into_inner();
+
+        if claims.sub != user_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let session_service = container.create_session_service();
+
+        let token = session_service.create_calendar(&claims)?;
+
+        let response_body = CalendarTokenResponse { token };
+        let response = HttpResponse::Created().json(response_body);
+        Ok(response)
+}
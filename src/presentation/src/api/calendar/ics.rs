@@ -0,0 +1,125 @@
+use domain::models::{event::{EventModel, EventStatus}, occurrence::OccurrenceOverrideModel, recurrence::format_utc};
+use time::OffsetDateTime;
+
+const PRODID: &str = "-//event-microservice//EN";
+const UID_DOMAIN: &str = "event-microservice";
+const MAX_LINE_OCTETS: usize = 75;
+
+pub fn render_calendar(name: &str, events: &[EventModel], overrides: &[OccurrenceOverrideModel]) -> String {
+        let mut calendar = String::new();
+
+        push_line(&mut calendar, "BEGIN:VCALENDAR");
+        push_line(&mut calendar, "VERSION:2.0");
+        push_line(&mut calendar, &format!("PRODID:{PRODID}"));
+        push_line(&mut calendar, "CALSCALE:GREGORIAN");
+        push_line(&mut calendar, "METHOD:PUBLISH");
+        push_line(&mut calendar, &format!("X-WR-CALNAME:{}", escape(name)));
+
+        for event in events {
+                let overrides: Vec<&OccurrenceOverrideModel> = overrides.iter()
+                        .filter(|occurrence| occurrence.event_id == event.id && event.recurrence.is_some())
+                        .collect();
+
+                push_event(&mut calendar, event, &overrides);
+
+                for occurrence in overrides.iter().filter(|occurrence| !occurrence.cancelled) {
+                        push_override(&mut calendar, event, occurrence);
+                }
+        }
+
+        push_line(&mut calendar, "END:VCALENDAR");
+        calendar
+}
+
+fn push_event(calendar: &mut String, event: &EventModel, overrides: &[&OccurrenceOverrideModel]) {
+        push_line(calendar, "BEGIN:VEVENT");
+        push_properties(calendar, event, event.date, &event.address, event.updated_at.assume_utc());
+
+        if let Some(recurrence) = &event.recurrence {
+                push_line(calendar, &format!("RRULE:{recurrence}"));
+        }
+
+        let exceptions: Vec<String> = overrides.iter()
+                .filter(|occurrence| occurrence.cancelled)
+                .map(|occurrence| format_utc(occurrence.original_date))
+                .collect();
+        if !exceptions.is_empty() {
+                push_line(calendar, &format!("EXDATE:{}", exceptions.join(",")));
+        }
+
+        push_line(calendar, "END:VEVENT");
+}
+
+fn push_override(calendar: &mut String, event: &EventModel, occurrence: &OccurrenceOverrideModel) {
+        let date = occurrence.date.unwrap_or(occurrence.original_date);
+        let address = occurrence.address.as_deref().unwrap_or(&event.address);
+        let updated_at = occurrence.updated_at.max(event.updated_at).assume_utc();
+
+        push_line(calendar, "BEGIN:VEVENT");
+        push_properties(calendar, event, date, address, updated_at);
+        push_line(calendar, &format!("RECURRENCE-ID:{}", format_utc(occurrence.original_date)));
+        push_line(calendar, "END:VEVENT");
+}
+
+fn push_properties(calendar: &mut String, event: &EventModel, date: OffsetDateTime, address: &str, updated_at: OffsetDateTime) {
+        let status = match event.status {
+                EventStatus::Cancelled => "CANCELLED",
+                EventStatus::Approved | EventStatus::Completed => "CONFIRMED",
+                _ => "TENTATIVE"
+        };
+
+        push_line(calendar, &format!("UID:event-{}@{UID_DOMAIN}", event.id));
+        push_line(calendar, &format!("DTSTAMP:{}", format_utc(updated_at)));
+        push_line(calendar, &format!("DTSTART:{}", format_utc(date)));
+        push_line(calendar, &format!("CREATED:{}", format_utc(event.created_at.assume_utc())));
+        push_line(calendar, &format!("LAST-MODIFIED:{}", format_utc(updated_at)));
+        push_line(calendar, &format!("SUMMARY:{}", escape(&event.title)));
+        push_line(calendar, &format!("DESCRIPTION:{}", escape(&event.description)));
+        push_line(calendar, &format!("LOCATION:{}", escape(address)));
+        push_line(calendar, &format!("STATUS:{status}"));
+
+        if let Some(location) = event.location {
+                push_line(calendar, &format!("GEO:{};{}", location.latitude, location.longitude));
+        }
+
+        let categories: Vec<String> = event.category.iter()
+                .chain(event.tags.iter())
+                .map(|category| escape(category))
+                .collect();
+        if !categories.is_empty() {
+                push_line(calendar, &format!("CATEGORIES:{}", categories.join(",")));
+        }
+}
+
+fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+
+        for char in text.chars() {
+                match char {
+                        '\\' => escaped.push_str("\\\\"),
+                        ';' => escaped.push_str("\\;"),
+                        ',' => escaped.push_str("\\,"),
+                        '\n' => escaped.push_str("\\n"),
+                        '\r' => {},
+                        _ => escaped.push(char)
+                }
+        }
+
+        escaped
+}
+
+fn push_line(calendar: &mut String, line: &str) {
+        let mut octets = 0;
+
+        for char in line.chars() {
+                if octets + char.len_utf8() > MAX_LINE_OCTETS {
+                        calendar.push_str("\r\n ");
+                        octets = 1;
+                }
+
+                calendar.push(char);
+                octets += char.len_utf8();
+        }
+
+        calendar.push_str("\r\n");
+}
@@ -0,0 +1,3 @@
+pub mod ics;
+pub mod types;
+pub mod handles;
\ No newline at end of file
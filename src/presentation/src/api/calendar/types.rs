@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToResponse};
+
+#[derive(Debug, Serialize, ToResponse)]
+pub struct CalendarTokenResponse {
+        pub token: String
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(style = Form, parameter_in = Query)]
+pub struct CalendarQuery {
+        pub token: String
+}
@@ -0,0 +1,39 @@
+use domain::models::category::{CategoryModel, CategoryName};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, TimestampSeconds};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(title = "NewCategory")]
+pub struct NewCategoryDto {
+        pub name: String
+}
+
+impl From<NewCategoryDto> for CategoryName {
+        fn from(value: NewCategoryDto) -> Self {
+                value.name.trim().to_lowercase()
+        }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(title = "Category")]
+pub struct CategoryDto {
+        pub name: String,
+        #[serde_as(as = "TimestampSeconds")]
+        pub created_at: PrimitiveDateTime,
+        #[serde_as(as = "TimestampSeconds")]
+        pub updated_at: PrimitiveDateTime
+}
+
+impl From<CategoryModel> for CategoryDto {
+        fn from(value: CategoryModel) -> Self {
+                Self {
+                        name: value.name,
+                        created_at: value.created_at,
+                        updated_at: value.updated_at
+                }
+        }
+}
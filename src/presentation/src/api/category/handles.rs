@@ -0,0 +1,60 @@
+use actix_web::{HttpResponse, delete, get, post, web::{Data, Json, Path}};
+use actix_web_httpauth::middleware::HttpAuthentication;
+use di::container::DiContainer;
+use domain::models::user::UserRole;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use actix_web_grants::protect;
+
+use super::{dto::NewCategoryDto, types::{CategoryNameParam, CategoryResponse, CategoryVecResponse}};
+
+use super::super::{authentication::validator, error::{BadRequest, Conflict, Forbidden, InternalServerError, NotFound, Result, Unauthorized, UnprocessableEntity, forbidden}};
+
+pub fn category_app_config(cfg: &mut ServiceConfig) {
+        cfg
+        .service(scope::scope("/categories")
+                .service(list_categories)
+                .service(scope::scope("")
+                        .wrap(HttpAuthentication::with_fn(validator))
+                        .service(create_category)
+                        .service(delete_category)
+                )
+        );
+}
+
+#[utoipa::path(responses((status = 500, response = InternalServerError)))]
+#[get("")]
+async fn list_categories(container: Data<DiContainer>) -> Result<HttpResponse> {
+        let category_service = container.create_category_service();
+
+        let categories = category_service.list().await?;
+
+        let response_body = CategoryVecResponse::from(categories);
+        let response = HttpResponse::Ok().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 409, response = Conflict), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
+#[post("")]
+#[protect("UserRole::Admin", ty = "UserRole", error = forbidden)]
+async fn create_category(container: Data<DiContainer>, body: Json<NewCategoryDto>) -> Result<HttpResponse> {
+        let category_service = container.create_category_service();
+
+        let category = category_service.create(body.into_inner().into()).await?;
+
+        let response_body = CategoryResponse::from(category);
+        let response = HttpResponse::Created().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(CategoryNameParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
+#[delete("/{name}")]
+#[protect("UserRole::Admin", ty = "UserRole", error = forbidden)]
+async fn delete_category(container: Data<DiContainer>, path: Path<CategoryNameParam>) -> Result<HttpResponse> {
+        let category_service = container.create_category_service();
+
+        let category = category_service.delete(path.into_inner().0).await?;
+
+        let response_body = CategoryResponse::from(category);
+        let response = HttpResponse::NoContent().json(response_body);
+        Ok(response)
+}
@@ -0,0 +1,33 @@
+use domain::models::category::CategoryModel;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToResponse};
+
+use super::dto::CategoryDto;
+
+#[derive(Debug, Serialize, ToResponse)]
+pub struct CategoryResponse {
+        pub category: CategoryDto
+}
+
+impl From<CategoryModel> for CategoryResponse {
+        fn from(value: CategoryModel) -> Self {
+                Self { category: value.into() }
+        }
+}
+
+#[derive(Debug, Serialize, ToResponse)]
+pub struct CategoryVecResponse {
+        pub categories: Vec<CategoryDto>
+}
+
+impl From<Vec<CategoryModel>> for CategoryVecResponse {
+        fn from(value: Vec<CategoryModel>) -> Self {
+                Self {
+                        categories: value.into_iter().map(Into::into).collect()
+                }
+        }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(names("name"), parameter_in = Path)]
+pub struct CategoryNameParam(pub String);
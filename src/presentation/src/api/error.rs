@@ -1,3 +1,12 @@
+use actix_web::{HttpRequest, HttpResponse, http::{StatusCode, header::{ContentType, HeaderValue, WWW_AUTHENTICATE}}};
+use domain::error::DomainError;
+use infrastructure::db::error::DbError;
+use serde::Serialize;
+use use_case::error::ServiceError;
+use utoipa::{ToResponse, ToSchema, openapi::{ContentBuilder, Ref, RefOr, Response, ResponseBuilder}};
+
+pub const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+
 #[derive(Debug, thiserror::Error)]
 pub enum HandlerError {
         #[error("{0}")]
@@ -14,10 +23,209 @@ pub enum HandlerError {
         MinPasswordLen,
         #[error("id accesses does not match")]
         IdMismatch,
+        #[error("update should change at least one field")]
+        EmptyUpdate,
+        #[error("status should be changed on its own, use PATCH /events/{{event_id}}/status")]
+        MixedUpdate,
+        #[error("latitude and longitude should be set together")]
+        IncompleteLocation,
         #[error("{0}")]
         Parse(#[from] domain::error::DomainError)
 }
 
-impl actix_web::ResponseError for HandlerError {}
+impl HandlerError {
+        pub fn code(&self) -> &'static str {
+                match self {
+                        Self::Service(err) => service_code(err),
+                        Self::LsThanZero(_) => "negative_value",
+                        Self::MaxLoginLen => "login_too_long",
+                        Self::MinLoginLen => "login_too_short",
+                        Self::MaxPasswordLen => "password_too_long",
+                        Self::MinPasswordLen => "password_too_short",
+                        Self::IdMismatch => "forbidden",
+                        Self::EmptyUpdate => "empty_update",
+                        Self::MixedUpdate => "mixed_update",
+                        Self::IncompleteLocation => "incomplete_location",
+                        Self::Parse(err) => domain_code(err)
+                }
+        }
+}
+
+fn service_code(err: &ServiceError) -> &'static str {
+        match err {
+                ServiceError::Db(DbError::ForeignKeyViolation { .. }) => "reference_violation",
+                ServiceError::Db(DbError::UniqueViolation { .. }) => "already_exists",
+                ServiceError::Db(DbError::Other(_)) => "internal",
+                ServiceError::Domain(err) => domain_code(err),
+                ServiceError::NotFound(..) => "not_found",
+                ServiceError::CapacityReached(_) => "capacity_reached",
+                ServiceError::RegistrationClosed(_) => "registration_closed",
+                ServiceError::CapacityBelowRegistrations(_) => "capacity_below_registrations",
+                ServiceError::WaitlistRejected(_) => "waitlist_rejected",
+                ServiceError::NotRecurring(_) => "not_recurring",
+                ServiceError::IllegalTransition { .. } => "illegal_transition",
+                ServiceError::InvalidCredentials => "invalid_credentials",
+                ServiceError::Expired(_) => "token_expired",
+                ServiceError::InvalidToken(_) => "invalid_token",
+                ServiceError::TokenReused(_) => "token_reused",
+                ServiceError::Revoked(_) => "revoked_token",
+                ServiceError::Other(_) => "internal"
+        }
+}
+
+fn domain_code(err: &DomainError) -> &'static str {
+        match err {
+                DomainError::OrderOp(_) | DomainError::Order(_) => "invalid_order",
+                DomainError::FilterOp(_) | DomainError::Filter(_) => "invalid_filter",
+                DomainError::Coordinates(_) => "invalid_coordinates",
+                DomainError::Recurrence(_) => "invalid_recurrence",
+                DomainError::Window(_) => "invalid_window",
+                DomainError::Cursor(_) => "invalid_cursor",
+                _ => "invalid_value"
+        }
+}
+
+fn domain_status(err: &DomainError) -> StatusCode {
+        match err {
+                DomainError::Coordinates(_) | DomainError::Recurrence(_) | DomainError::Window(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                _ => StatusCode::BAD_REQUEST
+        }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+        "type": "about:blank",
+        "title": "Not Found",
+        "status": 404,
+        "detail": "couldn't find event with field 1",
+        "code": "not_found"
+}))]
+pub struct ProblemDetails {
+        #[serde(rename = "type")]
+        pub problem_type: String,
+        pub title: String,
+        pub status: u16,
+        pub detail: String,
+        pub code: String
+}
+
+impl ProblemDetails {
+        pub fn new(status: StatusCode, code: &str, detail: impl ToString) -> Self {
+                Self {
+                        problem_type: "about:blank".to_string(),
+                        title: status.canonical_reason().unwrap_or_default().to_string(),
+                        status: status.as_u16(),
+                        detail: detail.to_string(),
+                        code: code.to_string()
+                }
+        }
+
+        pub fn into_response(self) -> HttpResponse {
+                let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+                HttpResponse::build(status)
+                        .content_type(ContentType(PROBLEM_CONTENT_TYPE.parse().unwrap()))
+                        .json(self)
+        }
+}
+
+macro_rules! problem_responses {
+        ($($name:ident => $description:literal),* $(,)?) => {
+                $(
+                        pub enum $name {}
+
+                        impl<'s> ToResponse<'s> for $name {
+                                fn response() -> (&'s str, RefOr<Response>) {
+                                        let content = ContentBuilder::new().schema(Some(Ref::from_schema_name("ProblemDetails"))).build();
+                                        let response = ResponseBuilder::new().description($description).content(PROBLEM_CONTENT_TYPE, content).build();
+                                        (stringify!($name), RefOr::T(response))
+                                }
+                        }
+                )*
+        };
+}
+
+problem_responses! {
+        BadRequest => "Malformed path, query or body",
+        Unauthorized => "Missing, invalid, expired or revoked credentials",
+        Forbidden => "Not allowed to access the resource",
+        NotFound => "Resource does not exist",
+        Conflict => "Request conflicts with the current state of the resource",
+        UnprocessableEntity => "Request is well-formed but fails validation",
+        InternalServerError => "Unexpected server error"
+}
+
+impl actix_web::ResponseError for HandlerError {
+        fn status_code(&self) -> StatusCode {
+                match self {
+                        Self::Service(err) => match err {
+                                ServiceError::Db(DbError::ForeignKeyViolation { .. } | DbError::UniqueViolation { .. })
+                                | ServiceError::CapacityReached(_)
+                                | ServiceError::RegistrationClosed(_)
+                                | ServiceError::CapacityBelowRegistrations(_)
+                                | ServiceError::WaitlistRejected(_)
+                                | ServiceError::IllegalTransition { .. } => StatusCode::CONFLICT,
+                                ServiceError::NotFound(..) => StatusCode::NOT_FOUND,
+                                ServiceError::NotRecurring(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                                ServiceError::InvalidCredentials
+                                | ServiceError::Expired(_)
+                                | ServiceError::InvalidToken(_)
+                                | ServiceError::TokenReused(_)
+                                | ServiceError::Revoked(_) => StatusCode::UNAUTHORIZED,
+                                ServiceError::Db(DbError::Other(_)) | ServiceError::Other(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                                ServiceError::Domain(err) => domain_status(err)
+                        },
+                        Self::LsThanZero(_)
+                        | Self::MaxLoginLen
+                        | Self::MinLoginLen
+                        | Self::MaxPasswordLen
+                        | Self::MinPasswordLen
+                        | Self::EmptyUpdate
+                        | Self::MixedUpdate
+                        | Self::IncompleteLocation => StatusCode::UNPROCESSABLE_ENTITY,
+                        Self::IdMismatch => StatusCode::FORBIDDEN,
+                        Self::Parse(err) => domain_status(err)
+                }
+        }
+
+        fn error_response(&self) -> HttpResponse {
+                let status = self.status_code();
+                let detail = match status {
+                        StatusCode::INTERNAL_SERVER_ERROR => {
+                                log::error!("{self:?}");
+                                "internal server error".to_string()
+                        },
+                        _ => self.to_string()
+                };
+
+                ProblemDetails::new(status, self.code(), detail).into_response()
+        }
+}
+
+pub fn forbidden() -> HttpResponse {
+        ProblemDetails::new(StatusCode::FORBIDDEN, "forbidden", "role is not allowed to access this resource").into_response()
+}
+
+pub fn unauthorized(code: &str, detail: &str) -> actix_web::Error {
+        let mut response = ProblemDetails::new(StatusCode::UNAUTHORIZED, code, detail).into_response();
+        response.headers_mut().insert(WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+        actix_web::error::InternalError::from_response(detail.to_string(), response).into()
+}
+
+pub fn json_error_handler(err: actix_web::error::JsonPayloadError, _: &HttpRequest) -> actix_web::Error {
+        bad_request("invalid_body", err)
+}
+
+pub fn query_error_handler(err: actix_web::error::QueryPayloadError, _: &HttpRequest) -> actix_web::Error {
+        bad_request("invalid_query", err)
+}
+
+pub fn path_error_handler(err: actix_web::error::PathError, _: &HttpRequest) -> actix_web::Error {
+        bad_request("invalid_path", err)
+}
+
+fn bad_request(code: &str, err: impl std::fmt::Display) -> actix_web::Error {
+        let response = ProblemDetails::new(StatusCode::BAD_REQUEST, code, &err).into_response();
+        actix_web::error::InternalError::from_response(err.to_string(), response).into()
+}
 
-pub type Result<T> = core::result::Result<T, HandlerError>;
\ No newline at end of file
+pub type Result<T> = core::result::Result<T, HandlerError>;
@@ -1,4 +1,4 @@
-use domain::models::event::{NewEvent, EventModel, EventStatus};
+use domain::models::{event::{NewEvent, EventModel, EventStatus, EventStatusChangeModel, EventUpdate}, geo::GeoPoint};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, TimestampSeconds};
 use time::{OffsetDateTime, PrimitiveDateTime};
@@ -17,7 +17,7 @@ impl TryFrom<EventStatusDto> for EventStatus {
         type Error = HandlerError;
 
         fn try_from(value: EventStatusDto) -> Result<Self> {
-                value.status.parse().map_err(|err| HandlerError::Parse(err))
+                value.status.parse().map_err(HandlerError::Parse)
         }
 }
 
@@ -31,7 +31,21 @@ pub struct NewEventDto {
         #[serde_as(as = "TimestampSeconds")]
         pub date: OffsetDateTime,
         pub cost: i32,
-        pub address: String
+        pub address: String,
+        #[serde(default)]
+        pub capacity: Option<i32>,
+        #[serde(default)]
+        pub category: Option<String>,
+        #[serde(default)]
+        pub tags: Vec<String>,
+        #[serde(default)]
+        pub language: Option<String>,
+        #[serde(default)]
+        pub latitude: Option<f64>,
+        #[serde(default)]
+        pub longitude: Option<f64>,
+        #[serde(default)]
+        pub recurrence: Option<String>
 }
 
 impl TryFrom<NewEventDto> for NewEvent {
@@ -44,11 +58,104 @@ impl TryFrom<NewEventDto> for NewEvent {
                         description: value.description,
                         date: value.date,
                         cost: value.cost.try_into()?,
-                        address: value.address
+                        address: value.address,
+                        capacity: value.capacity.map(TryInto::try_into).transpose()?,
+                        category: value.category.map(|category| category.trim().to_lowercase()),
+                        tags: normalize_tags(value.tags),
+                        language: value.language.map(|language| language.parse()).transpose()?.unwrap_or_default(),
+                        location: parse_location(value.latitude, value.longitude)?,
+                        recurrence: value.recurrence.map(|recurrence| recurrence.parse()).transpose()?
                 })
         }
 }
 
+fn parse_location(latitude: Option<f64>, longitude: Option<f64>) -> Result<Option<GeoPoint>> {
+        match (latitude, longitude) {
+                (Some(latitude), Some(longitude)) => Ok(Some(GeoPoint::new(latitude, longitude)?)),
+                (None, None) => Ok(None),
+                _ => Err(HandlerError::IncompleteLocation)
+        }
+}
+
+fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+        let mut tags: Vec<String> = tags.iter()
+                .map(|tag| tag.trim().to_lowercase())
+                .filter(|tag| !tag.is_empty())
+                .collect();
+        tags.sort();
+        tags.dedup();
+        tags
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(title = "EventUpdate")]
+pub struct EventUpdateDto {
+        #[serde(default)]
+        pub title: Option<String>,
+        #[serde(default)]
+        pub description: Option<String>,
+        #[serde(default)]
+        #[serde_as(as = "Option<TimestampSeconds>")]
+        pub date: Option<OffsetDateTime>,
+        #[serde(default)]
+        pub cost: Option<i32>,
+        #[serde(default)]
+        pub address: Option<String>,
+        #[serde(default, with = "::serde_with::rust::double_option")]
+        pub capacity: Option<Option<i32>>,
+        #[serde(default, with = "::serde_with::rust::double_option")]
+        pub latitude: Option<Option<f64>>,
+        #[serde(default, with = "::serde_with::rust::double_option")]
+        pub longitude: Option<Option<f64>>,
+        #[serde(default)]
+        #[schema(deprecated)]
+        pub status: Option<String>
+}
+
+pub enum EventPatch {
+        Update(EventUpdate),
+        Status(EventStatus)
+}
+
+impl TryFrom<EventUpdateDto> for EventPatch {
+        type Error = HandlerError;
+
+        fn try_from(mut value: EventUpdateDto) -> Result<Self> {
+                match (value.status.take(), EventUpdate::try_from(value)) {
+                        (None, changes) => Ok(Self::Update(changes?)),
+                        (Some(status), Err(HandlerError::EmptyUpdate)) => Ok(Self::Status(status.parse()?)),
+                        (Some(_), _) => Err(HandlerError::MixedUpdate)
+                }
+        }
+}
+
+impl TryFrom<EventUpdateDto> for EventUpdate {
+        type Error = HandlerError;
+
+        fn try_from(value: EventUpdateDto) -> Result<Self> {
+                let changes = EventUpdate {
+                        title: value.title,
+                        description: value.description,
+                        date: value.date,
+                        cost: value.cost.map(TryInto::try_into).transpose()?,
+                        address: value.address,
+                        capacity: value.capacity.map(|capacity| capacity.map(TryInto::try_into).transpose()).transpose()?,
+                        location: match (value.latitude, value.longitude) {
+                                (None, None) => None,
+                                (Some(latitude), Some(longitude)) => Some(parse_location(latitude, longitude)?),
+                                _ => return Err(HandlerError::IncompleteLocation)
+                        }
+                };
+
+                if changes.is_empty() {
+                        return Err(HandlerError::EmptyUpdate);
+                }
+
+                Ok(changes)
+        }
+}
+
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[schema(title = "Event")]
@@ -61,6 +168,15 @@ pub struct EventDto {
         pub date: OffsetDateTime,
         pub cost: i32,
         pub address: String,
+        pub capacity: Option<i32>,
+        pub category: Option<String>,
+        pub tags: Vec<String>,
+        pub language: String,
+        pub latitude: Option<f64>,
+        pub longitude: Option<f64>,
+        pub distance: Option<f64>,
+        pub rank: Option<f32>,
+        pub recurrence: Option<String>,
         pub status: String,
         #[serde_as(as = "TimestampSeconds")]
         pub created_at: PrimitiveDateTime,
@@ -78,9 +194,44 @@ impl From<EventModel> for EventDto {
                         date: value.date,
                         cost: value.cost as i32,
                         address: value.address,
+                        capacity: value.capacity.map(|capacity| capacity as i32),
+                        category: value.category,
+                        tags: value.tags,
+                        language: value.language.to_string(),
+                        latitude: value.location.map(|location| location.latitude),
+                        longitude: value.location.map(|location| location.longitude),
+                        distance: value.distance,
+                        rank: value.rank,
+                        recurrence: value.recurrence.map(|recurrence| recurrence.to_string()),
                         status: value.status.to_string(),
                         created_at: value.created_at,
                         updated_at: value.updated_at
                 }
         }
 }
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(title = "EventStatusChange")]
+pub struct EventStatusChangeDto {
+        pub id: i64,
+        pub event_id: i64,
+        pub from: String,
+        pub to: String,
+        pub changed_by: Option<i64>,
+        #[serde_as(as = "TimestampSeconds")]
+        pub created_at: PrimitiveDateTime
+}
+
+impl From<EventStatusChangeModel> for EventStatusChangeDto {
+        fn from(value: EventStatusChangeModel) -> Self {
+                Self {
+                        id: value.id as i64,
+                        event_id: value.event_id as i64,
+                        from: value.from.to_string(),
+                        to: value.to.to_string(),
+                        changed_by: value.changed_by.map(|changed_by| changed_by as i64),
+                        created_at: value.created_at
+                }
+        }
+}
@@ -1,29 +1,37 @@
-use actix_web::{HttpResponse, delete, get, patch, post, web::{Data, Json, Path, Query}};
+use actix_web::{HttpRequest, HttpResponse, delete, get, patch, post, web::{Data, Json, Path, Query}};
 use actix_web_httpauth::middleware::HttpAuthentication;
 use di::container::DiContainer;
-use domain::models::{event::{EventUpdate, NewEvent}, user::UserRole};
+use domain::models::{event::{EventId, NewEvent}, user::UserRole};
+use time::OffsetDateTime;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 use actix_web_grants::protect;
 
-use super::{dto::{EventStatusDto, NewEventDto}, types::{EventIdParam, EventResponse, EventVecResponse, ListEventsQuery}};
+use super::{dto::{EventPatch, EventStatusDto, EventUpdateDto, NewEventDto}, types::{EventIdParam, EventResponse, EventStatusChangeVecResponse, EventVecResponse, ListEventStatusHistoryQuery, ListEventsQuery}};
 
-use super::super::{authentication::{validator, ClaimsExtractor}, error::{HandlerError, Result}};
+use super::super::{authentication::{validator, ClaimsExtractor}, error::{BadRequest, Conflict, Forbidden, HandlerError, InternalServerError, NotFound, Result, Unauthorized, UnprocessableEntity, forbidden}, utils::decode_cursor};
 
 pub fn event_app_config(cfg: &mut ServiceConfig) {
         cfg
         .service(scope::scope("/events")
+                .configure(super::super::occurrence::handles::occurrence_public_app_config)
+                .configure(super::super::calendar::handles::event_calendar_app_config)
                 .service(get_event)
                 .service(list_events)
                 .service(scope::scope("")
-                        .wrap(HttpAuthentication::bearer(validator))
+                        .wrap(HttpAuthentication::with_fn(validator))
                         .service(create_event)
+                        .service(update_event)
                         .service(update_event_status)
+                        .service(list_event_status_history)
                         .service(delete_event)
+                        .configure(super::super::registration::handles::registration_app_config)
+                        .configure(super::super::waitlist::handles::waitlist_app_config)
+                        .configure(super::super::occurrence::handles::occurrence_app_config)
                 )
         );
 }
 
-#[utoipa::path(params(EventIdParam))]
+#[utoipa::path(params(EventIdParam), responses((status = 400, response = BadRequest), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
 #[get("/{event_id}")]
 async fn get_event(container: Data<DiContainer>, path: Path<EventIdParam>) -> Result<HttpResponse> {
         let event_id = path.into_inner().try_into()?;
@@ -36,9 +44,9 @@ async fn get_event(container: Data<DiContainer>, path: Path<EventIdParam>) -> Re
         Ok(response)
 }
 
-#[utoipa::path]
+#[utoipa::path(responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 409, response = Conflict), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
 #[post("")]
-#[protect(any("UserRole::Organizer", "UserRole::Admin"), ty = "UserRole")]
+#[protect(any("UserRole::Organizer", "UserRole::Admin"), ty = "UserRole", error = forbidden)]
 async fn create_event(container: Data<DiContainer>, body: Json<NewEventDto>, claims: ClaimsExtractor) -> Result<HttpResponse> {
         let new_event: NewEvent = body.into_inner().try_into()?;
 
@@ -55,18 +63,20 @@ async fn create_event(container: Data<DiContainer>, body: Json<NewEventDto>, cla
         Ok(response)
 }
 
-#[utoipa::path(params(EventIdParam))]
+#[utoipa::path(params(EventIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
 #[delete("/{event_id}")]
-#[protect(any("UserRole::Organizer", "UserRole::Admin"), ty = "UserRole")]
+#[protect(any("UserRole::Organizer", "UserRole::Admin"), ty = "UserRole", error = forbidden)]
 async fn delete_event(container: Data<DiContainer>, path: Path<EventIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
-        let event_id = path.into_inner().try_into()?;
+        let event_id: EventId = path.into_inner().try_into()?;
+        let claims = claims.into_inner();
+        let event_service = container.create_event_service();
 
-        if claims.into_inner().sub != event_id {
+        let event = event_service.get(event_id).await?;
+
+        if claims.role != UserRole::Admin && claims.sub != event.organizer_id {
                 return Err(HandlerError::IdMismatch);
         }
 
-        let event_service = container.create_event_service();
-
         let event = event_service.delete(event_id).await?;
 
         let response_body = EventResponse::from(event);
@@ -74,30 +84,104 @@ async fn delete_event(container: Data<DiContainer>, path: Path<EventIdParam>, cl
         Ok(response)
 }
 
-#[utoipa::path(params(EventIdParam))]
+#[utoipa::path(params(EventIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 409, response = Conflict), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
 #[patch("/{event_id}")]
-#[protect("UserRole::Admin", ty = "UserRole")]
-async fn update_event_status(container: Data<DiContainer>, path: Path<EventIdParam>, body: Json<EventStatusDto>) -> Result<HttpResponse> {
+#[protect(any("UserRole::Organizer", "UserRole::Admin"), ty = "UserRole", error = forbidden)]
+async fn update_event(container: Data<DiContainer>, path: Path<EventIdParam>, body: Json<EventUpdateDto>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let event_id: EventId = path.into_inner().try_into()?;
+        let claims = claims.into_inner();
+        let event_service = container.create_event_service();
+
+        let changes = match body.into_inner().try_into()? {
+                EventPatch::Update(changes) => changes,
+                EventPatch::Status(status) => {
+                        if claims.role != UserRole::Admin {
+                                return Ok(forbidden());
+                        }
+
+                        let event = event_service.update_status(event_id, status, claims.sub).await?;
+
+                        let response_body = EventResponse::from(event);
+                        let response = HttpResponse::Ok()
+                                .insert_header(("Deprecation", "true"))
+                                .insert_header(("Link", format!("</api/v1/events/{event_id}/status>; rel=\"successor-version\"")))
+                                .json(response_body);
+                        return Ok(response);
+                }
+        };
+
+        let event = event_service.get(event_id).await?;
+
+        if claims.role != UserRole::Admin && claims.sub != event.organizer_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let event = event_service.update(event_id, changes, claims.sub).await?;
+
+        let response_body = EventResponse::from(event);
+        let response = HttpResponse::Ok().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(EventIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 409, response = Conflict), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
+#[patch("/{event_id}/status")]
+#[protect("UserRole::Admin", ty = "UserRole", error = forbidden)]
+async fn update_event_status(container: Data<DiContainer>, path: Path<EventIdParam>, body: Json<EventStatusDto>, claims: ClaimsExtractor) -> Result<HttpResponse> {
         let event_id = path.into_inner().try_into()?;
         let event_status = body.into_inner().try_into()?;
         let event_service = container.create_event_service();
 
-        let event = event_service.update(event_id, EventUpdate::Status(event_status)).await?;
+        let event = event_service.update_status(event_id, event_status, claims.into_inner().sub).await?;
 
         let response_body = EventResponse::from(event);
         let response = HttpResponse::Ok().json(response_body);
         Ok(response)
 }
 
-#[utoipa::path(params(ListEventsQuery))]
+#[utoipa::path(params(ListEventsQuery), responses((status = 400, response = BadRequest), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
 #[get("")]
-async fn list_events(container: Data<DiContainer>, query: Query<ListEventsQuery>) -> Result<HttpResponse> {
+async fn list_events(container: Data<DiContainer>, query: Query<ListEventsQuery>, req: HttpRequest) -> Result<HttpResponse> {
+        let query = query.into_inner();
+        let event_service = container.create_event_service();
+
+        let search = query.search();
+        let mut filters = query.filter;
+        if let Some(view) = query.view {
+                filters.push(view.filter(OffsetDateTime::now_utc()).into());
+        }
+
+        let response_body = match decode_cursor(query.mode, query.cursor.as_deref())? {
+                Some(cursor) => {
+                        let events = event_service.list_after(query.offset.limit.try_into()?, cursor.as_ref(), &filters, &query.order_by, search.as_ref(), query.near.as_ref()).await?;
+                        EventVecResponse::from_cursor(events, &req)
+                },
+                None => {
+                        let events = event_service.list(query.offset.try_into()?, &filters, &query.order_by, search.as_ref(), query.near.as_ref()).await?;
+                        EventVecResponse::new(events, &req)
+                }
+        };
+        let response = HttpResponse::Ok().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(EventIdParam, ListEventStatusHistoryQuery), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
+#[get("/{event_id}/history")]
+#[protect(any("UserRole::Organizer", "UserRole::Admin"), ty = "UserRole", error = forbidden)]
+async fn list_event_status_history(container: Data<DiContainer>, path: Path<EventIdParam>, query: Query<ListEventStatusHistoryQuery>, req: HttpRequest, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let event_id: EventId = path.into_inner().try_into()?;
         let query = query.into_inner();
+        let claims = claims.into_inner();
         let event_service = container.create_event_service();
 
-        let events = event_service.list(query.offset.try_into()?, &query.filter, &query.order_by).await?;
+        let event = event_service.get(event_id).await?;
+
+        if claims.role != UserRole::Admin && claims.sub != event.organizer_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let history = event_service.list_status_history(event_id, query.offset.try_into()?).await?;
 
-        let response_body = EventVecResponse::from(events);
+        let response_body = EventStatusChangeVecResponse::new(history, &req);
         let response = HttpResponse::Ok().json(response_body);
         Ok(response)
 }
\ No newline at end of file
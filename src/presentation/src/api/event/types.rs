@@ -1,14 +1,15 @@
-use domain::models::event::{EventFilter, EventId, EventModel, EventOrder};
+use actix_web::HttpRequest;
+use domain::models::{cursor::CursorPage, event::{EventFilter, EventId, EventModel, EventOrder, EventSearch, EventStatusChangeModel, EventView, SearchLanguage}, geo::GeoRadius, utils::{FilterGroup, Page}};
 use serde::{Deserialize, Serialize};
 use serde_aux::prelude::deserialize_default_from_null;
-use serde_with::{serde_as, StringWithSeparator, formats::CommaSeparator};
+use serde_with::{serde_as, DisplayFromStr, StringWithSeparator, formats::CommaSeparator};
 use utoipa::{IntoParams, ToResponse};
 
 use super::super::{HandlerError, Result};
 
-use super::super::utils::OffsetDto;
+use super::super::utils::{CursorPaginationDto, OffsetDto, PaginationDto, PaginationMode, PaginationModeDto};
 
-use super::dto::EventDto;
+use super::dto::{EventDto, EventStatusChangeDto};
 
 #[derive(Debug, Serialize, ToResponse)]
 pub struct EventResponse {
@@ -23,13 +24,40 @@ impl From<EventModel> for EventResponse {
 
 #[derive(Debug, Serialize, ToResponse)]
 pub struct EventVecResponse {
-        pub events: Vec<EventDto>
+        pub events: Vec<EventDto>,
+        #[serde(flatten)]
+        pub pagination: PaginationModeDto
 }
 
-impl From<Vec<EventModel>> for EventVecResponse {
-        fn from(value: Vec<EventModel>) -> Self {
+impl EventVecResponse {
+        pub fn new(page: Page<EventModel>, req: &HttpRequest) -> Self {
                 Self {
-                        events: value.into_iter().map(Into::into).collect()
+                        pagination: PaginationModeDto::Page(PaginationDto::new(&page, req)),
+                        events: page.items.into_iter().map(Into::into).collect()
+                }
+        }
+
+        pub fn from_cursor(page: CursorPage<EventModel>, req: &HttpRequest) -> Self {
+                let (items, pagination) = CursorPaginationDto::new(page, req);
+                Self {
+                        pagination: PaginationModeDto::Cursor(pagination),
+                        events: items.into_iter().map(Into::into).collect()
+                }
+        }
+}
+
+#[derive(Debug, Serialize, ToResponse)]
+pub struct EventStatusChangeVecResponse {
+        pub history: Vec<EventStatusChangeDto>,
+        #[serde(flatten)]
+        pub pagination: PaginationDto
+}
+
+impl EventStatusChangeVecResponse {
+        pub fn new(page: Page<EventStatusChangeModel>, req: &HttpRequest) -> Self {
+                Self {
+                        pagination: PaginationDto::new(&page, req),
+                        history: page.items.into_iter().map(Into::into).collect()
                 }
         }
 }
@@ -55,10 +83,51 @@ pub struct ListEventsQuery {
         pub offset: OffsetDto,
         #[param(value_type = String)]
         #[serde(default)]
-        #[serde_as(as = "StringWithSeparator::<CommaSeparator, EventFilter>")]
-        pub filter: Vec<EventFilter>,
+        #[serde_as(as = "StringWithSeparator::<CommaSeparator, FilterGroup<EventFilter>>")]
+        pub filter: Vec<FilterGroup<EventFilter>>,
         #[param(value_type = String)]
         #[serde(default)]
         #[serde_as(as = "StringWithSeparator::<CommaSeparator, EventOrder>")]
-        pub order_by: Vec<EventOrder>
+        pub order_by: Vec<EventOrder>,
+        #[param(required = false)]
+        #[serde(default)]
+        pub q: Option<String>,
+        #[param(value_type = Option<String>)]
+        #[serde(default)]
+        #[serde_as(as = "Option<DisplayFromStr>")]
+        pub lang: Option<SearchLanguage>,
+        #[param(value_type = Option<String>)]
+        #[serde(default)]
+        #[serde_as(as = "Option<DisplayFromStr>")]
+        pub near: Option<GeoRadius>,
+        #[param(value_type = Option<String>)]
+        #[serde(default)]
+        #[serde_as(as = "Option<DisplayFromStr>")]
+        pub view: Option<EventView>,
+        #[param(inline)]
+        #[serde(default)]
+        pub mode: PaginationMode,
+        #[param(required = false)]
+        #[serde(default)]
+        pub cursor: Option<String>
+}
+
+impl ListEventsQuery {
+        pub fn search(&self) -> Option<EventSearch> {
+                self.q.as_ref()
+                        .map(|q| q.trim())
+                        .filter(|q| !q.is_empty())
+                        .map(|q| EventSearch {
+                                query: q.to_string(),
+                                language: self.lang
+                        })
+        }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(style = Form, parameter_in = Query)]
+pub struct ListEventStatusHistoryQuery {
+        #[param(required = false)]
+        #[serde(flatten, deserialize_with = "deserialize_default_from_null")]
+        pub offset: OffsetDto
 }
@@ -1,4 +1,4 @@
-use actix_web::{HttpResponse, delete, get, post, web::{Data, Path, Query}};
+use actix_web::{HttpRequest, HttpResponse, delete, get, post, web::{Data, Path, Query}};
 use di::container::DiContainer;
 use domain::models::favorite::FavoriteId;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
@@ -7,7 +7,7 @@ use crate::api::{HandlerError, authentication::ClaimsExtractor, user::types::Use
 
 use super::{types::{FavoriteIdParam, FavoriteEventResponse, FavoriteEventVecResponse, FavoriteResponse, ListFavoriteEventsQuery}};
 
-use super::super::error::Result;
+use super::super::{error::{BadRequest, Conflict, Forbidden, InternalServerError, NotFound, Result, Unauthorized, UnprocessableEntity}, utils::decode_cursor};
 
 pub fn favorite_app_config(cfg: &mut ServiceConfig) {
         cfg
@@ -19,7 +19,7 @@ pub fn favorite_app_config(cfg: &mut ServiceConfig) {
         );
 }
 
-#[utoipa::path(params(FavoriteIdParam))]
+#[utoipa::path(params(FavoriteIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
 #[get("/{event_id}")]
 async fn get_favorite(container: Data<DiContainer>, path: Path<FavoriteIdParam>) -> Result<HttpResponse> {
         let favorite_id = path.into_inner().try_into()?;
@@ -32,7 +32,7 @@ async fn get_favorite(container: Data<DiContainer>, path: Path<FavoriteIdParam>)
         Ok(response)
 }
 
-#[utoipa::path(params(FavoriteIdParam))]
+#[utoipa::path(params(FavoriteIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 409, response = Conflict), (status = 500, response = InternalServerError)))]
 #[post("/{event_id}")]
 async fn create_favorite(container: Data<DiContainer>, path: Path<FavoriteIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
         let favorite_id: FavoriteId = path.into_inner().try_into()?;
@@ -50,7 +50,7 @@ async fn create_favorite(container: Data<DiContainer>, path: Path<FavoriteIdPara
         Ok(response)
 }
 
-#[utoipa::path(params(FavoriteIdParam))]
+#[utoipa::path(params(FavoriteIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
 #[delete("/{event_id}")]
 async fn delete_favorite(container: Data<DiContainer>, path: Path<FavoriteIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
         let favorite_id: FavoriteId = path.into_inner().try_into()?;
@@ -68,16 +68,23 @@ async fn delete_favorite(container: Data<DiContainer>, path: Path<FavoriteIdPara
         Ok(response)
 }
 
-#[utoipa::path(params(UserIdParam, ListFavoriteEventsQuery))]
+#[utoipa::path(params(UserIdParam, ListFavoriteEventsQuery), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
 #[get("")]
-async fn list_favorites(container: Data<DiContainer>, path: Path<UserIdParam>, query: Query<ListFavoriteEventsQuery>) -> Result<HttpResponse> {
+async fn list_favorites(container: Data<DiContainer>, path: Path<UserIdParam>, query: Query<ListFavoriteEventsQuery>, req: HttpRequest) -> Result<HttpResponse> {
         let user_id = path.into_inner().try_into()?;
         let query = query.into_inner();
         let favorite_service = container.create_favorite_service();
 
-        let favorite_events = favorite_service.list(user_id, query.offset.try_into()?, &query.filter, &query.order_by).await?;
-
-        let response_body = FavoriteEventVecResponse::from(favorite_events);
+        let response_body = match decode_cursor(query.mode, query.cursor.as_deref())? {
+                Some(cursor) => {
+                        let favorite_events = favorite_service.list_after(user_id, query.offset.limit.try_into()?, cursor.as_ref(), &query.filter, &query.order_by).await?;
+                        FavoriteEventVecResponse::from_cursor(favorite_events, &req)
+                },
+                None => {
+                        let favorite_events = favorite_service.list(user_id, query.offset.try_into()?, &query.filter, &query.order_by).await?;
+                        FavoriteEventVecResponse::new(favorite_events, &req)
+                }
+        };
         let response = HttpResponse::Ok().json(response_body);
         Ok(response)
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
-use domain::models::favorite::{FavoriteEventModel, FavoriteFilter, FavoriteId, FavoriteModel, FavoriteOrder};
+use actix_web::HttpRequest;
+use domain::models::{cursor::CursorPage, favorite::{FavoriteEventModel, FavoriteFilter, FavoriteId, FavoriteModel, FavoriteOrder}, utils::{FilterGroup, Page}};
 use serde::{Deserialize, Serialize};
 use serde_aux::prelude::deserialize_default_from_null;
 use serde_with::{serde_as, StringWithSeparator, formats::CommaSeparator};
@@ -6,7 +7,7 @@ use utoipa::{IntoParams, ToResponse};
 
 use super::super::{HandlerError, Result};
 
-use super::super::utils::OffsetDto;
+use super::super::utils::{CursorPaginationDto, OffsetDto, PaginationDto, PaginationMode, PaginationModeDto};
 
 use super::dto::{FavoriteDto, FavoriteEventDto};
 
@@ -34,13 +35,24 @@ impl From<FavoriteEventModel> for FavoriteEventResponse {
 
 #[derive(Debug, Serialize, ToResponse)]
 pub struct FavoriteEventVecResponse {
-        pub favorite_events: Vec<FavoriteEventDto>
+        pub favorite_events: Vec<FavoriteEventDto>,
+        #[serde(flatten)]
+        pub pagination: PaginationModeDto
 }
 
-impl From<Vec<FavoriteEventModel>> for FavoriteEventVecResponse {
-        fn from(value: Vec<FavoriteEventModel>) -> Self {
+impl FavoriteEventVecResponse {
+        pub fn new(page: Page<FavoriteEventModel>, req: &HttpRequest) -> Self {
                 Self {
-                        favorite_events: value.into_iter().map(Into::into).collect()
+                        pagination: PaginationModeDto::Page(PaginationDto::new(&page, req)),
+                        favorite_events: page.items.into_iter().map(Into::into).collect()
+                }
+        }
+
+        pub fn from_cursor(page: CursorPage<FavoriteEventModel>, req: &HttpRequest) -> Self {
+                let (items, pagination) = CursorPaginationDto::new(page, req);
+                Self {
+                        pagination: PaginationModeDto::Cursor(pagination),
+                        favorite_events: items.into_iter().map(Into::into).collect()
                 }
         }
 }
@@ -69,10 +81,16 @@ pub struct ListFavoriteEventsQuery {
         pub offset: OffsetDto,
         #[param(value_type = String)]
         #[serde(default)]
-        #[serde_as(as = "StringWithSeparator::<CommaSeparator, FavoriteFilter>")]
-        pub filter: Vec<FavoriteFilter>,
+        #[serde_as(as = "StringWithSeparator::<CommaSeparator, FilterGroup<FavoriteFilter>>")]
+        pub filter: Vec<FilterGroup<FavoriteFilter>>,
         #[param(value_type = String)]
         #[serde(default)]
         #[serde_as(as = "StringWithSeparator::<CommaSeparator, FavoriteOrder>")]
-        pub order_by: Vec<FavoriteOrder>
+        pub order_by: Vec<FavoriteOrder>,
+        #[param(inline)]
+        #[serde(default)]
+        pub mode: PaginationMode,
+        #[param(required = false)]
+        #[serde(default)]
+        pub cursor: Option<String>
 }
@@ -0,0 +1,17 @@
+use actix_web::{HttpResponse, get};
+use domain::models::token;
+use utoipa_actix_web::service_config::ServiceConfig;
+
+use super::super::error::Result;
+
+pub fn jwks_app_config(cfg: &mut ServiceConfig) {
+        cfg
+        .service(get_jwks);
+}
+
+#[utoipa::path]
+#[get("/.well-known/jwks.json")]
+async fn get_jwks() -> Result<HttpResponse> {
+        let response = HttpResponse::Ok().json(token::jwks());
+        Ok(response)
+}
@@ -0,0 +1 @@
+pub mod handles;
\ No newline at end of file
@@ -2,8 +2,15 @@ pub mod user;
 pub mod error;
 pub mod utils;
 pub mod refresh;
+pub mod session;
 pub mod event;
+pub mod category;
+pub mod calendar;
 pub mod authentication;
 pub mod favorite;
+pub mod occurrence;
+pub mod registration;
+pub mod waitlist;
+pub mod jwks;
 
-pub(self) use error::{HandlerError, Result};
\ No newline at end of file
+use error::{HandlerError, Result};
\ No newline at end of file
@@ -0,0 +1,86 @@
+use domain::models::occurrence::{OccurrenceModel, OccurrenceOverride, OccurrenceOverrideModel};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, TimestampSeconds};
+use time::{OffsetDateTime, PrimitiveDateTime};
+use utoipa::ToSchema;
+
+use crate::api::event::dto::EventDto;
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(title = "NewOccurrenceOverride")]
+pub struct NewOccurrenceOverrideDto {
+        #[serde(default)]
+        #[serde_as(as = "Option<TimestampSeconds>")]
+        pub date: Option<OffsetDateTime>,
+        #[serde(default)]
+        pub address: Option<String>,
+        #[serde(default)]
+        pub cancelled: bool
+}
+
+impl From<NewOccurrenceOverrideDto> for OccurrenceOverride {
+        fn from(value: NewOccurrenceOverrideDto) -> Self {
+                Self {
+                        date: value.date,
+                        address: value.address,
+                        cancelled: value.cancelled
+                }
+        }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(title = "OccurrenceOverride")]
+pub struct OccurrenceOverrideDto {
+        pub event_id: i64,
+        #[serde_as(as = "TimestampSeconds")]
+        pub original_date: OffsetDateTime,
+        #[serde_as(as = "Option<TimestampSeconds>")]
+        pub date: Option<OffsetDateTime>,
+        pub address: Option<String>,
+        pub cancelled: bool,
+        #[serde_as(as = "TimestampSeconds")]
+        pub created_at: PrimitiveDateTime,
+        #[serde_as(as = "TimestampSeconds")]
+        pub updated_at: PrimitiveDateTime
+}
+
+impl From<OccurrenceOverrideModel> for OccurrenceOverrideDto {
+        fn from(value: OccurrenceOverrideModel) -> Self {
+                Self {
+                        event_id: value.event_id as i64,
+                        original_date: value.original_date,
+                        date: value.date,
+                        address: value.address,
+                        cancelled: value.cancelled,
+                        created_at: value.created_at,
+                        updated_at: value.updated_at
+                }
+        }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(title = "Occurrence")]
+pub struct OccurrenceDto {
+        pub event: EventDto,
+        #[serde_as(as = "TimestampSeconds")]
+        pub original_date: OffsetDateTime,
+        #[serde_as(as = "TimestampSeconds")]
+        pub date: OffsetDateTime,
+        pub address: String,
+        pub cancelled: bool
+}
+
+impl From<OccurrenceModel> for OccurrenceDto {
+        fn from(value: OccurrenceModel) -> Self {
+                Self {
+                        event: value.event.into(),
+                        original_date: value.original_date,
+                        date: value.date,
+                        address: value.address,
+                        cancelled: value.cancelled
+                }
+        }
+}
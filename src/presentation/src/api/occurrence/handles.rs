@@ -0,0 +1,95 @@
+use actix_web::{HttpRequest, HttpResponse, delete, get, put, web::{Data, Json, Path, Query}};
+use di::container::DiContainer;
+use domain::models::{occurrence::{OccurrenceId, OccurrenceWindow}, user::UserRole};
+use utoipa_actix_web::service_config::ServiceConfig;
+use actix_web_grants::protect;
+
+use crate::api::{HandlerError, authentication::ClaimsExtractor, event::types::EventIdParam};
+
+use super::{dto::NewOccurrenceOverrideDto, types::{ListOccurrencesQuery, OccurrenceIdParam, OccurrenceOverrideResponse, OccurrenceVecResponse, OccurrenceWindowQuery}};
+
+use super::super::error::{BadRequest, Forbidden, InternalServerError, NotFound, Result, Unauthorized, UnprocessableEntity, forbidden};
+
+pub fn occurrence_public_app_config(cfg: &mut ServiceConfig) {
+        cfg
+        .service(list_occurrences)
+        .service(list_event_occurrences);
+}
+
+pub fn occurrence_app_config(cfg: &mut ServiceConfig) {
+        cfg
+        .service(update_occurrence)
+        .service(delete_occurrence);
+}
+
+#[utoipa::path(params(ListOccurrencesQuery), responses((status = 400, response = BadRequest), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
+#[get("/occurrences")]
+async fn list_occurrences(container: Data<DiContainer>, query: Query<ListOccurrencesQuery>, req: HttpRequest) -> Result<HttpResponse> {
+        let query = query.into_inner();
+        let window = OccurrenceWindow::new(query.from.0, query.to.0)?;
+        let occurrence_service = container.create_occurrence_service();
+
+        let occurrences = occurrence_service.list(window, query.offset.try_into()?, &query.filter).await?;
+
+        let response_body = OccurrenceVecResponse::new(occurrences, &req);
+        let response = HttpResponse::Ok().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(EventIdParam, OccurrenceWindowQuery), responses((status = 400, response = BadRequest), (status = 404, response = NotFound), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
+#[get("/{event_id}/occurrences")]
+async fn list_event_occurrences(container: Data<DiContainer>, path: Path<EventIdParam>, query: Query<OccurrenceWindowQuery>) -> Result<HttpResponse> {
+        let event_id = path.into_inner().try_into()?;
+        let window = query.into_inner().try_into()?;
+        let occurrence_service = container.create_occurrence_service();
+
+        let occurrences = occurrence_service.list_event(event_id, window).await?;
+
+        let response_body = OccurrenceVecResponse::from(occurrences);
+        let response = HttpResponse::Ok().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(OccurrenceIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
+#[put("/{event_id}/occurrences/{original_date}")]
+#[protect(any("UserRole::Organizer", "UserRole::Admin"), ty = "UserRole", error = forbidden)]
+async fn update_occurrence(container: Data<DiContainer>, path: Path<OccurrenceIdParam>, body: Json<NewOccurrenceOverrideDto>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let occurrence_id: OccurrenceId = path.into_inner().try_into()?;
+        let claims = claims.into_inner();
+        let event_service = container.create_event_service();
+        let occurrence_service = container.create_occurrence_service();
+
+        let event = event_service.get(occurrence_id.event_id).await?;
+
+        if claims.role != UserRole::Admin && claims.sub != event.organizer_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let occurrence = occurrence_service.upsert(occurrence_id, body.into_inner().into()).await?;
+
+        let response_body = OccurrenceOverrideResponse::from(occurrence);
+        let response = HttpResponse::Ok().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(OccurrenceIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
+#[delete("/{event_id}/occurrences/{original_date}")]
+#[protect(any("UserRole::Organizer", "UserRole::Admin"), ty = "UserRole", error = forbidden)]
+async fn delete_occurrence(container: Data<DiContainer>, path: Path<OccurrenceIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let occurrence_id: OccurrenceId = path.into_inner().try_into()?;
+        let claims = claims.into_inner();
+        let event_service = container.create_event_service();
+        let occurrence_service = container.create_occurrence_service();
+
+        let event = event_service.get(occurrence_id.event_id).await?;
+
+        if claims.role != UserRole::Admin && claims.sub != event.organizer_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let occurrence = occurrence_service.delete(occurrence_id).await?;
+
+        let response_body = OccurrenceOverrideResponse::from(occurrence);
+        let response = HttpResponse::NoContent().json(response_body);
+        Ok(response)
+}
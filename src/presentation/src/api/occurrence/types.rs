@@ -0,0 +1,104 @@
+use actix_web::HttpRequest;
+use domain::{error::DomainError, models::{event::EventFilter, occurrence::{OccurrenceId, OccurrenceModel, OccurrenceOverrideModel, OccurrenceWindow}, utils::{FilterGroup, Page, Timestamp}}};
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::deserialize_default_from_null;
+use serde_with::{serde_as, DisplayFromStr, StringWithSeparator, formats::CommaSeparator};
+use time::OffsetDateTime;
+use utoipa::{IntoParams, ToResponse};
+
+use super::super::{HandlerError, Result};
+
+use super::super::utils::{OffsetDto, PaginationDto};
+
+use super::dto::{OccurrenceDto, OccurrenceOverrideDto};
+
+#[derive(Debug, Serialize, ToResponse)]
+pub struct OccurrenceOverrideResponse {
+        pub occurrence: OccurrenceOverrideDto
+}
+
+impl From<OccurrenceOverrideModel> for OccurrenceOverrideResponse {
+        fn from(value: OccurrenceOverrideModel) -> Self {
+                Self { occurrence: value.into() }
+        }
+}
+
+#[derive(Debug, Serialize, ToResponse)]
+pub struct OccurrenceVecResponse {
+        pub occurrences: Vec<OccurrenceDto>,
+        #[serde(flatten, skip_serializing_if = "Option::is_none")]
+        pub pagination: Option<PaginationDto>
+}
+
+impl OccurrenceVecResponse {
+        pub fn new(page: Page<OccurrenceModel>, req: &HttpRequest) -> Self {
+                Self {
+                        pagination: Some(PaginationDto::new(&page, req)),
+                        occurrences: page.items.into_iter().map(Into::into).collect()
+                }
+        }
+}
+
+impl From<Vec<OccurrenceModel>> for OccurrenceVecResponse {
+        fn from(value: Vec<OccurrenceModel>) -> Self {
+                Self {
+                        occurrences: value.into_iter().map(Into::into).collect(),
+                        pagination: None
+                }
+        }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(names("event_id", "original_date"), parameter_in = Path)]
+pub struct OccurrenceIdParam(pub i64, pub i64);
+
+impl TryFrom<OccurrenceIdParam> for OccurrenceId {
+        type Error = HandlerError;
+
+        fn try_from(value: OccurrenceIdParam) -> Result<Self> {
+                Ok(OccurrenceId {
+                        event_id: value.0.try_into()?,
+                        original_date: OffsetDateTime::from_unix_timestamp(value.1)
+                                .map_err(|_| DomainError::Parse(value.1.to_string()))?
+                })
+        }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(style = Form, parameter_in = Query)]
+pub struct OccurrenceWindowQuery {
+        #[param(value_type = String)]
+        #[serde_as(as = "DisplayFromStr")]
+        pub from: Timestamp,
+        #[param(value_type = String)]
+        #[serde_as(as = "DisplayFromStr")]
+        pub to: Timestamp
+}
+
+impl TryFrom<OccurrenceWindowQuery> for OccurrenceWindow {
+        type Error = HandlerError;
+
+        fn try_from(value: OccurrenceWindowQuery) -> Result<Self> {
+                OccurrenceWindow::new(value.from.0, value.to.0).map_err(Into::into)
+        }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(style = Form, parameter_in = Query)]
+pub struct ListOccurrencesQuery {
+        #[param(required = false)]
+        #[serde(flatten, deserialize_with = "deserialize_default_from_null")]
+        pub offset: OffsetDto,
+        #[param(value_type = String)]
+        #[serde(default)]
+        #[serde_as(as = "StringWithSeparator::<CommaSeparator, FilterGroup<EventFilter>>")]
+        pub filter: Vec<FilterGroup<EventFilter>>,
+        #[param(value_type = String)]
+        #[serde_as(as = "DisplayFromStr")]
+        pub from: Timestamp,
+        #[param(value_type = String)]
+        #[serde_as(as = "DisplayFromStr")]
+        pub to: Timestamp
+}
@@ -2,6 +2,8 @@ use domain::models::token::TokenPair;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::api::user::dto::UserCredentialsDto;
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[schema(title = "TokenPair")]
 pub struct TokenPairDto {
@@ -25,4 +27,13 @@ impl From<TokenPair> for TokenPairDto {
                         refresh: value.refresh_token
                 }
         }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(title = "Login")]
+pub struct LoginDto {
+        #[serde(flatten)]
+        pub credentials: UserCredentialsDto,
+        #[serde(default)]
+        pub device: Option<String>
 }
\ No newline at end of file
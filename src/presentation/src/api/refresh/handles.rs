@@ -1,44 +1,68 @@
-use actix_web::{HttpResponse, post, put, web::{Data, Json}};
+use actix_web::{HttpRequest, HttpResponse, delete, http::header, post, put, web::{Data, Json}};
 use di::container::DiContainer;
-use domain::models::token::TokenPair;
+use domain::models::{session::SessionMetadata, token::TokenPair};
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 
-use crate::api::{refresh::{dto::TokenPairDto, types::TokenResponse}, user::dto::UserCredentialsDto};
+use crate::api::refresh::{dto::{LoginDto, TokenPairDto}, types::TokenResponse};
 
-use super::super::error::Result;
+use super::super::error::{BadRequest, InternalServerError, NotFound, Result, Unauthorized, UnprocessableEntity};
 
 pub fn token_app_config(cfg: &mut ServiceConfig) {
         cfg
         .service(scope::scope("/login")
                 .service(create_refresh)
                 .service(update_refresh)
+                .service(delete_refresh)
         );
 }
 
-#[utoipa::path]
+fn session_metadata(req: &HttpRequest, device: Option<String>) -> SessionMetadata {
+        SessionMetadata {
+                device,
+                user_agent: req.headers().get(header::USER_AGENT).and_then(|value| value.to_str().ok()).map(ToString::to_string),
+                ip: req.peer_addr().map(|addr| addr.ip().to_string())
+        }
+}
+
+#[utoipa::path(responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
 #[post("")]
-async fn create_refresh(container: Data<DiContainer>, body: Json<UserCredentialsDto>) -> Result<HttpResponse> {
-        let credentials = body.into_inner().try_into()?;
+async fn create_refresh(container: Data<DiContainer>, body: Json<LoginDto>, req: HttpRequest) -> Result<HttpResponse> {
+        let body = body.into_inner();
+        let credentials = body.credentials.try_into()?;
+        let metadata = session_metadata(&req, body.device);
         let user_service = container.create_user_service();
-        let refresh_service = container.create_refresh_service();
+        let session_service = container.create_session_service();
 
         let user = user_service.get_by_login(credentials).await?;
-        let tokens = refresh_service.create(user.id, user.role).await?;
+        let tokens = session_service.create(user.id, user.role, metadata).await?;
 
         let response_body = TokenResponse::from(tokens);
         let response = HttpResponse::Created().json(response_body);
         Ok(response)
 }
 
-#[utoipa::path]
+#[utoipa::path(responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 500, response = InternalServerError)))]
 #[put("")]
-async fn update_refresh(container: Data<DiContainer>, body: Json<TokenPairDto>) -> Result<HttpResponse> {
+async fn update_refresh(container: Data<DiContainer>, body: Json<TokenPairDto>, req: HttpRequest) -> Result<HttpResponse> {
         let tokens = TokenPair::from(body.into_inner());
-        let refresh_service = container.create_refresh_service();
+        let metadata = session_metadata(&req, None);
+        let session_service = container.create_session_service();
 
-        let tokens = refresh_service.update(tokens.refresh_token).await?;
+        let tokens = session_service.refresh(tokens.refresh_token, metadata).await?;
 
         let response_body = TokenResponse::from(tokens);
         let response = HttpResponse::Created().json(response_body);
         Ok(response)
+}
+
+#[utoipa::path(responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
+#[delete("")]
+async fn delete_refresh(container: Data<DiContainer>, body: Json<TokenPairDto>) -> Result<HttpResponse> {
+        let tokens = TokenPair::from(body.into_inner());
+        let session_service = container.create_session_service();
+
+        session_service.logout(tokens.refresh_token).await?;
+
+        let response = HttpResponse::NoContent().finish();
+        Ok(response)
 }
\ No newline at end of file
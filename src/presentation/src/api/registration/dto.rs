@@ -0,0 +1,28 @@
+use domain::models::registration::RegistrationModel;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, TimestampSeconds};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(title = "Registration")]
+pub struct RegistrationDto {
+        pub user_id: i64,
+        pub event_id: i64,
+        #[serde_as(as = "TimestampSeconds")]
+        pub created_at: PrimitiveDateTime,
+        #[serde_as(as = "TimestampSeconds")]
+        pub updated_at: PrimitiveDateTime
+}
+
+impl From<RegistrationModel> for RegistrationDto {
+        fn from(value: RegistrationModel) -> Self {
+                Self {
+                        user_id: value.user_id as i64,
+                        event_id: value.event_id as i64,
+                        created_at: value.created_at,
+                        updated_at: value.updated_at
+                }
+        }
+}
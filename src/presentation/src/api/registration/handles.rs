@@ -0,0 +1,103 @@
+use actix_web::{HttpRequest, HttpResponse, delete, get, post, web::{Data, Path, Query}};
+use di::container::DiContainer;
+use domain::models::{registration::RegistrationId, user::UserRole};
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use actix_web_grants::protect;
+
+use crate::api::{HandlerError, authentication::ClaimsExtractor, event::types::EventIdParam};
+
+use super::types::{ListRegistrationsQuery, RegistrationIdParam, RegistrationResponse, RegistrationVecResponse};
+
+use super::super::error::{BadRequest, Conflict, Forbidden, InternalServerError, NotFound, Result, Unauthorized, UnprocessableEntity, forbidden};
+
+pub fn registration_app_config(cfg: &mut ServiceConfig) {
+        cfg
+        .service(scope::scope("/{event_id}/registrations")
+                .service(get_registration)
+                .service(list_registrations)
+                .service(create_registration)
+                .service(delete_registration)
+        );
+}
+
+#[utoipa::path(params(RegistrationIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
+#[get("/{user_id}")]
+async fn get_registration(container: Data<DiContainer>, path: Path<RegistrationIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let registration_id: RegistrationId = path.into_inner().try_into()?;
+        let claims = claims.into_inner();
+        let event_service = container.create_event_service();
+        let registration_service = container.create_registration_service();
+
+        if claims.role != UserRole::Admin && claims.sub != registration_id.user_id {
+                let event = event_service.get(registration_id.event_id).await?;
+
+                if claims.sub != event.organizer_id {
+                        return Err(HandlerError::IdMismatch);
+                }
+        }
+
+        let registration = registration_service.get(registration_id).await?;
+
+        let response_body = RegistrationResponse::from(registration);
+        let response = HttpResponse::Ok().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(RegistrationIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 409, response = Conflict), (status = 500, response = InternalServerError)))]
+#[post("/{user_id}")]
+async fn create_registration(container: Data<DiContainer>, path: Path<RegistrationIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let registration_id: RegistrationId = path.into_inner().try_into()?;
+
+        if claims.into_inner().sub != registration_id.user_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let registration_service = container.create_registration_service();
+
+        let registration = registration_service.create(registration_id).await?;
+
+        let response_body = RegistrationResponse::from(registration);
+        let response = HttpResponse::Created().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(RegistrationIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
+#[delete("/{user_id}")]
+async fn delete_registration(container: Data<DiContainer>, path: Path<RegistrationIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let registration_id: RegistrationId = path.into_inner().try_into()?;
+
+        if claims.into_inner().sub != registration_id.user_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let registration_service = container.create_registration_service();
+
+        let registration = registration_service.delete(registration_id).await?;
+
+        let response_body = RegistrationResponse::from(registration);
+        let response = HttpResponse::NoContent().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(EventIdParam, ListRegistrationsQuery), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
+#[get("")]
+#[protect(any("UserRole::Organizer", "UserRole::Admin"), ty = "UserRole", error = forbidden)]
+async fn list_registrations(container: Data<DiContainer>, path: Path<EventIdParam>, query: Query<ListRegistrationsQuery>, claims: ClaimsExtractor, req: HttpRequest) -> Result<HttpResponse> {
+        let event_id = path.into_inner().try_into()?;
+        let query = query.into_inner();
+        let claims = claims.into_inner();
+        let event_service = container.create_event_service();
+        let registration_service = container.create_registration_service();
+
+        let event = event_service.get(event_id).await?;
+
+        if claims.role != UserRole::Admin && claims.sub != event.organizer_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let registrations = registration_service.list(event_id, query.offset.try_into()?, &query.filter, &query.order_by).await?;
+
+        let response_body = RegistrationVecResponse::new(registrations, &req);
+        let response = HttpResponse::Ok().json(response_body);
+        Ok(response)
+}
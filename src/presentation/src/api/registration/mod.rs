@@ -0,0 +1,3 @@
+pub mod dto;
+pub mod types;
+pub mod handles;
\ No newline at end of file
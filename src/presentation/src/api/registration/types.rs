@@ -0,0 +1,71 @@
+use actix_web::HttpRequest;
+use domain::models::{registration::{RegistrationFilter, RegistrationId, RegistrationModel, RegistrationOrder}, utils::{FilterGroup, Page}};
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::deserialize_default_from_null;
+use serde_with::{serde_as, StringWithSeparator, formats::CommaSeparator};
+use utoipa::{IntoParams, ToResponse};
+
+use super::super::{HandlerError, Result};
+
+use super::super::utils::{OffsetDto, PaginationDto};
+
+use super::dto::RegistrationDto;
+
+#[derive(Debug, Serialize, ToResponse)]
+pub struct RegistrationResponse {
+        pub registration: RegistrationDto
+}
+
+impl From<RegistrationModel> for RegistrationResponse {
+        fn from(value: RegistrationModel) -> Self {
+                Self { registration: value.into() }
+        }
+}
+
+#[derive(Debug, Serialize, ToResponse)]
+pub struct RegistrationVecResponse {
+        pub registrations: Vec<RegistrationDto>,
+        #[serde(flatten)]
+        pub pagination: PaginationDto
+}
+
+impl RegistrationVecResponse {
+        pub fn new(page: Page<RegistrationModel>, req: &HttpRequest) -> Self {
+                Self {
+                        pagination: PaginationDto::new(&page, req),
+                        registrations: page.items.into_iter().map(Into::into).collect()
+                }
+        }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(names("event_id", "user_id"), parameter_in = Path)]
+pub struct RegistrationIdParam(pub i64, pub i64);
+
+impl TryFrom<RegistrationIdParam> for RegistrationId {
+        type Error = HandlerError;
+
+        fn try_from(value: RegistrationIdParam) -> Result<Self> {
+                Ok(RegistrationId {
+                        event_id: value.0.try_into()?,
+                        user_id: value.1.try_into()?
+                })
+        }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(style = Form, parameter_in = Query)]
+pub struct ListRegistrationsQuery {
+        #[param(required = false)]
+        #[serde(flatten, deserialize_with = "deserialize_default_from_null")]
+        pub offset: OffsetDto,
+        #[param(value_type = String)]
+        #[serde(default)]
+        #[serde_as(as = "StringWithSeparator::<CommaSeparator, FilterGroup<RegistrationFilter>>")]
+        pub filter: Vec<FilterGroup<RegistrationFilter>>,
+        #[param(value_type = String)]
+        #[serde(default)]
+        #[serde_as(as = "StringWithSeparator::<CommaSeparator, RegistrationOrder>")]
+        pub order_by: Vec<RegistrationOrder>
+}
@@ -0,0 +1,61 @@
+use domain::models::session::{SecurityEventModel, SessionId, SessionModel};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, TimestampSeconds};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(title = "Session")]
+pub struct SessionDto {
+        pub id: Uuid,
+        pub device: Option<String>,
+        pub user_agent: Option<String>,
+        pub ip: Option<String>,
+        #[serde_as(as = "TimestampSeconds")]
+        pub created_at: PrimitiveDateTime,
+        #[serde_as(as = "TimestampSeconds")]
+        pub last_used_at: PrimitiveDateTime,
+        pub current: bool
+}
+
+impl SessionDto {
+        pub fn new(value: SessionModel, current: Option<SessionId>) -> Self {
+                Self {
+                        current: current == Some(value.id),
+                        id: value.id,
+                        device: value.metadata.device,
+                        user_agent: value.metadata.user_agent,
+                        ip: value.metadata.ip,
+                        created_at: value.created_at,
+                        last_used_at: value.last_used_at
+                }
+        }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(title = "SecurityEvent")]
+pub struct SecurityEventDto {
+        pub id: i64,
+        pub session_id: Option<Uuid>,
+        pub kind: String,
+        pub user_agent: Option<String>,
+        pub ip: Option<String>,
+        #[serde_as(as = "TimestampSeconds")]
+        pub created_at: PrimitiveDateTime
+}
+
+impl From<SecurityEventModel> for SecurityEventDto {
+        fn from(value: SecurityEventModel) -> Self {
+                Self {
+                        id: value.id as i64,
+                        session_id: value.session_id,
+                        kind: value.kind.to_string(),
+                        user_agent: value.user_agent,
+                        ip: value.ip,
+                        created_at: value.created_at
+                }
+        }
+}
\ No newline at end of file
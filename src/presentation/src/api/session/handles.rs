@@ -0,0 +1,91 @@
+use actix_web::{HttpResponse, delete, get, web::{Data, Path}};
+use di::container::DiContainer;
+use domain::models::user::UserId;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::api::{HandlerError, authentication::ClaimsExtractor, user::types::UserIdParam};
+
+use super::types::{SecurityEventVecResponse, SessionIdParam, SessionVecResponse};
+
+use super::super::error::{BadRequest, Forbidden, InternalServerError, NotFound, Result, Unauthorized};
+
+pub fn session_app_config(cfg: &mut ServiceConfig) {
+        cfg
+        .service(scope::scope("/{user_id}/sessions")
+                .service(list_sessions)
+                .service(revoke_session)
+                .service(revoke_sessions)
+        )
+        .service(list_security_events);
+}
+
+#[utoipa::path(params(UserIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 500, response = InternalServerError)))]
+#[get("")]
+async fn list_sessions(container: Data<DiContainer>, path: Path<UserIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let user_id: UserId = path.into_inner().try_into()?;
+        let claims = claims.into_inner();
+
+        if claims.sub != user_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let session_service = container.create_session_service();
+
+        let sessions = session_service.list(user_id).await?;
+
+        let response_body = SessionVecResponse::new(sessions, claims.sid);
+        let response = HttpResponse::Ok().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(SessionIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
+#[delete("/{session_id}")]
+async fn revoke_session(container: Data<DiContainer>, path: Path<SessionIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let (user_id, session_id): (UserId, _) = path.into_inner().try_into()?;
+
+        if claims.into_inner().sub != user_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let session_service = container.create_session_service();
+
+        session_service.revoke(user_id, session_id).await?;
+
+        let response = HttpResponse::NoContent().finish();
+        Ok(response)
+}
+
+#[utoipa::path(params(UserIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 500, response = InternalServerError)))]
+#[delete("")]
+async fn revoke_sessions(container: Data<DiContainer>, path: Path<UserIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let user_id: UserId = path.into_inner().try_into()?;
+
+        if claims.into_inner().sub != user_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let session_service = container.create_session_service();
+
+        session_service.revoke_all(user_id).await?;
+
+        let response = HttpResponse::NoContent().finish();
+        Ok(response)
+}
+
+#[utoipa::path(params(UserIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 500, response = InternalServerError)))]
+#[get("/{user_id}/security-events")]
+async fn list_security_events(container: Data<DiContainer>, path: Path<UserIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let user_id: UserId = path.into_inner().try_into()?;
+
+        if claims.into_inner().sub != user_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let session_service = container.create_session_service();
+
+        let security_events = session_service.list_security_events(user_id).await?;
+
+        let response_body = SecurityEventVecResponse::from(security_events);
+        let response = HttpResponse::Ok().json(response_body);
+        Ok(response)
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use domain::models::session::{SecurityEventModel, SessionId, SessionModel};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToResponse};
+use uuid::Uuid;
+
+use super::super::{HandlerError, Result};
+
+use super::dto::{SecurityEventDto, SessionDto};
+
+#[derive(Debug, Serialize, ToResponse)]
+pub struct SessionVecResponse {
+        pub sessions: Vec<SessionDto>
+}
+
+impl SessionVecResponse {
+        pub fn new(sessions: Vec<SessionModel>, current: Option<SessionId>) -> Self {
+                Self {
+                        sessions: sessions.into_iter().map(|session| SessionDto::new(session, current)).collect()
+                }
+        }
+}
+
+#[derive(Debug, Serialize, ToResponse)]
+pub struct SecurityEventVecResponse {
+        pub security_events: Vec<SecurityEventDto>
+}
+
+impl From<Vec<SecurityEventModel>> for SecurityEventVecResponse {
+        fn from(value: Vec<SecurityEventModel>) -> Self {
+                Self {
+                        security_events: value.into_iter().map(Into::into).collect()
+                }
+        }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(names("user_id", "session_id"), parameter_in = Path)]
+pub struct SessionIdParam(pub i64, pub Uuid);
+
+impl TryFrom<SessionIdParam> for (u64, SessionId) {
+        type Error = HandlerError;
+
+        fn try_from(value: SessionIdParam) -> Result<Self> {
+                Ok((value.0.try_into()?, value.1))
+        }
+}
\ No newline at end of file
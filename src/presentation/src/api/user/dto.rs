@@ -22,7 +22,7 @@ impl TryFrom<UserRoleDto> for UserRole {
         type Error = HandlerError;
 
         fn try_from(value: UserRoleDto) -> Result<Self> {
-                value.role.parse().map_err(|err| HandlerError::Parse(err))
+                value.role.parse().map_err(HandlerError::Parse)
         }
 }
 
@@ -1,4 +1,4 @@
-use actix_web::{HttpResponse, delete, get, patch, post, web::{Data, Json, Path, Query}};
+use actix_web::{HttpRequest, HttpResponse, delete, get, patch, post, web::{Data, Json, Path, Query}};
 use actix_web_httpauth::middleware::HttpAuthentication;
 use di::container::DiContainer;
 use domain::models::user::{UserUpdate, UserRole};
@@ -9,7 +9,7 @@ use crate::api::refresh::handles::token_app_config;
 
 use super::{dto::{UserRoleDto, UserCredentialsDto}, types::{ListUsersQuery, UserIdParam, UserResponse, UserVecResponse}};
 
-use super::super::{authentication::validator, error::Result};
+use super::super::{authentication::validator, error::{BadRequest, Conflict, Forbidden, InternalServerError, NotFound, Result, Unauthorized, UnprocessableEntity, forbidden}, utils::decode_cursor};
 
 pub fn user_app_config(cfg: &mut ServiceConfig) {
         cfg
@@ -18,16 +18,19 @@ pub fn user_app_config(cfg: &mut ServiceConfig) {
                 .service(create_user)
                 .service(list_users)
                 .configure(token_app_config)
+                .configure(super::super::calendar::handles::user_calendar_app_config)
                 .service(scope::scope("")
-                        .wrap(HttpAuthentication::bearer(validator))
+                        .wrap(HttpAuthentication::with_fn(validator))
                         .service(update_user_role)
                         .service(delete_user)
                         .configure(super::super::favorite::handles::favorite_app_config)
+                        .configure(super::super::calendar::handles::calendar_token_app_config)
+                        .configure(super::super::session::handles::session_app_config)
                 )
         );
 }
 
-#[utoipa::path(params(UserIdParam))]
+#[utoipa::path(params(UserIdParam), responses((status = 400, response = BadRequest), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
 #[get("/{user_id}")]
 async fn get_user(container: Data<DiContainer>, path: Path<UserIdParam>) -> Result<HttpResponse> {
         let user_id = path.into_inner().try_into()?;
@@ -40,7 +43,7 @@ async fn get_user(container: Data<DiContainer>, path: Path<UserIdParam>) -> Resu
         Ok(response)
 }
 
-#[utoipa::path]
+#[utoipa::path(responses((status = 400, response = BadRequest), (status = 409, response = Conflict), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
 #[post("")]
 async fn create_user(container: Data<DiContainer>, body: Json<UserCredentialsDto>) -> Result<HttpResponse> {
         let credentials = body.into_inner().try_into()?;
@@ -53,9 +56,9 @@ async fn create_user(container: Data<DiContainer>, body: Json<UserCredentialsDto
         Ok(response)
 }
 
-#[utoipa::path(params(UserIdParam))]
+#[utoipa::path(params(UserIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
 #[delete("/{user_id}")]
-#[protect("UserRole::Admin", ty = "UserRole")]
+#[protect("UserRole::Admin", ty = "UserRole", error = forbidden)]
 async fn delete_user(container: Data<DiContainer>, path: Path<UserIdParam>) -> Result<HttpResponse> {
         let user_id = path.into_inner().try_into()?;
         let user_service = container.create_user_service();
@@ -67,9 +70,9 @@ async fn delete_user(container: Data<DiContainer>, path: Path<UserIdParam>) -> R
         Ok(response)
 }
 
-#[utoipa::path(params(UserIdParam))]
+#[utoipa::path(params(UserIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
 #[patch("/{user_id}")]
-#[protect("UserRole::Admin", ty = "UserRole")]
+#[protect("UserRole::Admin", ty = "UserRole", error = forbidden)]
 async fn update_user_role(container: Data<DiContainer>, path: Path<UserIdParam>, body: Json<UserRoleDto>) -> Result<HttpResponse> {
         let user_id = path.into_inner().try_into()?;
         let user_role = body.into_inner().try_into()?;
@@ -82,15 +85,22 @@ async fn update_user_role(container: Data<DiContainer>, path: Path<UserIdParam>,
         Ok(response)
 }
 
-#[utoipa::path(params(ListUsersQuery))]
+#[utoipa::path(params(ListUsersQuery), responses((status = 400, response = BadRequest), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
 #[get("")]
-async fn list_users(container: Data<DiContainer>, query: Query<ListUsersQuery>) -> Result<HttpResponse> {
+async fn list_users(container: Data<DiContainer>, query: Query<ListUsersQuery>, req: HttpRequest) -> Result<HttpResponse> {
         let query = query.into_inner();
         let user_service = container.create_user_service();
 
-        let users = user_service.list(query.offset.try_into()?, &query.filter, &query.order_by).await?;
-
-        let response_body = UserVecResponse::from(users);
+        let response_body = match decode_cursor(query.mode, query.cursor.as_deref())? {
+                Some(cursor) => {
+                        let users = user_service.list_after(query.offset.limit.try_into()?, cursor.as_ref(), &query.filter, &query.order_by).await?;
+                        UserVecResponse::from_cursor(users, &req)
+                },
+                None => {
+                        let users = user_service.list(query.offset.try_into()?, &query.filter, &query.order_by).await?;
+                        UserVecResponse::new(users, &req)
+                }
+        };
         let response = HttpResponse::Ok().json(response_body);
         Ok(response)
 }
\ No newline at end of file
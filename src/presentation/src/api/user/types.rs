@@ -1,4 +1,5 @@
-use domain::models::user::{UserFilter, UserId, UserModel, UserOrder};
+use actix_web::HttpRequest;
+use domain::models::{cursor::CursorPage, user::{UserFilter, UserId, UserModel, UserOrder}, utils::{FilterGroup, Page}};
 use serde::{Deserialize, Serialize};
 use serde_aux::prelude::deserialize_default_from_null;
 use serde_with::{serde_as, StringWithSeparator, formats::CommaSeparator};
@@ -6,7 +7,7 @@ use utoipa::{IntoParams, ToResponse};
 
 use super::super::{HandlerError, Result};
 
-use super::super::utils::OffsetDto;
+use super::super::utils::{CursorPaginationDto, OffsetDto, PaginationDto, PaginationMode, PaginationModeDto};
 
 use super::dto::UserDto;
 
@@ -23,13 +24,24 @@ impl From<UserModel> for UserResponse {
 
 #[derive(Debug, Serialize, ToResponse)]
 pub struct UserVecResponse {
-        pub users: Vec<UserDto>
+        pub users: Vec<UserDto>,
+        #[serde(flatten)]
+        pub pagination: PaginationModeDto
 }
 
-impl From<Vec<UserModel>> for UserVecResponse {
-        fn from(value: Vec<UserModel>) -> Self {
+impl UserVecResponse {
+        pub fn new(page: Page<UserModel>, req: &HttpRequest) -> Self {
                 Self {
-                        users: value.into_iter().map(Into::into).collect()
+                        pagination: PaginationModeDto::Page(PaginationDto::new(&page, req)),
+                        users: page.items.into_iter().map(Into::into).collect()
+                }
+        }
+
+        pub fn from_cursor(page: CursorPage<UserModel>, req: &HttpRequest) -> Self {
+                let (items, pagination) = CursorPaginationDto::new(page, req);
+                Self {
+                        pagination: PaginationModeDto::Cursor(pagination),
+                        users: items.into_iter().map(Into::into).collect()
                 }
         }
 }
@@ -55,10 +67,16 @@ pub struct ListUsersQuery {
         pub offset: OffsetDto,
         #[param(value_type = String)]
         #[serde(default)]
-        #[serde_as(as = "StringWithSeparator::<CommaSeparator, UserFilter>")]
-        pub filter: Vec<UserFilter>,
+        #[serde_as(as = "StringWithSeparator::<CommaSeparator, FilterGroup<UserFilter>>")]
+        pub filter: Vec<FilterGroup<UserFilter>>,
         #[param(value_type = String)]
         #[serde(default)]
         #[serde_as(as = "StringWithSeparator::<CommaSeparator, UserOrder>")]
-        pub order_by: Vec<UserOrder>
+        pub order_by: Vec<UserOrder>,
+        #[param(inline)]
+        #[serde(default)]
+        pub mode: PaginationMode,
+        #[param(required = false)]
+        #[serde(default)]
+        pub cursor: Option<String>
 }
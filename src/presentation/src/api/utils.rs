@@ -1,5 +1,6 @@
-use domain::models::utils::Offset;
-use serde::Deserialize;
+use actix_web::HttpRequest;
+use domain::models::{cursor::{Cursor, CursorPage}, utils::{Offset, Page}};
+use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use utoipa::ToSchema;
 
@@ -7,6 +8,7 @@ use super::{Result, HandlerError};
 
 #[serde_as]
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(default)]
 pub struct OffsetDto {
         #[serde_as(as = "DisplayFromStr")]
         pub page: i32,
@@ -33,3 +35,81 @@ impl Default for OffsetDto {
                 }
         }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct PaginationDto {
+        pub page: u32,
+        pub limit: u32,
+        pub total: u64,
+        pub total_pages: u64,
+        pub next: Option<String>,
+        pub prev: Option<String>
+}
+
+impl PaginationDto {
+        pub fn new<T>(page: &Page<T>, req: &HttpRequest) -> Self {
+                Self {
+                        page: page.offset.page,
+                        limit: page.offset.limit,
+                        total: page.total,
+                        total_pages: page.total_pages(),
+                        next: page.has_next().then(|| link(req, "page", &(page.offset.page + 1).to_string())),
+                        prev: page.has_prev().then(|| link(req, "page", &(page.offset.page - 1).to_string()))
+                }
+        }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct CursorPaginationDto {
+        pub limit: u32,
+        pub next_cursor: Option<String>,
+        pub next: Option<String>
+}
+
+impl CursorPaginationDto {
+        pub fn new<T>(page: CursorPage<T>, req: &HttpRequest) -> (Vec<T>, Self) {
+                let next_cursor = page.next.map(Cursor::encode);
+
+                let pagination = Self {
+                        limit: page.limit,
+                        next: next_cursor.as_deref().map(|cursor| link(req, "cursor", cursor)),
+                        next_cursor
+                };
+
+                (page.items, pagination)
+        }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum PaginationModeDto {
+        Page(PaginationDto),
+        Cursor(CursorPaginationDto)
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PaginationMode {
+        #[default]
+        Page,
+        Cursor
+}
+
+pub fn decode_cursor(mode: PaginationMode, cursor: Option<&str>) -> Result<Option<Option<Cursor>>> {
+        match (mode, cursor.map(str::trim)) {
+                (PaginationMode::Page, None) => Ok(None),
+                (_, None | Some("")) => Ok(Some(None)),
+                (_, Some(cursor)) => Ok(Some(Some(Cursor::decode_from(cursor)?)))
+        }
+}
+
+fn link(req: &HttpRequest, key: &str, value: &str) -> String {
+        let prefix = format!("{key}=");
+        let mut query: Vec<&str> = req.query_string()
+                .split('&')
+                .filter(|pair| !pair.is_empty() && !pair.starts_with(&prefix))
+                .collect();
+        let pair = format!("{prefix}{value}");
+        query.push(&pair);
+        format!("{}?{}", req.path(), query.join("&"))
+}
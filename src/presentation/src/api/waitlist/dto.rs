@@ -0,0 +1,30 @@
+use domain::models::waitlist::WaitlistModel;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, TimestampSeconds};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(title = "Waitlist")]
+pub struct WaitlistDto {
+        pub user_id: i64,
+        pub event_id: i64,
+        pub position: i64,
+        #[serde_as(as = "TimestampSeconds")]
+        pub created_at: PrimitiveDateTime,
+        #[serde_as(as = "TimestampSeconds")]
+        pub updated_at: PrimitiveDateTime
+}
+
+impl From<WaitlistModel> for WaitlistDto {
+        fn from(value: WaitlistModel) -> Self {
+                Self {
+                        user_id: value.user_id as i64,
+                        event_id: value.event_id as i64,
+                        position: value.position as i64,
+                        created_at: value.created_at,
+                        updated_at: value.updated_at
+                }
+        }
+}
@@ -0,0 +1,103 @@
+use actix_web::{HttpRequest, HttpResponse, delete, get, post, web::{Data, Path, Query}};
+use di::container::DiContainer;
+use domain::models::{user::UserRole, waitlist::WaitlistId};
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use actix_web_grants::protect;
+
+use crate::api::{HandlerError, authentication::ClaimsExtractor, event::types::EventIdParam};
+
+use super::types::{ListWaitlistQuery, WaitlistIdParam, WaitlistResponse, WaitlistVecResponse};
+
+use super::super::error::{BadRequest, Conflict, Forbidden, InternalServerError, NotFound, Result, Unauthorized, UnprocessableEntity, forbidden};
+
+pub fn waitlist_app_config(cfg: &mut ServiceConfig) {
+        cfg
+        .service(scope::scope("/{event_id}/waitlist")
+                .service(get_waitlist)
+                .service(list_waitlist)
+                .service(create_waitlist)
+                .service(delete_waitlist)
+        );
+}
+
+#[utoipa::path(params(WaitlistIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
+#[get("/{user_id}")]
+async fn get_waitlist(container: Data<DiContainer>, path: Path<WaitlistIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let waitlist_id: WaitlistId = path.into_inner().try_into()?;
+        let claims = claims.into_inner();
+        let event_service = container.create_event_service();
+        let waitlist_service = container.create_waitlist_service();
+
+        if claims.role != UserRole::Admin && claims.sub != waitlist_id.user_id {
+                let event = event_service.get(waitlist_id.event_id).await?;
+
+                if claims.sub != event.organizer_id {
+                        return Err(HandlerError::IdMismatch);
+                }
+        }
+
+        let waitlist = waitlist_service.get(waitlist_id).await?;
+
+        let response_body = WaitlistResponse::from(waitlist);
+        let response = HttpResponse::Ok().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(WaitlistIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 409, response = Conflict), (status = 500, response = InternalServerError)))]
+#[post("/{user_id}")]
+async fn create_waitlist(container: Data<DiContainer>, path: Path<WaitlistIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let waitlist_id: WaitlistId = path.into_inner().try_into()?;
+
+        if claims.into_inner().sub != waitlist_id.user_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let waitlist_service = container.create_waitlist_service();
+
+        let waitlist = waitlist_service.create(waitlist_id).await?;
+
+        let response_body = WaitlistResponse::from(waitlist);
+        let response = HttpResponse::Created().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(WaitlistIdParam), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 500, response = InternalServerError)))]
+#[delete("/{user_id}")]
+async fn delete_waitlist(container: Data<DiContainer>, path: Path<WaitlistIdParam>, claims: ClaimsExtractor) -> Result<HttpResponse> {
+        let waitlist_id: WaitlistId = path.into_inner().try_into()?;
+
+        if claims.into_inner().sub != waitlist_id.user_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let waitlist_service = container.create_waitlist_service();
+
+        let waitlist = waitlist_service.delete(waitlist_id).await?;
+
+        let response_body = WaitlistResponse::from(waitlist);
+        let response = HttpResponse::NoContent().json(response_body);
+        Ok(response)
+}
+
+#[utoipa::path(params(EventIdParam, ListWaitlistQuery), responses((status = 400, response = BadRequest), (status = 401, response = Unauthorized), (status = 403, response = Forbidden), (status = 404, response = NotFound), (status = 422, response = UnprocessableEntity), (status = 500, response = InternalServerError)))]
+#[get("")]
+#[protect(any("UserRole::Organizer", "UserRole::Admin"), ty = "UserRole", error = forbidden)]
+async fn list_waitlist(container: Data<DiContainer>, path: Path<EventIdParam>, query: Query<ListWaitlistQuery>, claims: ClaimsExtractor, req: HttpRequest) -> Result<HttpResponse> {
+        let event_id = path.into_inner().try_into()?;
+        let query = query.into_inner();
+        let claims = claims.into_inner();
+        let event_service = container.create_event_service();
+        let waitlist_service = container.create_waitlist_service();
+
+        let event = event_service.get(event_id).await?;
+
+        if claims.role != UserRole::Admin && claims.sub != event.organizer_id {
+                return Err(HandlerError::IdMismatch);
+        }
+
+        let waitlist = waitlist_service.list(event_id, query.offset.try_into()?).await?;
+
+        let response_body = WaitlistVecResponse::new(waitlist, &req);
+        let response = HttpResponse::Ok().json(response_body);
+        Ok(response)
+}
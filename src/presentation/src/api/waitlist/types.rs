@@ -0,0 +1,61 @@
+use actix_web::HttpRequest;
+use domain::models::{utils::Page, waitlist::{WaitlistId, WaitlistModel}};
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::deserialize_default_from_null;
+use utoipa::{IntoParams, ToResponse};
+
+use super::super::{HandlerError, Result};
+
+use super::super::utils::{OffsetDto, PaginationDto};
+
+use super::dto::WaitlistDto;
+
+#[derive(Debug, Serialize, ToResponse)]
+pub struct WaitlistResponse {
+        pub waitlist: WaitlistDto
+}
+
+impl From<WaitlistModel> for WaitlistResponse {
+        fn from(value: WaitlistModel) -> Self {
+                Self { waitlist: value.into() }
+        }
+}
+
+#[derive(Debug, Serialize, ToResponse)]
+pub struct WaitlistVecResponse {
+        pub waitlist: Vec<WaitlistDto>,
+        #[serde(flatten)]
+        pub pagination: PaginationDto
+}
+
+impl WaitlistVecResponse {
+        pub fn new(page: Page<WaitlistModel>, req: &HttpRequest) -> Self {
+                Self {
+                        pagination: PaginationDto::new(&page, req),
+                        waitlist: page.items.into_iter().map(Into::into).collect()
+                }
+        }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(names("event_id", "user_id"), parameter_in = Path)]
+pub struct WaitlistIdParam(pub i64, pub i64);
+
+impl TryFrom<WaitlistIdParam> for WaitlistId {
+        type Error = HandlerError;
+
+        fn try_from(value: WaitlistIdParam) -> Result<Self> {
+                Ok(WaitlistId {
+                        event_id: value.0.try_into()?,
+                        user_id: value.1.try_into()?
+                })
+        }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(style = Form, parameter_in = Query)]
+pub struct ListWaitlistQuery {
+        #[param(required = false)]
+        #[serde(flatten, deserialize_with = "deserialize_default_from_null")]
+        pub offset: OffsetDto
+}
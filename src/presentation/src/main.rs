@@ -1,21 +1,29 @@
 mod api;
+#[cfg(test)]
+mod tests;
 
-use std::{net::SocketAddr, sync::LazyLock};
+use std::{net::SocketAddr, process::ExitCode};
 
-use actix_web::{App, HttpServer, middleware, web::Data};
+use actix_web::{App, HttpServer, middleware, web::{Data, JsonConfig, PathConfig, QueryConfig}};
 use utoipa_actix_web::{AppExt, scope, service_config::ServiceConfig};
 use utoipa::{Modify, OpenApi, openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme}};
 use utoipa_swagger_ui::SwaggerUi;
 
 use di::container::DiContainer;
-use api::{event, user, utils::OffsetDto};
+use domain::{config::{Config, ConfigError}, error::DomainError, models::token};
+use api::{category, error::{self, BadRequest, Conflict, Forbidden, InternalServerError, NotFound, ProblemDetails, Unauthorized, UnprocessableEntity}, event, jwks, user, utils::OffsetDto};
 
 fn app_config(cfg: &mut ServiceConfig) {
         cfg
         .service(scope::scope("/api/v1")
+                .app_data(JsonConfig::default().error_handler(error::json_error_handler))
+                .app_data(QueryConfig::default().error_handler(error::query_error_handler))
+                .app_data(PathConfig::default().error_handler(error::path_error_handler))
                 .configure(user::handles::user_app_config)
                 .configure(event::handles::event_app_config)
-        );
+                .configure(category::handles::category_app_config)
+        )
+        .configure(jwks::handles::jwks_app_config);
 }
 
 fn openapi_service_factory(api: utoipa::openapi::OpenApi) -> SwaggerUi {
@@ -23,23 +31,12 @@ fn openapi_service_factory(api: utoipa::openapi::OpenApi) -> SwaggerUi {
                 .url("/api/openapi.json", api)
 }
 
-static SERVER_WORKERS: LazyLock<usize> = LazyLock::new(|| {
-        dotenvy::var("SERVER_WORKERS")
-                .expect("SERVER_WORKERS var should be set")
-                .parse()
-                .expect("SERVER_WORKERS should be a number greater than 0")
-});
-
-static SERVER_ADDRESS: LazyLock<SocketAddr> = LazyLock::new(|| {
-        dotenvy::var("SERVER_ADDRESS")
-                .expect("SERVER_ADDRESS var should be set")
-                .parse()
-                .expect("SERVER_ADDRESS should be valid socket address")
-});
-
 #[derive(OpenApi)]
 #[openapi(
-        components(schemas(OffsetDto)),
+        components(
+                schemas(OffsetDto, ProblemDetails),
+                responses(BadRequest, Unauthorized, Forbidden, NotFound, Conflict, UnprocessableEntity, InternalServerError)
+        ),
         security(("Bearer" = [])),
         modifiers(&SecurityAddon)
 )]
@@ -57,12 +54,27 @@ impl Modify for SecurityAddon {
         }
 }
 
-#[actix_web::main]
-async fn main() {
-        simple_logger::init_with_level(log::Level::Debug).unwrap();
+#[derive(Debug, thiserror::Error)]
+enum StartupError {
+        #[error("{0}")]
+        Config(#[from] ConfigError),
+        #[error("couldn't set up tokens: {0}")]
+        Token(#[from] DomainError),
+        #[error("couldn't bind {0}: {1}")]
+        Bind(SocketAddr, #[source] std::io::Error),
+        #[error("server stopped: {0}")]
+        Server(#[source] std::io::Error)
+}
+
+async fn run() -> Result<(), StartupError> {
         let _ = dotenvy::dotenv();
 
-        let data = Data::new(DiContainer::new().await);
+        let config = Config::load()?;
+
+        token::set_config(config.token.clone())?;
+        token::load_keys(&config.token)?;
+
+        let data = Data::new(DiContainer::new(&config.database).await?);
 
         HttpServer::new(move ||
                 App::new()
@@ -74,10 +86,26 @@ async fn main() {
                         .openapi_service(openapi_service_factory)
                         .into_app()
                 )
-                .workers(*SERVER_WORKERS)
-                .bind(*SERVER_ADDRESS)
-                .unwrap()
+                .workers(config.server.workers)
+                .bind(config.server.address)
+                .map_err(|err| StartupError::Bind(config.server.address, err))?
                 .run()
                 .await
-                .unwrap()
-}
\ No newline at end of file
+                .map_err(StartupError::Server)
+}
+
+#[actix_web::main]
+async fn main() -> ExitCode {
+        if let Err(err) = simple_logger::init_with_level(log::Level::Debug) {
+                eprintln!("couldn't set up the logger: {err}");
+                return ExitCode::FAILURE;
+        }
+
+        match run().await {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(err) => {
+                        log::error!("{err}");
+                        ExitCode::FAILURE
+                }
+        }
+}
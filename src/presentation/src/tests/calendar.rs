@@ -0,0 +1,93 @@
+use actix_web::{http::StatusCode, test::{self, TestRequest}};
+use domain::models::user::{UserRole, UserUpdate};
+use infrastructure::db::provider::Provider;
+use serde_json::json;
+
+use super::{bearer, call, create_event, create_user_with_token, init, login, new_event};
+
+#[actix_web::test]
+async fn event_calendar_includes_overrides_and_cancellations() {
+        let (app, provider) = init().await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let mut event = new_event(organizer_id, "Daily standup", 0, &[]);
+        event["recurrence"] = json!("FREQ=DAILY;COUNT=5");
+        let event_id = create_event(&app, &organizer_token, event).await;
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri(&format!("/api/v1/events/{event_id}/occurrences/1900086400"))
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "cancelled": true }))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri(&format!("/api/v1/events/{event_id}/occurrences/1900172800"))
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "date": 1_900_176_400, "address": "Side street 2" }))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+
+        let res = test::call_service(&app, TestRequest::get()
+                .uri(&format!("/api/v1/events/{event_id}.ics"))
+                .to_request()
+        ).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = String::from_utf8(test::read_body(res).await.to_vec()).expect("calendar should be utf-8");
+
+        assert_eq!(body.matches("BEGIN:VEVENT").count(), 2);
+        assert!(body.contains("DTSTART:20300317T174640Z\r\nCREATED:"));
+        assert!(body.contains("RRULE:FREQ=DAILY;COUNT=5\r\n"));
+        assert!(!body.contains("DTEND"));
+        assert!(body.contains("EXDATE:20300318T174640Z\r\n"));
+        assert!(body.contains("DTSTART:20300319T184640Z\r\nCREATED:"));
+        assert!(body.contains("LOCATION:Side street 2\r\n"));
+        assert!(body.contains("RECURRENCE-ID:20300319T174640Z\r\n"));
+}
+
+#[actix_web::test]
+async fn calendar_token_is_bound_to_its_session_and_role() {
+        let (app, provider) = init().await;
+
+        let (user_id, token) = create_user_with_token(&app, &provider, "subscriber", UserRole::User).await;
+        let feed = |calendar_token: &str| TestRequest::get().uri(&format!("/api/v1/users/{user_id}/calendar.ics?token={calendar_token}"));
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("/api/v1/users/{user_id}/calendar"))
+                .insert_header(bearer(&token))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+        let calendar_token = body["token"].as_str().expect("calendar token should be issued").to_string();
+
+        let res = test::call_service(&app, feed(&calendar_token).to_request()).await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        provider.provide_user_repository()
+                .update(user_id, UserUpdate::Role(UserRole::Organizer))
+                .await
+                .expect("role should be updated");
+        let (status, body) = call(&app, feed(&calendar_token)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "revoked_token");
+
+        let (token, _) = login(&app, "subscriber").await;
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("/api/v1/users/{user_id}/calendar"))
+                .insert_header(bearer(&token))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+        let calendar_token = body["token"].as_str().expect("calendar token should be issued").to_string();
+
+        let res = test::call_service(&app, feed(&calendar_token).to_request()).await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri(&format!("/api/v1/users/{user_id}/sessions"))
+                .insert_header(bearer(&token))
+        ).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, body) = call(&app, feed(&calendar_token)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "revoked_token");
+}
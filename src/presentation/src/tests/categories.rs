@@ -0,0 +1,101 @@
+use actix_web::{http::StatusCode, test::TestRequest};
+use domain::models::user::UserRole;
+use serde_json::{Value, json};
+
+use super::{bearer, call, create_event, create_user_with_token, init, new_event};
+
+fn names(body: &Value) -> Vec<String> {
+        body["categories"]
+                .as_array()
+                .expect("categories should be an array")
+                .iter()
+                .map(|category| category["name"].as_str().unwrap().to_string())
+                .collect()
+}
+
+#[actix_web::test]
+async fn categories_are_managed_by_admin() {
+        let (app, provider) = init().await;
+
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+        let (_, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/categories")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(names(&body).contains(&"music".to_string()));
+        assert!(!names(&body).contains(&"hackathon".to_string()));
+
+        let (status, _) = call(&app, TestRequest::post()
+                .uri("/api/v1/categories")
+                .set_json(json!({ "name": "hackathon" }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, _) = call(&app, TestRequest::post()
+                .uri("/api/v1/categories")
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "name": "hackathon" }))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/categories")
+                .insert_header(bearer(&admin_token))
+                .set_json(json!({ "name": " Hackathon " }))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+        assert_eq!(body["category"]["name"], "hackathon");
+
+        let (status, _) = call(&app, TestRequest::post()
+                .uri("/api/v1/categories")
+                .insert_header(bearer(&admin_token))
+                .set_json(json!({ "name": "hackathon" }))
+        ).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/categories")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(names(&body).contains(&"hackathon".to_string()));
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri("/api/v1/categories/hackathon")
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri("/api/v1/categories/hackathon")
+                .insert_header(bearer(&admin_token))
+        ).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri("/api/v1/categories/hackathon")
+                .insert_header(bearer(&admin_token))
+        ).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn event_category_must_exist() {
+        let (app, provider) = init().await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+
+        let mut event = new_event(organizer_id, "Hack night", 0, &[]);
+        event["category"] = json!("hackathon");
+        let (status, _) = call(&app, TestRequest::post()
+                .uri("/api/v1/events")
+                .insert_header(bearer(&organizer_token))
+                .set_json(event)
+        ).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let mut event = new_event(organizer_id, "Jazz night", 0, &[]);
+        event["category"] = json!("music");
+        let id = create_event(&app, &organizer_token, event).await;
+
+        let (status, body) = call(&app, TestRequest::get().uri(&format!("/api/v1/events/{id}"))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["event"]["category"], "music");
+}
@@ -0,0 +1,428 @@
+use actix_web::{http::StatusCode, test::{self, TestRequest}};
+use domain::models::user::UserRole;
+use infrastructure::db::provider::Provider;
+use serde_json::{Value, json};
+
+use super::{backend_tests, bearer, call, create_event, create_user_with_token, init_with, new_event};
+
+fn titles(body: &Value, key: &str) -> Vec<String> {
+        body[key].as_array()
+                .unwrap()
+                .iter()
+                .map(|event| event["title"].as_str().unwrap().to_string())
+                .collect()
+}
+
+async fn create_event_requires_organizer(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (user_id, user_token) = create_user_with_token(&app, &provider, "plain_user", UserRole::User).await;
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+
+        let (status, _) = call(&app, TestRequest::post()
+                .uri("/api/v1/events")
+                .set_json(new_event(organizer_id, "Meetup", 0, &[]))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/events")
+                .insert_header(bearer(&user_token))
+                .set_json(new_event(user_id, "Meetup", 0, &[]))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "forbidden");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/events")
+                .insert_header(bearer(&organizer_token))
+                .set_json(new_event(user_id, "Meetup", 0, &[]))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "forbidden");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/events")
+                .insert_header(bearer(&organizer_token))
+                .set_json(new_event(organizer_id, "Meetup", 10, &["Rust", "rust", " Meetup "]))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["event"]["status"], "OnReview");
+        assert_eq!(body["event"]["tags"], json!(["meetup", "rust"]));
+
+        let id = body["event"]["id"].as_i64().unwrap();
+        let (status, body) = call(&app, TestRequest::get().uri(&format!("/api/v1/events/{id}"))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["event"]["title"], "Meetup");
+}
+
+async fn create_event_validation(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+
+        let mut event = new_event(organizer_id, "Meetup", 0, &[]);
+        event["longitude"] = Value::Null;
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/events")
+                .insert_header(bearer(&organizer_token))
+                .set_json(event)
+        ).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "incomplete_location");
+
+        let mut event = new_event(organizer_id, "Meetup", 0, &[]);
+        event["latitude"] = json!(120.0);
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/events")
+                .insert_header(bearer(&organizer_token))
+                .set_json(event)
+        ).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "invalid_coordinates");
+
+        let (status, _) = call(&app, TestRequest::post()
+                .uri("/api/v1/events")
+                .insert_header(bearer(&organizer_token))
+                .set_json(new_event(organizer_id, "Meetup", -1, &[]))
+        ).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+async fn list_events_filter_order_and_pagination(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+
+        create_event(&app, &organizer_token, new_event(organizer_id, "Rust meetup", 30, &["rust"])).await;
+        create_event(&app, &organizer_token, new_event(organizer_id, "Go meetup", 10, &["go"])).await;
+        create_event(&app, &organizer_token, new_event(organizer_id, "Rust conference", 50, &["rust", "conference"])).await;
+        create_event(&app, &organizer_token, new_event(organizer_id, "Jazz night", 20, &["music"])).await;
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?filter=tag%20eq%20rust&order_by=cost%20desc")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(titles(&body, "events"), ["Rust conference", "Rust meetup"]);
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?filter=cost%20between%2015%20and%2035,title%20contains%20meetup")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(titles(&body, "events"), ["Rust meetup"]);
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?filter=(tag%20eq%20go%20or%20tag%20eq%20music)&order_by=cost")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(titles(&body, "events"), ["Go meetup", "Jazz night"]);
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?page=2&limit=3&order_by=cost")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(titles(&body, "events"), ["Rust conference"]);
+        assert_eq!(body["total"], 4);
+        assert_eq!(body["total_pages"], 2);
+        assert!(body["next"].is_null());
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?mode=cursor&limit=3&order_by=cost%20desc")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(titles(&body, "events"), ["Rust conference", "Rust meetup", "Jazz night"]);
+
+        let cursor = body["next_cursor"].as_str().unwrap().to_string();
+        let (status, body) = call(&app, TestRequest::get().uri(&format!("/api/v1/events?cursor={cursor}&limit=3&order_by=cost%20desc"))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(titles(&body, "events"), ["Go meetup"]);
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?filter=cost%20near%2010")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalid_query");
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?order_by=title%20sideways")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalid_query");
+}
+
+async fn list_events_search_and_near(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+
+        create_event(&app, &organizer_token, new_event(organizer_id, "Rust meetup", 0, &[])).await;
+        let mut far = new_event(organizer_id, "Rust far away", 0, &[]);
+        far["latitude"] = json!(59.93);
+        far["longitude"] = json!(30.31);
+        create_event(&app, &organizer_token, far).await;
+        create_event(&app, &organizer_token, new_event(organizer_id, "Jazz night", 0, &[])).await;
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?q=rust&order_by=rank%20desc,id")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(titles(&body, "events"), ["Rust meetup", "Rust far away"]);
+        assert!(body["events"][0]["rank"].is_number());
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?near=55.75,37.61,10&order_by=distance")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["events"].as_array().unwrap().len(), 2);
+        assert!(body["events"][0]["distance"].as_f64().unwrap() < 1.0);
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?q=rust&near=55.75,37.61,10")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(titles(&body, "events"), ["Rust meetup"]);
+
+        let (status, _) = call(&app, TestRequest::get().uri("/api/v1/events?near=55.75,37.61")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+async fn list_events_filter_by_category_and_tag(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+
+        let mut jazz = new_event(organizer_id, "Jazz night", 0, &["Jazz"]);
+        jazz["category"] = json!("Music");
+        create_event(&app, &organizer_token, jazz).await;
+        let mut rust = new_event(organizer_id, "Rust meetup", 0, &["rust"]);
+        rust["category"] = json!("meetup");
+        create_event(&app, &organizer_token, rust).await;
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?filter=category%20eq%20music,tag%20eq%20jazz")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(titles(&body, "events"), ["Jazz night"]);
+        assert_eq!(body["events"][0]["category"], "music");
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?filter=category%20eq%20%20Meetup%20,tag%20eq%20RUST")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(titles(&body, "events"), ["Rust meetup"]);
+}
+
+async fn update_event_status_requires_admin(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+
+        let id = create_event(&app, &organizer_token, new_event(organizer_id, "Meetup", 0, &[])).await;
+        let uri = format!("/api/v1/events/{id}/status");
+
+        let (status, _) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "status": "Approved" }))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&admin_token))
+                .set_json(json!({ "status": "Approved" }))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["event"]["status"], "Approved");
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&admin_token))
+                .set_json(json!({ "status": "Draft" }))
+        ).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "illegal_transition");
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/events/{id}/history"))
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body["total"], 1);
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/events?filter=status%20eq%20Approved")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(titles(&body, "events"), ["Meetup"]);
+}
+
+async fn legacy_status_patch_is_still_accepted(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+
+        let id = create_event(&app, &organizer_token, new_event(organizer_id, "Meetup", 0, &[])).await;
+        let uri = format!("/api/v1/events/{id}");
+
+        let (status, _) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "status": "Approved" }))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&admin_token))
+                .set_json(json!({ "status": "Approved", "title": "Renamed" }))
+        ).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "mixed_update");
+
+        let res = test::call_service(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&admin_token))
+                .set_json(json!({ "status": "Approved" }))
+                .to_request()
+        ).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get("Deprecation").unwrap(), "true");
+
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["event"]["status"], "Approved");
+}
+
+async fn material_change_sends_approved_event_back_to_review(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+
+        let id = create_event(&app, &organizer_token, new_event(organizer_id, "Meetup", 10, &[])).await;
+        let (status, _) = call(&app, TestRequest::patch()
+                .uri(&format!("/api/v1/events/{id}/status"))
+                .insert_header(bearer(&admin_token))
+                .set_json(json!({ "status": "Approved" }))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let uri = format!("/api/v1/events/{id}");
+        for (changes, expected) in [
+                (json!({ "title": "Meetup", "cost": 10 }), "Approved"),
+                (json!({ "capacity": 50 }), "Approved"),
+                (json!({ "cost": 20 }), "OnReview")
+        ] {
+                let (status, body) = call(&app, TestRequest::patch()
+                        .uri(&uri)
+                        .insert_header(bearer(&organizer_token))
+                        .set_json(&changes)
+                ).await;
+                assert_eq!(status, StatusCode::OK, "{body}");
+                assert_eq!(body["event"]["status"], expected, "{changes}");
+        }
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/events/{id}/history"))
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        let history: Vec<_> = body["history"].as_array().unwrap().iter().map(|change| (change["from"].as_str().unwrap(), change["to"].as_str().unwrap())).collect();
+        assert_eq!(history, [("OnReview", "Approved"), ("Approved", "OnReview")]);
+}
+
+async fn status_history_records_every_transition(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (admin_id, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+        let (_, user_token) = create_user_with_token(&app, &provider, "plain_user", UserRole::User).await;
+        let (_, other_token) = create_user_with_token(&app, &provider, "other_organizer", UserRole::Organizer).await;
+
+        let id = create_event(&app, &organizer_token, new_event(organizer_id, "Meetup", 0, &[])).await;
+        let uri = format!("/api/v1/events/{id}");
+
+        for next in ["Approved", "Postponed", "Approved", "Completed"] {
+                let (status, body) = call(&app, TestRequest::patch()
+                        .uri(&format!("{uri}/status"))
+                        .insert_header(bearer(&admin_token))
+                        .set_json(json!({ "status": next }))
+                ).await;
+                assert_eq!(status, StatusCode::OK, "{body}");
+                assert_eq!(body["event"]["status"], next);
+        }
+
+        for next in ["Cancelled", "OnReview", "Completed"] {
+                let (status, body) = call(&app, TestRequest::patch()
+                        .uri(&format!("{uri}/status"))
+                        .insert_header(bearer(&admin_token))
+                        .set_json(json!({ "status": next }))
+                ).await;
+                assert_eq!(status, StatusCode::CONFLICT, "{next}");
+                assert_eq!(body["code"], "illegal_transition");
+        }
+
+        let (status, _) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}/history"))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}/history"))
+                .insert_header(bearer(&other_token))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = call(&app, TestRequest::get()
+                .uri("/api/v1/events/999999/history")
+                .insert_header(bearer(&admin_token))
+        ).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}/history"))
+                .insert_header(bearer(&admin_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body["total"], 4);
+        let history: Vec<_> = body["history"].as_array().unwrap().iter().map(|change| (change["from"].as_str().unwrap(), change["to"].as_str().unwrap())).collect();
+        assert_eq!(history, [("OnReview", "Approved"), ("Approved", "Postponed"), ("Postponed", "Approved"), ("Approved", "Completed")]);
+        assert!(body["history"].as_array().unwrap().iter().all(|change| change["changed_by"] == admin_id));
+}
+
+async fn update_and_delete_event_require_ownership(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (owner_id, owner_token) = create_user_with_token(&app, &provider, "owner_organizer", UserRole::Organizer).await;
+        let (_, other_token) = create_user_with_token(&app, &provider, "other_organizer", UserRole::Organizer).await;
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+
+        let id = create_event(&app, &owner_token, new_event(owner_id, "Meetup", 0, &[])).await;
+        let uri = format!("/api/v1/events/{id}");
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&other_token))
+                .set_json(json!({ "title": "Hijacked" }))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "forbidden");
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&owner_token))
+                .set_json(json!({ "title": "Renamed" }))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["event"]["title"], "Renamed");
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&admin_token))
+                .set_json(json!({}))
+        ).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "empty_update");
+
+        let (status, _) = call(&app, TestRequest::delete().uri(&uri).insert_header(bearer(&other_token))).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = call(&app, TestRequest::delete().uri(&uri).insert_header(bearer(&owner_token))).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, _) = call(&app, TestRequest::get().uri(&uri)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = call(&app, TestRequest::delete().uri(&uri).insert_header(bearer(&admin_token))).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+backend_tests!(
+        create_event_requires_organizer,
+        create_event_validation,
+        list_events_filter_order_and_pagination,
+        list_events_search_and_near,
+        list_events_filter_by_category_and_tag,
+        update_event_status_requires_admin,
+        legacy_status_patch_is_still_accepted,
+        material_change_sends_approved_event_back_to_review,
+        status_history_records_every_transition,
+        update_and_delete_event_require_ownership,
+);
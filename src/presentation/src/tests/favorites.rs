@@ -0,0 +1,150 @@
+use actix_web::{http::StatusCode, test::TestRequest};
+use domain::models::user::UserRole;
+
+use super::{bearer, call, create_event, create_user_with_token, init, new_event};
+
+#[actix_web::test]
+async fn favorite_requires_matching_user() {
+        let (app, provider) = init().await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (user_id, user_token) = create_user_with_token(&app, &provider, "fan_user", UserRole::User).await;
+        let (other_id, _) = create_user_with_token(&app, &provider, "other_user", UserRole::User).await;
+
+        let event_id = create_event(&app, &organizer_token, new_event(organizer_id, "Meetup", 0, &[])).await;
+
+        let (status, _) = call(&app, TestRequest::post().uri(&format!("/api/v1/users/{user_id}/favorite/{event_id}"))).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("/api/v1/users/{other_id}/favorite/{event_id}"))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "forbidden");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("/api/v1/users/{user_id}/favorite/{event_id}"))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["favorite"]["user_id"], user_id);
+        assert_eq!(body["favorite"]["event_id"], event_id);
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/favorite/{event_id}"))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["favorite_event"]["event"]["title"], "Meetup");
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri(&format!("/api/v1/users/{user_id}/favorite/{event_id}"))
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri(&format!("/api/v1/users/{user_id}/favorite/{event_id}"))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, _) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/favorite/{event_id}"))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn favorite_conflicts() {
+        let (app, provider) = init().await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (user_id, user_token) = create_user_with_token(&app, &provider, "fan_user", UserRole::User).await;
+
+        let event_id = create_event(&app, &organizer_token, new_event(organizer_id, "Meetup", 0, &[])).await;
+        let uri = format!("/api/v1/users/{user_id}/favorite/{event_id}");
+
+        let (status, _) = call(&app, TestRequest::post().uri(&uri).insert_header(bearer(&user_token))).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, body) = call(&app, TestRequest::post().uri(&uri).insert_header(bearer(&user_token))).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "already_exists");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("/api/v1/users/{user_id}/favorite/{}", event_id + 100))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "reference_violation");
+}
+
+#[actix_web::test]
+async fn list_favorites_filter_order_and_pagination() {
+        let (app, provider) = init().await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (user_id, user_token) = create_user_with_token(&app, &provider, "fan_user", UserRole::User).await;
+
+        let mut event_ids = Vec::new();
+        for title in ["First", "Second", "Third"] {
+                let event_id = create_event(&app, &organizer_token, new_event(organizer_id, title, 0, &[])).await;
+                let (status, _) = call(&app, TestRequest::post()
+                        .uri(&format!("/api/v1/users/{user_id}/favorite/{event_id}"))
+                        .insert_header(bearer(&user_token))
+                ).await;
+                assert_eq!(status, StatusCode::CREATED);
+                event_ids.push(event_id);
+        }
+        create_event(&app, &organizer_token, new_event(organizer_id, "Ignored", 0, &[])).await;
+
+        let uri = format!("/api/v1/users/{user_id}/favorite");
+
+        let (status, _) = call(&app, TestRequest::get().uri(&uri)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}?order_by=event_id%20desc"))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        let titles: Vec<_> = body["favorite_events"].as_array().unwrap().iter().map(|favorite| favorite["event"]["title"].as_str().unwrap()).collect();
+        assert_eq!(titles, ["Third", "Second", "First"]);
+        assert_eq!(body["total"], 3);
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}?filter=event_id%20eq%20{}", event_ids[1]))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["favorite_events"].as_array().unwrap().len(), 1);
+        assert_eq!(body["favorite_events"][0]["event"]["title"], "Second");
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}?page=2&limit=2&order_by=event_id"))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["favorite_events"].as_array().unwrap().len(), 1);
+        assert_eq!(body["favorite_events"][0]["event"]["title"], "Third");
+        assert_eq!(body["total_pages"], 2);
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}?mode=cursor&limit=2&order_by=event_id"))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["favorite_events"].as_array().unwrap().len(), 2);
+
+        let cursor = body["next_cursor"].as_str().unwrap().to_string();
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}?cursor={cursor}&limit=2&order_by=event_id"))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["favorite_events"][0]["event"]["title"], "Third");
+        assert!(body["next_cursor"].is_null());
+}
@@ -0,0 +1,76 @@
+use actix_web::{http::StatusCode, test::TestRequest};
+use domain::models::{token::Claims, user::UserRole};
+use ed25519_dalek::{SigningKey, pkcs8::EncodePrivateKey};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+
+use super::{PREVIOUS_KID, RETIRED_KID, bearer, call, create_user_with_token, init, setup};
+
+fn sign_with(key: &SigningKey, kid: &str, token: &str) -> String {
+        let claims = Claims::decode_from(token).unwrap();
+        let secret_key = key.to_pkcs8_der().unwrap();
+
+        let mut header = Header::new(Algorithm::EdDSA);
+        header.kid = Some(kid.to_string());
+        jsonwebtoken::encode(&header, &claims, &EncodingKey::from_ed_der(secret_key.as_bytes())).unwrap()
+}
+
+#[actix_web::test]
+async fn jwks_publishes_signing_kid() {
+        let (app, provider) = init().await;
+
+        let (_, access) = create_user_with_token(&app, &provider, "jwks_user", UserRole::User).await;
+        let kid = jsonwebtoken::decode_header(&access).unwrap().kid.expect("token should carry a kid");
+
+        let (status, body) = call(&app, TestRequest::get().uri("/.well-known/jwks.json")).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let keys = body["keys"].as_array().unwrap();
+        let kids: Vec<_> = keys.iter().map(|key| key["kid"].as_str().unwrap()).collect();
+        assert_eq!(kids, [kid.as_str(), PREVIOUS_KID]);
+
+        for key in keys {
+                assert_eq!(key["kty"], "OKP");
+                assert_eq!(key["crv"], "Ed25519");
+                assert_eq!(key["alg"], "EdDSA");
+                assert_eq!(key["use"], "sig");
+                assert!(key.get("d").is_none());
+        }
+}
+
+#[actix_web::test]
+async fn token_signed_by_previous_key_is_accepted() {
+        let (app, provider) = init().await;
+        let keys = setup();
+
+        let (user_id, access) = create_user_with_token(&app, &provider, "jwks_user", UserRole::User).await;
+        let access = sign_with(&keys.previous, PREVIOUS_KID, &access);
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/sessions"))
+                .insert_header(bearer(&access))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+}
+
+#[actix_web::test]
+async fn token_signed_by_retired_or_unknown_key_is_rejected() {
+        let (app, provider) = init().await;
+        let keys = setup();
+
+        let (user_id, access) = create_user_with_token(&app, &provider, "jwks_user", UserRole::User).await;
+        let active_kid = jsonwebtoken::decode_header(&access).unwrap().kid.unwrap();
+
+        let tokens = [
+                sign_with(&keys.retired, RETIRED_KID, &access),
+                sign_with(&keys.previous, "unknown", &access),
+                sign_with(&keys.previous, &active_kid, &access)
+        ];
+
+        for token in tokens {
+                let (status, _) = call(&app, TestRequest::get()
+                        .uri(&format!("/api/v1/users/{user_id}/sessions"))
+                        .insert_header(bearer(&token))
+                ).await;
+                assert_eq!(status, StatusCode::UNAUTHORIZED);
+        }
+}
@@ -0,0 +1,145 @@
+use actix_web::{http::StatusCode, test::TestRequest};
+use domain::models::{token::Claims, user::UserRole};
+use serde_json::json;
+use time::Duration;
+
+use super::{bearer, call, create_user, create_user_with_token, init, login};
+
+#[actix_web::test]
+async fn login_issues_token_pair() {
+        let (app, provider) = init().await;
+
+        let user_id = create_user(&app, &provider, "login_user", UserRole::Organizer).await;
+        let (access, refresh) = login(&app, "login_user").await;
+
+        let access = Claims::decode_from(&access).unwrap();
+        assert!(access.is_access());
+        assert_eq!(access.sub as i64, user_id);
+        assert_eq!(access.role, UserRole::Organizer);
+
+        let refresh = Claims::decode_from(&refresh).unwrap();
+        assert!(refresh.is_refresh());
+        assert_eq!(refresh.sub as i64, user_id);
+}
+
+#[actix_web::test]
+async fn login_rejects_invalid_credentials() {
+        let (app, provider) = init().await;
+
+        create_user(&app, &provider, "login_user", UserRole::User).await;
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "login": "login_user", "password": "wrong password" }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "invalid_credentials");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "login": "missing_user", "password": "password" }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "invalid_credentials");
+
+        let (status, _) = call(&app, TestRequest::post()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "login": "login_user", "password": "short" }))
+        ).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[actix_web::test]
+async fn refresh_rotates_token_pair() {
+        let (app, provider) = init().await;
+
+        let user_id = create_user(&app, &provider, "login_user", UserRole::User).await;
+        let (access, refresh) = login(&app, "login_user").await;
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "access": access, "refresh": refresh }))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let access = body["token_pair"]["access"].as_str().unwrap();
+        let claims = Claims::decode_from(access).unwrap();
+        assert!(claims.is_access());
+        assert_eq!(claims.sub as i64, user_id);
+
+        let (status, _) = call(&app, TestRequest::put()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "access": access, "refresh": "not-a-token" }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[actix_web::test]
+async fn refresh_token_is_not_a_bearer_token() {
+        let (app, provider) = init().await;
+
+        let user_id = create_user(&app, &provider, "login_user", UserRole::Admin).await;
+        let (access, refresh) = login(&app, "login_user").await;
+
+        let uri = format!("/api/v1/users/{user_id}");
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&refresh))
+                .set_json(json!({ "role": "Admin" }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "invalid_token");
+
+        let (status, _) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&access))
+                .set_json(json!({ "role": "Admin" }))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn expired_access_token_is_reported_as_expired() {
+        let (app, provider) = init().await;
+
+        let (user_id, access) = create_user_with_token(&app, &provider, "expired_user", UserRole::User).await;
+        let mut claims = Claims::decode_from(&access).unwrap();
+        claims.iat -= Duration::hours(2);
+        claims.nbf -= Duration::hours(2);
+        claims.exp -= Duration::hours(2);
+        let expired = claims.encode().unwrap();
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/sessions"))
+                .insert_header(bearer(&expired))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "token_expired");
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/sessions"))
+                .insert_header(bearer("not.a.token"))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "invalid_token");
+}
+
+#[actix_web::test]
+async fn missing_bearer_token_is_problem_details() {
+        let (app, provider) = init().await;
+
+        let (user_id, _) = create_user_with_token(&app, &provider, "anonymous_user", UserRole::User).await;
+
+        for header in [None, Some("Basic dXNlcjpwYXNz")] {
+                let mut req = TestRequest::get().uri(&format!("/api/v1/users/{user_id}/sessions"));
+                if let Some(header) = header {
+                        req = req.insert_header(("Authorization", header));
+                }
+
+                let (status, body) = call(&app, req).await;
+                assert_eq!(status, StatusCode::UNAUTHORIZED);
+                assert_eq!(body["code"], "missing_token");
+                assert_eq!(body["status"], 401);
+        }
+}
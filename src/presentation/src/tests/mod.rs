@@ -0,0 +1,235 @@
+mod calendar;
+mod categories;
+mod events;
+mod favorites;
+mod keys;
+mod login;
+mod occurrences;
+mod registrations;
+mod sessions;
+mod users;
+mod waitlist;
+
+use std::sync::OnceLock;
+
+use actix_http::Request;
+use actix_web::{App, body::MessageBody, dev::{Service, ServiceResponse}, http::StatusCode, test::{self, TestRequest}, web::Data};
+use di::container::DiContainer;
+#[cfg(feature = "sqlite")]
+use domain::config::DatabaseConfig;
+use domain::models::{token::{KeySet, set_cursor_key, set_hash_key, set_keys}, user::{UserRole, UserUpdate}};
+use ed25519_dalek::{SigningKey, pkcs8::{EncodePrivateKey, EncodePublicKey, spki::der::pem::LineEnding}};
+use infrastructure::db::provider::{MemoryProvider, Provider};
+#[cfg(feature = "sqlite")]
+use infrastructure::db::provider::SqliteProvider;
+use rand_core::{OsRng, RngCore};
+use serde_json::{Value, json};
+use time::{Duration, OffsetDateTime};
+use utoipa_actix_web::AppExt;
+
+macro_rules! backend_tests {
+        ($($name:ident),* $(,)?) => {
+                mod memory {
+                        $(
+                        #[actix_web::test]
+                        async fn $name() {
+                                super::$name(infrastructure::db::provider::MemoryProvider::new()).await;
+                        }
+                        )*
+                }
+
+                #[cfg(feature = "sqlite")]
+                mod sqlite {
+                        $(
+                        #[actix_web::test]
+                        async fn $name() {
+                                super::$name(crate::tests::sqlite_provider().await).await;
+                        }
+                        )*
+                }
+        };
+}
+
+pub(crate) use backend_tests;
+
+pub(crate) const PREVIOUS_KID: &str = "previous";
+pub(crate) const RETIRED_KID: &str = "retired";
+
+pub(crate) struct TestKeys {
+        pub previous: SigningKey,
+        pub retired: SigningKey
+}
+
+static KEYS: OnceLock<TestKeys> = OnceLock::new();
+
+fn public_pem(key: &SigningKey) -> String {
+        key.verifying_key().to_public_key_pem(LineEnding::LF).expect("generated key should be encodable")
+}
+
+pub(crate) fn setup() -> &'static TestKeys {
+        KEYS.get_or_init(|| {
+                let active = SigningKey::generate(&mut OsRng);
+                let previous = SigningKey::generate(&mut OsRng);
+                let retired = SigningKey::generate(&mut OsRng);
+                let now = OffsetDateTime::now_utc();
+
+                let secret_pem = active.to_pkcs8_pem(LineEnding::LF).expect("generated key should be encodable");
+                let keys = KeySet::from_ed_pem(&secret_pem, &public_pem(&active), None)
+                        .and_then(|keys| keys.with_previous_key(&public_pem(&previous), Some(PREVIOUS_KID.to_string()), now + Duration::days(1)))
+                        .and_then(|keys| keys.with_previous_key(&public_pem(&retired), Some(RETIRED_KID.to_string()), now - Duration::days(1)))
+                        .expect("generated keys should form a key set");
+                set_keys(keys).expect("keys should be set once");
+
+                let mut hash_key = vec![0; 32];
+                OsRng.fill_bytes(&mut hash_key);
+                set_hash_key(hash_key).expect("hash key should be set once");
+
+                let mut cursor_key = vec![0; 32];
+                OsRng.fill_bytes(&mut cursor_key);
+                set_cursor_key(cursor_key).expect("cursor key should be set once");
+
+                TestKeys { previous, retired }
+        })
+}
+
+pub(crate) async fn init() -> (impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = actix_web::Error>, MemoryProvider) {
+        init_with(MemoryProvider::new()).await
+}
+
+pub(crate) async fn init_with<P: Provider + Clone + 'static>(provider: P) -> (impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = actix_web::Error>, P) {
+        setup();
+
+        let app = test::init_service(
+                App::new()
+                        .into_utoipa_app()
+                        .app_data(Data::new(DiContainer::from_provider(provider.clone())))
+                        .configure(crate::app_config)
+                        .into_app()
+        ).await;
+
+        (app, provider)
+}
+
+#[cfg(feature = "sqlite")]
+pub(crate) async fn sqlite_provider() -> SqliteProvider {
+        // every connection to sqlite::memory: opens its own empty database, so the pool keeps exactly one
+        let config = DatabaseConfig {
+                url: "sqlite::memory:".to_string(),
+                min_connections: 1,
+                max_connections: 1,
+                idle_timeout: None,
+                max_lifetime: None,
+                ..DatabaseConfig::default()
+        };
+
+        SqliteProvider::new(&config).await.expect("in-memory sqlite should be set up")
+}
+
+pub(crate) async fn call<S, B>(app: &S, req: TestRequest) -> (StatusCode, Value)
+where
+        S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+        B: MessageBody
+{
+        let res = test::call_service(app, req.to_request()).await;
+        let status = res.status();
+        let body = test::read_body(res).await;
+
+        match body.is_empty() {
+                true => (status, Value::Null),
+                false => (status, serde_json::from_slice(&body).expect("response body should be json"))
+        }
+}
+
+pub(crate) async fn create_user<S, B>(app: &S, provider: &impl Provider, login: &str, role: UserRole) -> i64
+where
+        S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+        B: MessageBody
+{
+        let (status, body) = call(app, TestRequest::post()
+                .uri("/api/v1/users")
+                .set_json(json!({ "login": login, "password": "password" }))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+
+        let id = body["user"]["id"].as_i64().expect("user should have id");
+        if role != UserRole::User {
+                provider.provide_user_repository()
+                        .update(id, UserUpdate::Role(role))
+                        .await
+                        .expect("role should be updated");
+        }
+
+        id
+}
+
+pub(crate) async fn login<S, B>(app: &S, login: &str) -> (String, String)
+where
+        S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+        B: MessageBody
+{
+        let (status, body) = call(app, TestRequest::post()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "login": login, "password": "password" }))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+
+        let tokens = &body["token_pair"];
+        (tokens["access"].as_str().unwrap().to_string(), tokens["refresh"].as_str().unwrap().to_string())
+}
+
+pub(crate) async fn create_user_with_token<S, B>(app: &S, provider: &impl Provider, name: &str, role: UserRole) -> (i64, String)
+where
+        S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+        B: MessageBody
+{
+        let id = create_user(app, provider, name, role).await;
+        let (access, _) = login(app, name).await;
+
+        (id, access)
+}
+
+pub(crate) fn bearer(token: &str) -> (&'static str, String) {
+        ("Authorization", format!("Bearer {token}"))
+}
+
+pub(crate) fn new_event(organizer_id: i64, title: &str, cost: i32, tags: &[&str]) -> Value {
+        json!({
+                "organizer_id": organizer_id,
+                "title": title,
+                "description": format!("{title} description"),
+                "date": 1_900_000_000,
+                "cost": cost,
+                "address": "Main street 1",
+                "tags": tags,
+                "latitude": 55.75,
+                "longitude": 37.61
+        })
+}
+
+pub(crate) async fn create_event<S, B>(app: &S, token: &str, event: Value) -> i64
+where
+        S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+        B: MessageBody
+{
+        let (status, body) = call(app, TestRequest::post()
+                .uri("/api/v1/events")
+                .insert_header(bearer(token))
+                .set_json(event)
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+
+        body["event"]["id"].as_i64().expect("event should have id")
+}
+
+pub(crate) async fn approve_event<S, B>(app: &S, admin_token: &str, event_id: i64)
+where
+        S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+        B: MessageBody
+{
+        let (status, body) = call(app, TestRequest::patch()
+                .uri(&format!("/api/v1/events/{event_id}/status"))
+                .insert_header(bearer(admin_token))
+                .set_json(json!({ "status": "Approved" }))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+}
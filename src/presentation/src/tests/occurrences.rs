@@ -0,0 +1,131 @@
+use actix_web::{http::StatusCode, test::TestRequest};
+use domain::models::user::UserRole;
+use serde_json::{Value, json};
+
+use super::{bearer, call, create_event, create_user_with_token, init, new_event};
+
+const DAY: i64 = 86_400;
+const START: i64 = 1_900_000_000;
+
+fn dates(body: &Value) -> Vec<(i64, i64, bool)> {
+        body["occurrences"]
+                .as_array()
+                .expect("occurrences should be an array")
+                .iter()
+                .map(|occurrence| (
+                        occurrence["original_date"].as_i64().unwrap(),
+                        occurrence["date"].as_i64().unwrap(),
+                        occurrence["cancelled"].as_bool().unwrap()
+                ))
+                .collect()
+}
+
+#[actix_web::test]
+async fn occurrences_expand_recurrence_with_overrides() {
+        let (app, provider) = init().await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (_, other_token) = create_user_with_token(&app, &provider, "other_organizer", UserRole::Organizer).await;
+
+        let mut event = new_event(organizer_id, "Daily standup", 0, &[]);
+        event["recurrence"] = json!("FREQ=DAILY;COUNT=5");
+        let event_id = create_event(&app, &organizer_token, event).await;
+        let single_id = create_event(&app, &organizer_token, new_event(organizer_id, "One-off", 0, &[])).await;
+        let uri = format!("/api/v1/events/{event_id}/occurrences");
+
+        let (status, body) = call(&app, TestRequest::get().uri(&format!("{uri}?from={START}&to={}", START + 10 * DAY))).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(dates(&body), (0..5).map(|day| (START + day * DAY, START + day * DAY, false)).collect::<Vec<_>>());
+
+        let (status, _) = call(&app, TestRequest::put()
+                .uri(&format!("{uri}/{}", START + DAY))
+                .insert_header(bearer(&other_token))
+                .set_json(json!({ "cancelled": true }))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri(&format!("{uri}/{}", START + DAY))
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "cancelled": true }))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body["occurrence"]["cancelled"], true);
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri(&format!("{uri}/{}", START + 2 * DAY))
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "date": START + 2 * DAY + 3600, "address": "Side street 2" }))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+
+        let (status, _) = call(&app, TestRequest::put()
+                .uri(&format!("{uri}/{}", START + 30))
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "cancelled": true }))
+        ).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) = call(&app, TestRequest::get().uri(&format!("{uri}?from={}&to={}", START + DAY, START + 3 * DAY - 1))).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(dates(&body), [(START + DAY, START + DAY, true), (START + 2 * DAY, START + 2 * DAY + 3600, false)]);
+        assert_eq!(body["occurrences"][1]["address"], "Side street 2");
+
+        let (status, body) = call(&app, TestRequest::get().uri(&format!("/api/v1/events/occurrences?from={START}&to={}", START + 2 * DAY - 1))).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        let titles: Vec<_> = body["occurrences"].as_array().unwrap().iter().map(|occurrence| occurrence["event"]["title"].as_str().unwrap()).collect();
+        assert_eq!(titles, ["Daily standup", "One-off", "Daily standup"]);
+
+        let (status, body) = call(&app, TestRequest::get().uri(&format!("/api/v1/events/occurrences?from={START}&to={}&page=2147483647&limit=2147483647", START + 2 * DAY - 1))).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body["occurrences"], json!([]));
+        assert_eq!(body["total"], 3);
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri(&format!("{uri}/{}", START + DAY))
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, body) = call(&app, TestRequest::get().uri(&format!("{uri}?from={}&to={}", START + DAY, START + 2 * DAY - 1))).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(dates(&body), [(START + DAY, START + DAY, false)]);
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri(&format!("/api/v1/events/{single_id}/occurrences/{START}"))
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "cancelled": true }))
+        ).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "not_recurring");
+
+        let (status, _) = call(&app, TestRequest::get().uri(&format!("{uri}?from={}&to={START}", START + DAY))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[actix_web::test]
+async fn finished_recurrences_are_left_out_of_later_windows() {
+        let (app, provider) = init().await;
+
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+
+        let mut event = new_event(organizer_id, "Short series", 0, &[]);
+        event["recurrence"] = json!("FREQ=DAILY;COUNT=2");
+        let event_id = create_event(&app, &organizer_token, event).await;
+        let uri = format!("/api/v1/events/occurrences?from={}&to={}", START + 3 * DAY, START + 4 * DAY - 1);
+
+        let (status, body) = call(&app, TestRequest::get().uri(&uri)).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body["occurrences"], json!([]));
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&format!("/api/v1/events/{event_id}"))
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "date": START + 2 * DAY }))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+
+        let (status, body) = call(&app, TestRequest::get().uri(&uri)).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(dates(&body), [(START + 3 * DAY, START + 3 * DAY, false)]);
+}
@@ -0,0 +1,207 @@
+use actix_web::{http::StatusCode, test::TestRequest};
+use domain::models::user::UserRole;
+use futures_util::future::join_all;
+use infrastructure::db::provider::Provider;
+use serde_json::json;
+
+use super::{approve_event, backend_tests, bearer, call, create_event, create_user_with_token, init_with, new_event};
+
+async fn capacity_patch_respects_registrations_and_promotes_waitlist(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let mut event = new_event(organizer_id, "Meetup", 0, &[]);
+        event["capacity"] = json!(1);
+        let event_id = create_event(&app, &organizer_token, event).await;
+        approve_event(&app, &admin_token, event_id).await;
+        let uri = format!("/api/v1/events/{event_id}");
+
+        let mut attendees = Vec::new();
+        for login in ["first_user", "second_user", "third_user"] {
+                attendees.push(create_user_with_token(&app, &provider, login, UserRole::User).await);
+        }
+
+        let (first_id, first_token) = &attendees[0];
+        let (status, _) = call(&app, TestRequest::post()
+                .uri(&format!("{uri}/registrations/{first_id}"))
+                .insert_header(bearer(first_token))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        for (user_id, token) in &attendees[1..] {
+                let (status, body) = call(&app, TestRequest::post()
+                        .uri(&format!("{uri}/waitlist/{user_id}"))
+                        .insert_header(bearer(token))
+                ).await;
+                assert_eq!(status, StatusCode::CREATED, "{body}");
+        }
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "capacity": 0 }))
+        ).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "capacity_below_registrations");
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "capacity": 2 }))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["event"]["capacity"], 2);
+
+        let (second_id, _) = &attendees[1];
+        let (third_id, _) = &attendees[2];
+        let (status, _) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}/registrations/{second_id}"))
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}/registrations/{third_id}"))
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "capacity": null }))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["event"]["capacity"].is_null());
+
+        let (status, _) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}/registrations/{third_id}"))
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "latitude": null, "longitude": null }))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["event"]["latitude"].is_null());
+        assert!(body["event"]["longitude"].is_null());
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "latitude": null }))
+        ).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "incomplete_location");
+}
+
+async fn registration_requires_approved_event(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (user_id, user_token) = create_user_with_token(&app, &provider, "attendee", UserRole::User).await;
+        let event_id = create_event(&app, &organizer_token, new_event(organizer_id, "Meetup", 0, &[])).await;
+        let uri = format!("/api/v1/events/{event_id}/registrations/{user_id}");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&uri)
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "registration_closed");
+
+        approve_event(&app, &admin_token, event_id).await;
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&uri)
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("/api/v1/events/{}/registrations/{user_id}", event_id + 1))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "not_found");
+}
+
+async fn registrations_are_visible_to_owner_admin_and_attendee(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (_, other_token) = create_user_with_token(&app, &provider, "other_organizer", UserRole::Organizer).await;
+        let (user_id, user_token) = create_user_with_token(&app, &provider, "attendee", UserRole::User).await;
+        let (_, stranger_token) = create_user_with_token(&app, &provider, "stranger", UserRole::User).await;
+        let event_id = create_event(&app, &organizer_token, new_event(organizer_id, "Meetup", 0, &[])).await;
+        approve_event(&app, &admin_token, event_id).await;
+        let uri = format!("/api/v1/events/{event_id}/registrations");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("{uri}/{user_id}"))
+                .insert_header(bearer(&user_token))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+
+        for (token, expected) in [(&organizer_token, StatusCode::OK), (&admin_token, StatusCode::OK), (&other_token, StatusCode::FORBIDDEN), (&user_token, StatusCode::FORBIDDEN)] {
+                let (status, body) = call(&app, TestRequest::get()
+                        .uri(&uri)
+                        .insert_header(bearer(token))
+                ).await;
+                assert_eq!(status, expected, "{body}");
+        }
+
+        for (token, expected) in [(&organizer_token, StatusCode::OK), (&admin_token, StatusCode::OK), (&user_token, StatusCode::OK), (&other_token, StatusCode::FORBIDDEN), (&stranger_token, StatusCode::FORBIDDEN)] {
+                let (status, body) = call(&app, TestRequest::get()
+                        .uri(&format!("{uri}/{user_id}"))
+                        .insert_header(bearer(token))
+                ).await;
+                assert_eq!(status, expected, "{body}");
+        }
+}
+
+async fn concurrent_registrations_do_not_exceed_capacity(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let mut event = new_event(organizer_id, "Meetup", 0, &[]);
+        event["capacity"] = json!(2);
+        let event_id = create_event(&app, &organizer_token, event).await;
+        approve_event(&app, &admin_token, event_id).await;
+
+        let mut attendees = Vec::new();
+        for index in 0..6 {
+                attendees.push(create_user_with_token(&app, &provider, &format!("attendee_{index}"), UserRole::User).await);
+        }
+
+        let responses = join_all(attendees.iter().map(|(user_id, token)| call(&app, TestRequest::post()
+                .uri(&format!("/api/v1/events/{event_id}/registrations/{user_id}"))
+                .insert_header(bearer(token))
+        ))).await;
+
+        assert_eq!(responses.iter().filter(|(status, _)| *status == StatusCode::CREATED).count(), 2);
+        assert!(responses.iter()
+                .filter(|(status, _)| *status != StatusCode::CREATED)
+                .all(|(status, body)| *status == StatusCode::CONFLICT && body["code"] == "capacity_reached"));
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/events/{event_id}/registrations"))
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body["total"], 2);
+}
+
+backend_tests!(
+        capacity_patch_respects_registrations_and_promotes_waitlist,
+        registration_requires_approved_event,
+        registrations_are_visible_to_owner_admin_and_attendee,
+        concurrent_registrations_do_not_exceed_capacity,
+);
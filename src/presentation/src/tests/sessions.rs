@@ -0,0 +1,283 @@
+use actix_web::{http::StatusCode, test::TestRequest};
+use domain::models::{token::{Claims, hash_token}, user::UserRole};
+use infrastructure::db::provider::Provider;
+use serde_json::json;
+
+use super::{bearer, call, create_user, init, login};
+
+#[actix_web::test]
+async fn logins_keep_separate_sessions() {
+        let (app, provider) = init().await;
+
+        let user_id = create_user(&app, &provider, "session_user", UserRole::User).await;
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/users/login")
+                .insert_header(("User-Agent", "laptop-browser"))
+                .insert_header(("X-Forwarded-For", "203.0.113.7"))
+                .peer_addr("192.0.2.10:41000".parse().unwrap())
+                .set_json(json!({ "login": "session_user", "password": "password", "device": "Laptop" }))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let laptop_access = body["token_pair"]["access"].as_str().unwrap().to_string();
+        let laptop_refresh = body["token_pair"]["refresh"].as_str().unwrap().to_string();
+
+        let (phone_access, phone_refresh) = login(&app, "session_user").await;
+
+        let laptop = Claims::decode_from(&laptop_access).unwrap();
+        let phone = Claims::decode_from(&phone_access).unwrap();
+        assert!(laptop.sid.is_some());
+        assert_ne!(laptop.sid, phone.sid);
+        assert_ne!(laptop.jti, phone.jti);
+        assert_eq!(Claims::decode_from(&laptop_refresh).unwrap().sid, laptop.sid);
+
+        for (access, refresh) in [(&laptop_access, &laptop_refresh), (&phone_access, &phone_refresh)] {
+                let (status, _) = call(&app, TestRequest::put()
+                        .uri("/api/v1/users/login")
+                        .set_json(json!({ "access": access, "refresh": refresh }))
+                ).await;
+                assert_eq!(status, StatusCode::CREATED);
+        }
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/sessions"))
+                .insert_header(bearer(&laptop_access))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        let sessions = body["sessions"].as_array().unwrap();
+        assert_eq!(sessions.len(), 2);
+
+        let current: Vec<_> = sessions.iter().filter(|session| session["current"] == true).collect();
+        assert_eq!(current.len(), 1);
+        assert_eq!(current[0]["id"], laptop.sid.unwrap().to_string());
+        assert_eq!(current[0]["device"], "Laptop");
+        assert_eq!(current[0]["user_agent"], "laptop-browser");
+        assert_eq!(current[0]["ip"], "192.0.2.10");
+}
+
+#[actix_web::test]
+async fn refresh_token_reuse_revokes_session() {
+        let (app, provider) = init().await;
+
+        let user_id = create_user(&app, &provider, "session_user", UserRole::User).await;
+        let (access, refresh) = login(&app, "session_user").await;
+        let (other_access, _) = login(&app, "session_user").await;
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "access": access, "refresh": refresh }))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let rotated_access = body["token_pair"]["access"].as_str().unwrap().to_string();
+        let rotated_refresh = body["token_pair"]["refresh"].as_str().unwrap().to_string();
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri("/api/v1/users/login")
+                .insert_header(("User-Agent", "stolen-client"))
+                .set_json(json!({ "access": access, "refresh": refresh }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "token_reused");
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "access": rotated_access, "refresh": rotated_refresh }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "revoked_token");
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/sessions"))
+                .insert_header(bearer(&rotated_access))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "revoked_token");
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/security-events"))
+                .insert_header(bearer(&other_access))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        let events = body["security_events"].as_array().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0]["kind"], "RefreshTokenReuse");
+        assert_eq!(events[0]["session_id"], Claims::decode_from(&access).unwrap().sid.unwrap().to_string());
+        assert_eq!(events[0]["user_agent"], "stolen-client");
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/sessions"))
+                .insert_header(bearer(&other_access))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["sessions"].as_array().unwrap().len(), 1);
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "access": access, "refresh": access }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "invalid_token");
+}
+
+#[actix_web::test]
+async fn refresh_token_is_stored_hashed() {
+        let (app, provider) = init().await;
+
+        create_user(&app, &provider, "session_user", UserRole::User).await;
+        let (_, refresh) = login(&app, "session_user").await;
+        let session_id = Claims::decode_from(&refresh).unwrap().sid.unwrap();
+        let repository = provider.provide_session_repository();
+
+        assert!(repository.rotate(session_id, &refresh, "replacement").await.unwrap().is_none());
+        assert!(repository.rotate(session_id, &hash_token(&refresh), &hash_token("replacement")).await.unwrap().is_some());
+}
+
+#[actix_web::test]
+async fn revoke_session() {
+        let (app, provider) = init().await;
+
+        let user_id = create_user(&app, &provider, "session_user", UserRole::User).await;
+        let other_id = create_user(&app, &provider, "other_user", UserRole::User).await;
+        let (laptop_access, _) = login(&app, "session_user").await;
+        let (phone_access, phone_refresh) = login(&app, "session_user").await;
+        let (other_access, _) = login(&app, "other_user").await;
+
+        let phone_sid = Claims::decode_from(&phone_access).unwrap().sid.unwrap();
+        let uri = format!("/api/v1/users/{user_id}/sessions/{phone_sid}");
+
+        let (status, _) = call(&app, TestRequest::delete().uri(&uri)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, body) = call(&app, TestRequest::delete().uri(&uri).insert_header(bearer(&other_access))).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "forbidden");
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri(&format!("/api/v1/users/{other_id}/sessions/{phone_sid}"))
+                .insert_header(bearer(&other_access))
+        ).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/sessions"))
+                .insert_header(bearer(&other_access))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = call(&app, TestRequest::delete().uri(&uri).insert_header(bearer(&laptop_access))).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, _) = call(&app, TestRequest::delete().uri(&uri).insert_header(bearer(&laptop_access))).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "access": phone_access, "refresh": phone_refresh }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "revoked_token");
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/sessions"))
+                .insert_header(bearer(&laptop_access))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["sessions"].as_array().unwrap().len(), 1);
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri(&format!("/api/v1/users/{user_id}/sessions/not-a-uuid"))
+                .insert_header(bearer(&laptop_access))
+        ).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn logout_revokes_current_session() {
+        let (app, provider) = init().await;
+
+        let user_id = create_user(&app, &provider, "session_user", UserRole::User).await;
+        let (laptop_access, laptop_refresh) = login(&app, "session_user").await;
+        let (phone_access, _) = login(&app, "session_user").await;
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "access": laptop_access, "refresh": laptop_access }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "access": laptop_access, "refresh": laptop_refresh }))
+        ).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "access": laptop_access, "refresh": laptop_refresh }))
+        ).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/sessions"))
+                .insert_header(bearer(&laptop_access))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "revoked_token");
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "access": laptop_access, "refresh": laptop_refresh }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "revoked_token");
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{user_id}/sessions"))
+                .insert_header(bearer(&phone_access))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["sessions"].as_array().unwrap().len(), 1);
+}
+
+#[actix_web::test]
+async fn logout_everywhere_revokes_all_sessions() {
+        let (app, provider) = init().await;
+
+        let user_id = create_user(&app, &provider, "session_user", UserRole::User).await;
+        let other_id = create_user(&app, &provider, "other_user", UserRole::User).await;
+        let (laptop_access, _) = login(&app, "session_user").await;
+        let (phone_access, phone_refresh) = login(&app, "session_user").await;
+        let (other_access, _) = login(&app, "other_user").await;
+
+        let uri = format!("/api/v1/users/{user_id}/sessions");
+
+        let (status, _) = call(&app, TestRequest::delete().uri(&uri).insert_header(bearer(&other_access))).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = call(&app, TestRequest::delete().uri(&uri).insert_header(bearer(&laptop_access))).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        for access in [&laptop_access, &phone_access] {
+                let (status, body) = call(&app, TestRequest::get().uri(&uri).insert_header(bearer(access))).await;
+                assert_eq!(status, StatusCode::UNAUTHORIZED);
+                assert_eq!(body["code"], "revoked_token");
+        }
+
+        let (status, body) = call(&app, TestRequest::put()
+                .uri("/api/v1/users/login")
+                .set_json(json!({ "access": phone_access, "refresh": phone_refresh }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "revoked_token");
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("/api/v1/users/{other_id}/sessions"))
+                .insert_header(bearer(&other_access))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["sessions"].as_array().unwrap().len(), 1);
+
+        let (access, _) = login(&app, "session_user").await;
+        let (status, _) = call(&app, TestRequest::get().uri(&uri).insert_header(bearer(&access))).await;
+        assert_eq!(status, StatusCode::OK);
+}
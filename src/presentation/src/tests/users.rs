@@ -0,0 +1,222 @@
+use actix_web::{http::StatusCode, test::TestRequest};
+use domain::models::user::UserRole;
+use serde_json::json;
+
+use super::{bearer, call, create_user, create_user_with_token, init};
+
+#[actix_web::test]
+async fn create_and_get_user() {
+        let (app, _) = init().await;
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/users")
+                .set_json(json!({ "login": "alice_user", "password": "password" }))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["user"]["login"], "alice_user");
+        assert_eq!(body["user"]["role"], "User");
+        assert!(body["user"].get("password_hash").is_none());
+
+        let id = body["user"]["id"].as_i64().unwrap();
+        let (status, body) = call(&app, TestRequest::get().uri(&format!("/api/v1/users/{id}"))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["user"]["id"], id);
+}
+
+#[actix_web::test]
+async fn create_user_validation() {
+        let (app, _) = init().await;
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/users")
+                .set_json(json!({ "login": "bob", "password": "password" }))
+        ).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "login_too_short");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/users")
+                .set_json(json!({ "login": "bob_user", "password": "short" }))
+        ).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "password_too_short");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/users")
+                .set_json(json!({ "login": "bob_user" }))
+        ).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["status"], 400);
+}
+
+#[actix_web::test]
+async fn create_duplicate_user() {
+        let (app, provider) = init().await;
+
+        create_user(&app, &provider, "carol_user", UserRole::User).await;
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri("/api/v1/users")
+                .set_json(json!({ "login": "carol_user", "password": "password" }))
+        ).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "already_exists");
+}
+
+#[actix_web::test]
+async fn get_missing_user() {
+        let (app, _) = init().await;
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/users/42")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "not_found");
+
+        let (status, _) = call(&app, TestRequest::get().uri("/api/v1/users/abc")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn list_users_filter_order_and_pagination() {
+        let (app, provider) = init().await;
+
+        for login in ["user_one", "user_two", "user_three", "other_four"] {
+                create_user(&app, &provider, login, UserRole::User).await;
+        }
+        create_user(&app, &provider, "admin_five", UserRole::Admin).await;
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/users?filter=login%20starts_with%20user_&order_by=id%20desc")).await;
+        assert_eq!(status, StatusCode::OK);
+        let logins: Vec<_> = body["users"].as_array().unwrap().iter().map(|user| user["login"].as_str().unwrap()).collect();
+        assert_eq!(logins, ["user_three", "user_two", "user_one"]);
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/users?filter=role%20eq%20Admin")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["users"].as_array().unwrap().len(), 1);
+        assert_eq!(body["users"][0]["login"], "admin_five");
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/users?page=2&limit=2&order_by=id")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["users"].as_array().unwrap().len(), 2);
+        assert_eq!(body["users"][0]["login"], "user_three");
+        assert_eq!(body["page"], 2);
+        assert_eq!(body["total"], 5);
+        assert_eq!(body["total_pages"], 3);
+        assert!(body["next"].as_str().unwrap().contains("page=3"));
+        assert!(body["prev"].as_str().unwrap().contains("page=1"));
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/users?page=-1&limit=2")).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["status"], 422);
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/users?filter=password%20eq%20x")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["status"], 400);
+}
+
+#[actix_web::test]
+async fn list_users_cursor() {
+        let (app, provider) = init().await;
+
+        for login in ["cursor_one", "cursor_two", "cursor_three"] {
+                create_user(&app, &provider, login, UserRole::User).await;
+        }
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/users?mode=cursor&limit=2&order_by=id")).await;
+        assert_eq!(status, StatusCode::OK);
+        let logins: Vec<_> = body["users"].as_array().unwrap().iter().map(|user| user["login"].as_str().unwrap()).collect();
+        assert_eq!(logins, ["cursor_one", "cursor_two"]);
+
+        let cursor = body["next_cursor"].as_str().unwrap().to_string();
+        let next = body["next"].as_str().unwrap().to_string();
+        let (status, body) = call(&app, TestRequest::get().uri(&next)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["users"].as_array().unwrap().len(), 1);
+        assert_eq!(body["users"][0]["login"], "cursor_three");
+        assert!(body["next_cursor"].is_null());
+
+        let mut tampered = cursor.into_bytes();
+        tampered[30] = if tampered[30] == b'A' { b'B' } else { b'A' };
+        let tampered = String::from_utf8(tampered).unwrap();
+        let (status, body) = call(&app, TestRequest::get().uri(&format!("/api/v1/users?cursor={tampered}&limit=2&order_by=id"))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalid_cursor");
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/users?mode=cursor&limit=2147483647")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["users"].as_array().unwrap().len(), 3);
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/users?page=2147483647&limit=2147483647")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["users"], json!([]));
+
+        let (status, body) = call(&app, TestRequest::get().uri("/api/v1/users?cursor=garbage&limit=2")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalid_cursor");
+}
+
+#[actix_web::test]
+async fn update_user_role_requires_admin() {
+        let (app, provider) = init().await;
+
+        let user_id = create_user(&app, &provider, "plain_user", UserRole::User).await;
+        let (_, user_token) = create_user_with_token(&app, &provider, "other_user", UserRole::User).await;
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+
+        let uri = format!("/api/v1/users/{user_id}");
+
+        let (status, _) = call(&app, TestRequest::patch().uri(&uri).set_json(json!({ "role": "Organizer" }))).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, _) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer("not-a-token"))
+                .set_json(json!({ "role": "Organizer" }))
+        ).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&user_token))
+                .set_json(json!({ "role": "Organizer" }))
+        ).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "forbidden");
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&admin_token))
+                .set_json(json!({ "role": "Organizer" }))
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["user"]["role"], "Organizer");
+
+        let (status, _) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&admin_token))
+                .set_json(json!({ "role": "Superuser" }))
+        ).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn delete_user_requires_admin() {
+        let (app, provider) = init().await;
+
+        let user_id = create_user(&app, &provider, "doomed_user", UserRole::User).await;
+        let (_, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+
+        let uri = format!("/api/v1/users/{user_id}");
+
+        let (status, _) = call(&app, TestRequest::delete().uri(&uri).insert_header(bearer(&organizer_token))).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = call(&app, TestRequest::delete().uri(&uri).insert_header(bearer(&admin_token))).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, _) = call(&app, TestRequest::get().uri(&uri)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = call(&app, TestRequest::delete().uri(&uri).insert_header(bearer(&admin_token))).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+}
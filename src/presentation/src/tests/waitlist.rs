@@ -0,0 +1,216 @@
+use actix_web::{http::StatusCode, test::TestRequest};
+use domain::models::user::UserRole;
+use infrastructure::db::provider::Provider;
+use serde_json::json;
+
+use super::{approve_event, backend_tests, bearer, call, create_event, create_user_with_token, init_with, new_event};
+
+async fn waitlist_is_visible_to_owner_admin_and_entry_user(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (_, other_token) = create_user_with_token(&app, &provider, "other_organizer", UserRole::Organizer).await;
+        let (attendee_id, attendee_token) = create_user_with_token(&app, &provider, "attendee", UserRole::User).await;
+        let (waiting_id, waiting_token) = create_user_with_token(&app, &provider, "waiting_user", UserRole::User).await;
+        let (_, stranger_token) = create_user_with_token(&app, &provider, "stranger", UserRole::User).await;
+
+        let mut event = new_event(organizer_id, "Meetup", 0, &[]);
+        event["capacity"] = json!(1);
+        let event_id = create_event(&app, &organizer_token, event).await;
+        approve_event(&app, &admin_token, event_id).await;
+        let uri = format!("/api/v1/events/{event_id}");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("{uri}/registrations/{attendee_id}"))
+                .insert_header(bearer(&attendee_token))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("{uri}/waitlist/{waiting_id}"))
+                .insert_header(bearer(&waiting_token))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+
+        for (token, expected) in [(&organizer_token, StatusCode::OK), (&admin_token, StatusCode::OK), (&other_token, StatusCode::FORBIDDEN), (&waiting_token, StatusCode::FORBIDDEN)] {
+                let (status, body) = call(&app, TestRequest::get()
+                        .uri(&format!("{uri}/waitlist"))
+                        .insert_header(bearer(token))
+                ).await;
+                assert_eq!(status, expected, "{body}");
+        }
+
+        for (token, expected) in [(&organizer_token, StatusCode::OK), (&admin_token, StatusCode::OK), (&waiting_token, StatusCode::OK), (&other_token, StatusCode::FORBIDDEN), (&stranger_token, StatusCode::FORBIDDEN)] {
+                let (status, body) = call(&app, TestRequest::get()
+                        .uri(&format!("{uri}/waitlist/{waiting_id}"))
+                        .insert_header(bearer(token))
+                ).await;
+                assert_eq!(status, expected, "{body}");
+        }
+}
+
+async fn waitlist_requires_full_event_and_promotes_on_cancel(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (attendee_id, attendee_token) = create_user_with_token(&app, &provider, "attendee", UserRole::User).await;
+        let (first_id, first_token) = create_user_with_token(&app, &provider, "first_waiting", UserRole::User).await;
+        let (second_id, second_token) = create_user_with_token(&app, &provider, "second_waiting", UserRole::User).await;
+
+        let mut event = new_event(organizer_id, "Meetup", 0, &[]);
+        event["capacity"] = json!(1);
+        let event_id = create_event(&app, &organizer_token, event).await;
+        approve_event(&app, &admin_token, event_id).await;
+        let uri = format!("/api/v1/events/{event_id}");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("{uri}/waitlist/{first_id}"))
+                .insert_header(bearer(&first_token))
+        ).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "waitlist_rejected");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("{uri}/registrations/{attendee_id}"))
+                .insert_header(bearer(&attendee_token))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("{uri}/registrations/{first_id}"))
+                .insert_header(bearer(&first_token))
+        ).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "capacity_reached");
+
+        for (user_id, token) in [(first_id, &first_token), (second_id, &second_token)] {
+                let (status, body) = call(&app, TestRequest::post()
+                        .uri(&format!("{uri}/waitlist/{user_id}"))
+                        .insert_header(bearer(token))
+                ).await;
+                assert_eq!(status, StatusCode::CREATED, "{body}");
+        }
+
+        let (status, _) = call(&app, TestRequest::post()
+                .uri(&format!("{uri}/waitlist/{attendee_id}"))
+                .insert_header(bearer(&attendee_token))
+        ).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri(&format!("{uri}/registrations/{attendee_id}"))
+                .insert_header(bearer(&attendee_token))
+        ).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}/registrations"))
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body["total"], 1);
+        assert_eq!(body["registrations"][0]["user_id"], first_id);
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}/waitlist"))
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body["total"], 1);
+        assert_eq!(body["waitlist"][0]["user_id"], second_id);
+}
+
+async fn waitlist_only_serves_approved_events(provider: impl Provider + Clone + 'static) {
+        let (app, provider) = init_with(provider).await;
+
+        let (_, admin_token) = create_user_with_token(&app, &provider, "admin_user", UserRole::Admin).await;
+        let (organizer_id, organizer_token) = create_user_with_token(&app, &provider, "organizer", UserRole::Organizer).await;
+        let (attendee_id, attendee_token) = create_user_with_token(&app, &provider, "attendee", UserRole::User).await;
+        let (waiting_id, waiting_token) = create_user_with_token(&app, &provider, "waiting_user", UserRole::User).await;
+        let (late_id, late_token) = create_user_with_token(&app, &provider, "late_user", UserRole::User).await;
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("/api/v1/events/999999/waitlist/{waiting_id}"))
+                .insert_header(bearer(&waiting_token))
+        ).await;
+        assert_eq!(status, StatusCode::NOT_FOUND, "{body}");
+
+        let mut event = new_event(organizer_id, "Meetup", 0, &[]);
+        event["capacity"] = json!(0);
+        let draft_id = create_event(&app, &organizer_token, event).await;
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("/api/v1/events/{draft_id}/waitlist/{waiting_id}"))
+                .insert_header(bearer(&waiting_token))
+        ).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "registration_closed");
+
+        let mut event = new_event(organizer_id, "Workshop", 0, &[]);
+        event["capacity"] = json!(1);
+        let event_id = create_event(&app, &organizer_token, event).await;
+        approve_event(&app, &admin_token, event_id).await;
+        let uri = format!("/api/v1/events/{event_id}");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("{uri}/registrations/{attendee_id}"))
+                .insert_header(bearer(&attendee_token))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("{uri}/waitlist/{waiting_id}"))
+                .insert_header(bearer(&waiting_token))
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&format!("{uri}/status"))
+                .insert_header(bearer(&admin_token))
+                .set_json(json!({ "status": "Cancelled" }))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+
+        let (status, body) = call(&app, TestRequest::post()
+                .uri(&format!("{uri}/waitlist/{late_id}"))
+                .insert_header(bearer(&late_token))
+        ).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "registration_closed");
+
+        let (status, _) = call(&app, TestRequest::delete()
+                .uri(&format!("{uri}/registrations/{attendee_id}"))
+                .insert_header(bearer(&attendee_token))
+        ).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, body) = call(&app, TestRequest::patch()
+                .uri(&uri)
+                .insert_header(bearer(&organizer_token))
+                .set_json(json!({ "capacity": 5 }))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}/registrations"))
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body["total"], 0);
+
+        let (status, body) = call(&app, TestRequest::get()
+                .uri(&format!("{uri}/waitlist"))
+                .insert_header(bearer(&organizer_token))
+        ).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body["total"], 1);
+        assert_eq!(body["waitlist"][0]["user_id"], waiting_id);
+}
+
+backend_tests!(
+        waitlist_is_visible_to_owner_admin_and_entry_user,
+        waitlist_requires_full_event_and_promotes_on_cancel,
+        waitlist_only_serves_approved_events,
+);
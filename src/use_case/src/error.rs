@@ -2,17 +2,45 @@
 pub enum ServiceError {
         #[error("{0}")]
         Db(#[from] infrastructure::db::error::DbError),
-        #[error("couldn't find {0} with field {0}")]
+        #[error("{0}")]
+        Domain(#[from] domain::error::DomainError),
+        #[error("couldn't find {0} with field {1}")]
         NotFound(String, String),
+        #[error("event {0} has reached its capacity")]
+        CapacityReached(String),
+        #[error("event {0} is not open for registration")]
+        RegistrationClosed(String),
+        #[error("event {0} already has more registrations than the requested capacity")]
+        CapacityBelowRegistrations(String),
+        #[error("couldn't put {0} on waitlist: event has free seats or user is already registered")]
+        WaitlistRejected(String),
+        #[error("event {0} is not recurring")]
+        NotRecurring(String),
+        #[error("event status can't change from {from} to {to}")]
+        IllegalTransition {
+                from: domain::models::event::EventStatus,
+                to: domain::models::event::EventStatus
+        },
+        #[error("invalid login or password")]
+        InvalidCredentials,
         #[error("JWT have expired")]
         Expired(#[source] jsonwebtoken::errors::Error),
+        #[error("refresh token of session {0} has already been used")]
+        TokenReused(String),
+        #[error("token {0} has been revoked")]
+        Revoked(String),
+        #[error("JWT is invalid")]
+        InvalidToken(#[source] jsonwebtoken::errors::Error),
         #[error("{0}")]
         Other(#[source] Box<dyn std::error::Error>),
 }
 
 impl From<argon2::password_hash::Error> for ServiceError {
         fn from(value: argon2::password_hash::Error) -> Self {
-                Self::Other(Box::new(value))
+                match value {
+                        argon2::password_hash::Error::Password => Self::InvalidCredentials,
+                        _ => Self::Other(Box::new(value))
+                }
         }
 }
 
@@ -21,6 +49,17 @@ impl From<jsonwebtoken::errors::Error> for ServiceError {
                 use jsonwebtoken::errors::ErrorKind;
                 match value.kind() {
                         ErrorKind::ExpiredSignature => Self::Expired(value),
+                        ErrorKind::InvalidToken
+                        | ErrorKind::InvalidSignature
+                        | ErrorKind::InvalidAudience
+                        | ErrorKind::InvalidIssuer
+                        | ErrorKind::InvalidSubject
+                        | ErrorKind::InvalidAlgorithm
+                        | ErrorKind::ImmatureSignature
+                        | ErrorKind::MissingRequiredClaim(_)
+                        | ErrorKind::Base64(_)
+                        | ErrorKind::Json(_)
+                        | ErrorKind::Utf8(_) => Self::InvalidToken(value),
                         _ => Self::Other(Box::new(value))
                 }
         }
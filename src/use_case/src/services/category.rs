@@ -0,0 +1,49 @@
+use domain::models::category::{CategoryModel, CategoryName};
+use infrastructure::db::category::repository::CategoryRepository;
+
+use crate::{Result, ServiceError};
+
+pub struct CategoryService<T: CategoryRepository> {
+        repository: T,
+}
+
+impl <T: CategoryRepository> CategoryService<T> {
+        pub fn new(repository: T) -> Self {
+                Self { repository }
+        }
+
+        pub async fn list(&self) -> Result<Vec<CategoryModel>> {
+                let res = self.repository
+                        .list()
+                        .await;
+
+                match res {
+                        Ok(res) => Ok(res.into_iter().map(Into::into).collect()),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn create(&self, name: CategoryName) -> Result<CategoryModel> {
+                let res = self.repository
+                        .create(&name)
+                        .await;
+
+                match res {
+                        Ok(res) => Ok(res.into()),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn delete(&self, name: CategoryName) -> Result<CategoryModel> {
+                let res = self.repository
+                        .delete(&name)
+                        .await;
+
+                match res {
+                        Ok(res) =>
+                                res.map(Into::into)
+                                        .ok_or(ServiceError::NotFound("category".to_string(), name)),
+                        Err(err) => Err(err.into())
+                }
+        }
+}
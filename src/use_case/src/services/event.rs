@@ -1,4 +1,4 @@
-use domain::models::{event::{EventFilter, EventId, EventModel, EventOrder, EventUpdate, NewEvent}, utils::Offset};
+use domain::models::{cursor::{Cursor, CursorPage}, event::{EventFilter, EventId, EventModel, EventOrder, EventSearch, EventStatus, EventStatusChangeModel, EventUpdate, NewEvent}, geo::GeoRadius, user::UserId, utils::{FilterGroup, Offset, Page}};
 use infrastructure::db::event::repository::EventRepository;
 
 use crate::{Result, ServiceError};
@@ -27,14 +27,7 @@ impl <T: EventRepository> EventService<T> {
 
         pub async fn create(&self, event: NewEvent) -> Result<EventModel> {
                 let res = self.repository
-                        .create(
-                                event.organizer_id as i64,
-                                &event.title,
-                                &event.description,
-                                event.date,
-                                event.cost.try_into().unwrap(),
-                                &event.address
-                        )
+                        .create(&event)
                         .await;
 
                 match res {
@@ -43,26 +36,45 @@ impl <T: EventRepository> EventService<T> {
                 }
         }
 
-        pub async fn list(&self, offset: Offset, filters: &[EventFilter], order_by: &[EventOrder]) -> Result<Vec<EventModel>> {
+        pub async fn list(&self, offset: Offset, filters: &[FilterGroup<EventFilter>], order_by: &[EventOrder], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<Page<EventModel>> {
+                let order_by = EventOrder::sort_keys(order_by, search.is_some(), near.is_some());
                 let res = self.repository
-                        .list(offset, filters, order_by)
+                        .list(offset.clone(), filters, &order_by, search, near)
                         .await;
 
                 match res {
-                        Ok(res) => Ok(res.into_iter().map(Into::into).collect()),
+                        Ok(res) => {
+                                let total = self.repository.count(filters, search, near).await?;
+                                Ok(Page::new(res.into_iter().map(Into::into).collect(), offset, total as u64))
+                        },
                         Err(err) => Err(err.into())
                 }
         }
 
-        pub async fn update(&self, id: EventId, changes: EventUpdate) -> Result<EventModel> {
+        pub async fn list_after(&self, limit: u32, cursor: Option<&Cursor>, filters: &[FilterGroup<EventFilter>], order_by: &[EventOrder], search: Option<&EventSearch>, near: Option<&GeoRadius>) -> Result<CursorPage<EventModel>> {
+                let order_by = EventOrder::sort_keys(order_by, search.is_some(), near.is_some());
+                let after = cursor.map(|cursor| cursor.values(&order_by)).transpose()?;
                 let res = self.repository
-                        .update(id as i64, changes)
+                        .list_after(limit.saturating_add(1), after, filters, &order_by, search, near)
                         .await;
 
                 match res {
-                        Ok(res) =>
-                                res.map(Into::into)
-                                        .ok_or(ServiceError::NotFound("event".to_string(), id.to_string())),
+                        Ok(res) => Ok(CursorPage::new(res.into_iter().map(Into::into).collect(), limit, &order_by)),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn update(&self, id: EventId, changes: EventUpdate, changed_by: UserId) -> Result<EventModel> {
+                let res = self.repository
+                        .update(id as i64, &changes, changed_by as i64)
+                        .await;
+
+                match res {
+                        Ok(Some(res)) => Ok(res.into()),
+                        Ok(None) => match self.repository.get(id as i64).await? {
+                                Some(_) => Err(ServiceError::CapacityBelowRegistrations(id.to_string())),
+                                None => Err(ServiceError::NotFound("event".to_string(), id.to_string()))
+                        },
                         Err(err) => Err(err.into())
                 }
         }
@@ -79,4 +91,39 @@ impl <T: EventRepository> EventService<T> {
                         Err(err) => Err(err.into())
                 }
         }
+
+        pub async fn update_status(&self, id: EventId, status: EventStatus, changed_by: UserId) -> Result<EventModel> {
+                let event = self.get(id).await?;
+
+                if !event.status.can_transition_to(&status) {
+                        return Err(ServiceError::IllegalTransition { from: event.status, to: status });
+                }
+
+                let res = self.repository
+                        .update_status(id as i64, event.status, status.clone(), changed_by as i64)
+                        .await;
+
+                match res {
+                        Ok(Some(res)) => Ok(res.into()),
+                        Ok(None) => {
+                                let event = self.get(id).await?;
+                                Err(ServiceError::IllegalTransition { from: event.status, to: status })
+                        },
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn list_status_history(&self, id: EventId, offset: Offset) -> Result<Page<EventStatusChangeModel>> {
+                let res = self.repository
+                        .list_status_history(id as i64, offset.clone())
+                        .await;
+
+                match res {
+                        Ok(res) => {
+                                let total = self.repository.count_status_history(id as i64).await?;
+                                Ok(Page::new(res.into_iter().map(Into::into).collect(), offset, total as u64))
+                        },
+                        Err(err) => Err(err.into())
+                }
+        }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use domain::models::{favorite::{FavoriteEventModel, FavoriteFilter, FavoriteId, FavoriteModel, FavoriteOrder}, user::UserId, utils::Offset};
+use domain::models::{cursor::{Cursor, CursorPage}, favorite::{FavoriteEventModel, FavoriteFilter, FavoriteId, FavoriteModel, FavoriteOrder}, user::UserId, utils::{FilterGroup, Offset, Page}};
 use infrastructure::db::favorite::repository::FavoriteRepository;
 
 use crate::{Result, ServiceError};
@@ -36,13 +36,30 @@ impl <T: FavoriteRepository> FavoriteService<T> {
                 }
         }
 
-        pub async fn list(&self, user_id: UserId, offset: Offset, filters: &[FavoriteFilter], order_by: &[FavoriteOrder]) -> Result<Vec<FavoriteEventModel>> {
+        pub async fn list(&self, user_id: UserId, offset: Offset, filters: &[FilterGroup<FavoriteFilter>], order_by: &[FavoriteOrder]) -> Result<Page<FavoriteEventModel>> {
+                let order_by = FavoriteOrder::sort_keys(order_by);
                 let res = self.repository
-                        .list(user_id as i64, offset, filters, order_by)
+                        .list(user_id as i64, offset.clone(), filters, &order_by)
                         .await;
 
                 match res {
-                        Ok(res) => Ok(res.into_iter().map(Into::into).collect()),
+                        Ok(res) => {
+                                let total = self.repository.count(user_id as i64, filters).await?;
+                                Ok(Page::new(res.into_iter().map(Into::into).collect(), offset, total as u64))
+                        },
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn list_after(&self, user_id: UserId, limit: u32, cursor: Option<&Cursor>, filters: &[FilterGroup<FavoriteFilter>], order_by: &[FavoriteOrder]) -> Result<CursorPage<FavoriteEventModel>> {
+                let order_by = FavoriteOrder::sort_keys(order_by);
+                let after = cursor.map(|cursor| cursor.values(&order_by)).transpose()?;
+                let res = self.repository
+                        .list_after(user_id as i64, limit.saturating_add(1), after, filters, &order_by)
+                        .await;
+
+                match res {
+                        Ok(res) => Ok(CursorPage::new(res.into_iter().map(Into::into).collect(), limit, &order_by)),
                         Err(err) => Err(err.into())
                 }
         }
@@ -1,4 +1,8 @@
-pub mod refresh;
+pub mod session;
+pub mod category;
 pub mod event;
 pub mod favorite;
-pub mod user;
\ No newline at end of file
+pub mod occurrence;
+pub mod registration;
+pub mod user;
+pub mod waitlist;
\ No newline at end of file
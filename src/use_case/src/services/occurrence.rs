@@ -0,0 +1,112 @@
+use domain::models::{event::{EventFilter, EventId, EventModel}, occurrence::{OccurrenceId, OccurrenceModel, OccurrenceOverride, OccurrenceOverrideModel, OccurrenceWindow}, utils::{FilterGroup, Offset, Page}};
+use infrastructure::db::{event::repository::EventRepository, occurrence::repository::OccurrenceRepository};
+
+use crate::{Result, ServiceError};
+
+pub struct OccurrenceService<E: EventRepository, O: OccurrenceRepository> {
+        event_repository: E,
+        repository: O
+}
+
+impl <E: EventRepository, O: OccurrenceRepository> OccurrenceService<E, O> {
+        pub fn new(event_repository: E, repository: O) -> Self {
+                Self { event_repository, repository }
+        }
+
+        pub async fn list(&self, window: OccurrenceWindow, offset: Offset, filters: &[FilterGroup<EventFilter>]) -> Result<Page<OccurrenceModel>> {
+                let events: Vec<EventModel> = self.event_repository
+                        .list_window(window.from, window.to, filters)
+                        .await?
+                        .into_iter()
+                        .map(Into::into)
+                        .collect();
+
+                let event_ids: Vec<i64> = events.iter().map(|event| event.id as i64).collect();
+                let overrides: Vec<OccurrenceOverrideModel> = self.repository
+                        .list(&event_ids, window.from, window.to)
+                        .await?
+                        .into_iter()
+                        .map(Into::into)
+                        .collect();
+
+                let mut occurrences: Vec<OccurrenceModel> = events.iter()
+                        .flat_map(|event| window.expand(event, &overrides))
+                        .collect();
+                occurrences.sort_by_key(|occurrence| (occurrence.date, occurrence.event.id));
+
+                Ok(Page::paginate(occurrences, offset))
+        }
+
+        pub async fn list_event(&self, event_id: EventId, window: OccurrenceWindow) -> Result<Vec<OccurrenceModel>> {
+                let event = self.get_event(event_id).await?;
+
+                let overrides: Vec<OccurrenceOverrideModel> = self.repository
+                        .list(&[event_id as i64], window.from, window.to)
+                        .await?
+                        .into_iter()
+                        .map(Into::into)
+                        .collect();
+
+                Ok(window.expand(&event, &overrides))
+        }
+
+        pub async fn list_overrides(&self, event_ids: &[EventId]) -> Result<Vec<OccurrenceOverrideModel>> {
+                let event_ids: Vec<i64> = event_ids.iter().map(|event_id| *event_id as i64).collect();
+
+                let res = self.repository
+                        .list_all(&event_ids)
+                        .await;
+
+                match res {
+                        Ok(res) => Ok(res.into_iter().map(Into::into).collect()),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn upsert(&self, id: OccurrenceId, occurrence: OccurrenceOverride) -> Result<OccurrenceOverrideModel> {
+                let event = self.get_event(id.event_id).await?;
+
+                let Some(rule) = event.recurrence else {
+                        return Err(ServiceError::NotRecurring(id.event_id.to_string()));
+                };
+
+                if !rule.is_occurrence(event.date, id.original_date) {
+                        return Err(ServiceError::NotFound("occurrence".to_string(), id.to_string()));
+                }
+
+                let res = self.repository
+                        .upsert(id.event_id as i64, id.original_date, &occurrence)
+                        .await;
+
+                match res {
+                        Ok(res) => Ok(res.into()),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn delete(&self, id: OccurrenceId) -> Result<OccurrenceOverrideModel> {
+                let res = self.repository
+                        .delete(id.event_id as i64, id.original_date)
+                        .await;
+
+                match res {
+                        Ok(res) =>
+                                res.map(Into::into)
+                                        .ok_or(ServiceError::NotFound("occurrence".to_string(), id.to_string())),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        async fn get_event(&self, event_id: EventId) -> Result<EventModel> {
+                let res = self.event_repository
+                        .get(event_id as i64)
+                        .await;
+
+                match res {
+                        Ok(res) =>
+                                res.map(Into::into)
+                                        .ok_or(ServiceError::NotFound("event".to_string(), event_id.to_string())),
+                        Err(err) => Err(err.into())
+                }
+        }
+}
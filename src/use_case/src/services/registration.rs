@@ -0,0 +1,82 @@
+use domain::models::{registration::{RegistrationFilter, RegistrationId, RegistrationModel, RegistrationOrder}, event::{EventId, EventModel, EventStatus}, user::UserId, utils::{FilterGroup, Offset, Page}};
+use infrastructure::db::{event::repository::EventRepository, registration::repository::RegistrationRepository};
+
+use crate::{Result, ServiceError};
+
+pub struct RegistrationService<E: EventRepository, T: RegistrationRepository> {
+        event_repository: E,
+        repository: T,
+}
+
+impl <E: EventRepository, T: RegistrationRepository> RegistrationService<E, T> {
+        pub fn new(event_repository: E, repository: T) -> Self {
+                Self { event_repository, repository }
+        }
+
+        pub async fn get(&self, id: RegistrationId) -> Result<RegistrationModel> {
+                let res = self.repository
+                        .get(id.user_id as i64, id.event_id as i64)
+                        .await;
+
+                match res {
+                        Ok(res) =>
+                                res.map(Into::into)
+                                        .ok_or(ServiceError::NotFound("registration".to_string(), id.to_string())),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn create(&self, id: RegistrationId) -> Result<RegistrationModel> {
+                let res = self.repository
+                        .create(id.user_id as i64, id.event_id as i64)
+                        .await;
+
+                match res {
+                        Ok(Some(res)) => Ok(res.into()),
+                        Ok(None) => match self.event_repository.get(id.event_id as i64).await? {
+                                Some(event) if event.status != EventStatus::Approved.to_string() => Err(ServiceError::RegistrationClosed(id.event_id.to_string())),
+                                Some(_) => Err(ServiceError::CapacityReached(id.event_id.to_string())),
+                                None => Err(ServiceError::NotFound("event".to_string(), id.event_id.to_string()))
+                        },
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn list(&self, event_id: EventId, offset: Offset, filters: &[FilterGroup<RegistrationFilter>], order_by: &[RegistrationOrder]) -> Result<Page<RegistrationModel>> {
+                let res = self.repository
+                        .list(event_id as i64, offset.clone(), filters, order_by)
+                        .await;
+
+                match res {
+                        Ok(res) => {
+                                let total = self.repository.count(event_id as i64, filters).await?;
+                                Ok(Page::new(res.into_iter().map(Into::into).collect(), offset, total as u64))
+                        },
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn list_events(&self, user_id: UserId, offset: Offset) -> Result<Vec<EventModel>> {
+                let res = self.repository
+                        .list_events(user_id as i64, offset)
+                        .await;
+
+                match res {
+                        Ok(res) => Ok(res.into_iter().map(Into::into).collect()),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn delete(&self, id: RegistrationId) -> Result<RegistrationModel> {
+                let res = self.repository
+                        .delete(id.user_id as i64, id.event_id as i64)
+                        .await;
+
+                match res {
+                        Ok(res) =>
+                                res.map(Into::into)
+                                        .ok_or(ServiceError::NotFound("registration".to_string(), id.to_string())),
+                        Err(err) => Err(err.into())
+                }
+        }
+}
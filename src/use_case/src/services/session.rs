@@ -0,0 +1,164 @@
+use domain::models::{session::{SecurityEventModel, SessionId, SessionMetadata, SessionModel}, token::{Claims, RefreshToken, TokenPair, hash_token}, user::{UserId, UserRole}};
+use infrastructure::db::session::repository::SessionRepository;
+use jsonwebtoken::errors::ErrorKind;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{Result, ServiceError};
+
+pub struct SessionService<T: SessionRepository> {
+        repository: T,
+}
+
+impl<T: SessionRepository> SessionService<T> {
+        pub fn new(repository: T) -> Self {
+                Self { repository }
+        }
+
+        pub async fn create(&self, user_id: UserId, role: UserRole, metadata: SessionMetadata) -> Result<TokenPair> {
+                let session_id = Uuid::new_v4();
+                let current_timestamp = OffsetDateTime::now_utc();
+
+                let refresh_token =
+                        Claims::new_refresh(user_id, session_id, current_timestamp, role.clone()).encode()?;
+
+                let res = self.repository
+                        .create(session_id, user_id as i64, &hash_token(&refresh_token), &metadata)
+                        .await;
+
+                match res {
+                        Ok(_) => {
+                                let access_token =
+                                        Claims::new_access(user_id, session_id, current_timestamp, role).encode()?;
+
+                                Ok(TokenPair {
+                                        access_token,
+                                        refresh_token
+                                })
+                        },
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn refresh(&self, old: RefreshToken, metadata: SessionMetadata) -> Result<TokenPair> {
+                let mut claims = Claims::decode_from(&old)?;
+                let Some(session_id) = claims.sid.filter(|_| claims.is_refresh()) else {
+                        return Err(ServiceError::InvalidToken(ErrorKind::InvalidToken.into()));
+                };
+                let user_id = claims.sub;
+                let current_timestamp = OffsetDateTime::now_utc();
+
+                claims.iat = current_timestamp;
+                claims.nbf = current_timestamp;
+                claims.jti = Uuid::new_v4();
+
+                let new_refresh_token = claims.clone().encode()?;
+
+                let res = self.repository
+                        .rotate(session_id, &hash_token(&old), &hash_token(&new_refresh_token))
+                        .await;
+
+                match res {
+                        Ok(Some(_)) => {
+                                let access_token =
+                                        Claims::new_access(user_id, session_id, current_timestamp, claims.role).encode()?;
+
+                                Ok(TokenPair {
+                                        refresh_token: new_refresh_token,
+                                        access_token
+                                })
+                        },
+                        Ok(None) => match self.repository.revoke_reused(session_id, &metadata).await? {
+                                Some(_) => Err(ServiceError::TokenReused(session_id.to_string())),
+                                None => Err(ServiceError::Revoked(session_id.to_string()))
+                        },
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn logout(&self, token: RefreshToken) -> Result<()> {
+                let claims = Claims::decode_from(&token)?;
+                let Some(session_id) = claims.sid.filter(|_| claims.is_refresh()) else {
+                        return Err(ServiceError::InvalidToken(ErrorKind::InvalidToken.into()));
+                };
+
+                self.revoke(claims.sub, session_id).await
+        }
+
+        pub fn create_calendar(&self, claims: &Claims) -> Result<String> {
+                let Some(session_id) = claims.sid.filter(|_| claims.is_access()) else {
+                        return Err(ServiceError::InvalidToken(ErrorKind::InvalidToken.into()));
+                };
+
+                Ok(Claims::new_calendar(claims.sub, session_id, OffsetDateTime::now_utc(), claims.role.clone()).encode()?)
+        }
+
+        pub async fn verify_calendar(&self, token: &str) -> Result<Claims> {
+                let claims = Claims::decode_from(token)?;
+                let Some(session_id) = claims.sid.filter(|_| claims.is_calendar()) else {
+                        return Err(ServiceError::InvalidToken(ErrorKind::InvalidToken.into()));
+                };
+
+                match self.is_active(session_id).await? {
+                        true => Ok(claims),
+                        false => Err(ServiceError::Revoked(claims.jti.to_string()))
+                }
+        }
+
+        pub async fn is_active(&self, session_id: SessionId) -> Result<bool> {
+                let res = self.repository
+                        .get(session_id)
+                        .await;
+
+                match res {
+                        Ok(res) => Ok(res.is_some_and(|session| session.revoked_at.is_none())),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn list(&self, user_id: UserId) -> Result<Vec<SessionModel>> {
+                let res = self.repository
+                        .list(user_id as i64)
+                        .await;
+
+                match res {
+                        Ok(res) => Ok(res.into_iter().map(Into::into).collect()),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn revoke(&self, user_id: UserId, session_id: SessionId) -> Result<()> {
+                let res = self.repository
+                        .revoke(session_id, user_id as i64)
+                        .await;
+
+                match res {
+                        Ok(res) =>
+                                res.map(|_| ())
+                                        .ok_or(ServiceError::NotFound("session".to_string(), session_id.to_string())),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn revoke_all(&self, user_id: UserId) -> Result<()> {
+                let res = self.repository
+                        .revoke_all(user_id as i64)
+                        .await;
+
+                match res {
+                        Ok(_) => Ok(()),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn list_security_events(&self, user_id: UserId) -> Result<Vec<SecurityEventModel>> {
+                let res = self.repository
+                        .list_security_events(user_id as i64)
+                        .await;
+
+                match res {
+                        Ok(res) => Ok(res.into_iter().map(Into::into).collect()),
+                        Err(err) => Err(err.into())
+                }
+        }
+}
\ No newline at end of file
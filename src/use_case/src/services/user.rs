@@ -3,7 +3,7 @@ use argon2::{
                 PasswordHash, PasswordHasher, SaltString, rand_core::OsRng
         }
 };
-use domain::models::{user::{UserCredentials, UserFilter, UserId, UserModel, UserOrder, UserUpdate}, utils::Offset};
+use domain::models::{cursor::{Cursor, CursorPage}, user::{UserCredentials, UserFilter, UserId, UserModel, UserOrder, UserUpdate}, utils::{FilterGroup, Offset, Page}};
 use infrastructure::db::user::repository::UserRepository;
 
 use crate::{Result, ServiceError};
@@ -44,7 +44,7 @@ impl<T: UserRepository> UserService<T> {
 
                                                 Ok(user.into())
                                         },
-                                        None => Err(ServiceError::NotFound("user".to_string(), credentials.login))
+                                        None => Err(ServiceError::InvalidCredentials)
                                 }
                         },
                         Err(err) => Err(err.into())
@@ -69,13 +69,30 @@ impl<T: UserRepository> UserService<T> {
                 }
         }
 
-        pub async fn list(&self, offset: Offset, filters: &[UserFilter], order_by: &[UserOrder]) -> Result<Vec<UserModel>> {
+        pub async fn list(&self, offset: Offset, filters: &[FilterGroup<UserFilter>], order_by: &[UserOrder]) -> Result<Page<UserModel>> {
+                let order_by = UserOrder::sort_keys(order_by);
                 let res = self.repository
-                        .list(offset, filters, order_by)
+                        .list(offset.clone(), filters, &order_by)
                         .await;
 
                 match res {
-                        Ok(res) => Ok(res.into_iter().map(Into::into).collect()),
+                        Ok(res) => {
+                                let total = self.repository.count(filters).await?;
+                                Ok(Page::new(res.into_iter().map(Into::into).collect(), offset, total as u64))
+                        },
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn list_after(&self, limit: u32, cursor: Option<&Cursor>, filters: &[FilterGroup<UserFilter>], order_by: &[UserOrder]) -> Result<CursorPage<UserModel>> {
+                let order_by = UserOrder::sort_keys(order_by);
+                let after = cursor.map(|cursor| cursor.values(&order_by)).transpose()?;
+                let res = self.repository
+                        .list_after(limit.saturating_add(1), after, filters, &order_by)
+                        .await;
+
+                match res {
+                        Ok(res) => Ok(CursorPage::new(res.into_iter().map(Into::into).collect(), limit, &order_by)),
                         Err(err) => Err(err.into())
                 }
         }
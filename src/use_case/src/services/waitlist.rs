@@ -0,0 +1,71 @@
+use domain::models::{event::{EventId, EventStatus}, utils::{Offset, Page}, waitlist::{WaitlistId, WaitlistModel}};
+use infrastructure::db::{event::repository::EventRepository, waitlist::repository::WaitlistRepository};
+
+use crate::{Result, ServiceError};
+
+pub struct WaitlistService<E: EventRepository, T: WaitlistRepository> {
+        event_repository: E,
+        repository: T,
+}
+
+impl <E: EventRepository, T: WaitlistRepository> WaitlistService<E, T> {
+        pub fn new(event_repository: E, repository: T) -> Self {
+                Self { event_repository, repository }
+        }
+
+        pub async fn get(&self, id: WaitlistId) -> Result<WaitlistModel> {
+                let res = self.repository
+                        .get(id.user_id as i64, id.event_id as i64)
+                        .await;
+
+                match res {
+                        Ok(res) =>
+                                res.map(Into::into)
+                                        .ok_or(ServiceError::NotFound("waitlist".to_string(), id.to_string())),
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn create(&self, id: WaitlistId) -> Result<WaitlistModel> {
+                let res = self.repository
+                        .create(id.user_id as i64, id.event_id as i64)
+                        .await;
+
+                match res {
+                        Ok(Some(res)) => Ok(res.into()),
+                        Ok(None) => match self.event_repository.get(id.event_id as i64).await? {
+                                Some(event) if event.status != EventStatus::Approved.to_string() => Err(ServiceError::RegistrationClosed(id.event_id.to_string())),
+                                Some(_) => Err(ServiceError::WaitlistRejected(id.to_string())),
+                                None => Err(ServiceError::NotFound("event".to_string(), id.event_id.to_string()))
+                        },
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn list(&self, event_id: EventId, offset: Offset) -> Result<Page<WaitlistModel>> {
+                let res = self.repository
+                        .list(event_id as i64, offset.clone())
+                        .await;
+
+                match res {
+                        Ok(res) => {
+                                let total = self.repository.count(event_id as i64).await?;
+                                Ok(Page::new(res.into_iter().map(Into::into).collect(), offset, total as u64))
+                        },
+                        Err(err) => Err(err.into())
+                }
+        }
+
+        pub async fn delete(&self, id: WaitlistId) -> Result<WaitlistModel> {
+                let res = self.repository
+                        .delete(id.user_id as i64, id.event_id as i64)
+                        .await;
+
+                match res {
+                        Ok(res) =>
+                                res.map(Into::into)
+                                        .ok_or(ServiceError::NotFound("waitlist".to_string(), id.to_string())),
+                        Err(err) => Err(err.into())
+                }
+        }
+}